@@ -0,0 +1,146 @@
+//! Generates the lookup tables from the RIR files.
+//!
+//! The files are read from `IANA_DATA_PATH`, `data/` by default, as written by `iana sync`.
+//! `IANA_OVERLAY` lists overlay CSVs, separated like `PATH`, applied before the tables are
+//! written. Without any RIR file the tables are empty: the crate still builds from a clean
+//! checkout, and `lookup` finds nothing until the data is synced and the crate rebuilt.
+
+// The parser of the crate itself, so the tables match `iana::parse`.
+#[path = "src/rir/mod.rs"]
+pub mod rir;
+
+use crate::rir::record::Record;
+use crate::rir::registry::Registry;
+use crate::rir::overlay::Overlay;
+use crate::rir::rir_files::IANA_RIR_FILES;
+use crate::rir::parse::parse;
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::io::{self, Write, BufWriter};
+
+
+fn codegen(filepath: &Path, name: &str, number_type: &str, records: &[&Record]) -> io::Result<()> {
+    let db = records.iter().map(|record| format!("    {}", record.codegen()) ).collect::<Vec<String>>();
+
+    let mut file = BufWriter::new(fs::File::create(filepath)?);
+    file.write_all(b"// Format: (first_ip, last_ip, country_index, registry_index, status_index, origin_index)\n")?;
+    file.write_all(b"#[doc(hidden)]\n")?;
+    file.write_all(format!("pub static {}: [({}, {}, u8, u8, u8, u8); {}] = [\n{}\n];",
+                            name,
+                            number_type,
+                            number_type,
+                            db.len(),
+                            db.join(",\n"))
+                                .as_bytes())?;
+    file.flush()
+}
+
+/// Index in `IANA_RIR_FILES` and line of every record, `255` when unknown.
+fn provenance_row(record: &Record) -> (u8, u32) {
+    let provenance = record.provenance();
+    let file_idx = provenance.and_then(|provenance| provenance.file)
+        .and_then(|file| IANA_RIR_FILES.iter().position(|&(filename, _url)| filename == file))
+        .map(|file_idx| file_idx as u8)
+        .unwrap_or(255);
+
+    (file_idx, provenance.map(|provenance| provenance.line).unwrap_or(0))
+}
+
+fn option_code<T: std::fmt::Display>(value: Option<T>) -> String {
+    match value {
+        Some(value) => format!("Some({})", value),
+        None => "None".to_string(),
+    }
+}
+
+fn codegen_provenance(filepath: &Path, v4_records: &[&Record], v6_records: &[&Record]) -> io::Result<()> {
+    let source_files = IANA_RIR_FILES.iter().map(|&(filename, _url)| {
+        let provenance = v4_records.iter().chain(v6_records.iter())
+            .filter_map(|record| record.provenance())
+            .find(|provenance| provenance.file == Some(filename));
+        format!("    ({}, {})",
+                option_code(provenance.and_then(|provenance| provenance.serial)),
+                option_code(provenance.and_then(|provenance| provenance.date)))
+    }).collect::<Vec<String>>();
+    let table = |records: &[&Record]| records.iter()
+        .map(|record| format!("    {:?}", provenance_row(record)))
+        .collect::<Vec<String>>();
+
+    let mut file = BufWriter::new(fs::File::create(filepath)?);
+    file.write_all(b"// Format: (serial, date) of every file in IANA_RIR_FILES, (file_index, line) of every record\n")?;
+    for (name, type_, rows) in [("SOURCE_FILES", "(Option<u64>, Option<u32>)", source_files),
+                                ("IPV4_PROVENANCE", "(u8, u32)", table(v4_records)),
+                                ("IPV6_PROVENANCE", "(u8, u32)", table(v6_records))] {
+        file.write_all(format!("#[doc(hidden)]\npub static {}: [{}; {}] = [\n{}\n];\n",
+                                name, type_, rows.len(), rows.join(",\n")).as_bytes())?;
+    }
+    file.flush()
+}
+
+fn load_overlay(overlay_paths: &[PathBuf]) -> Overlay {
+    let mut overlay = Overlay::new();
+    for filepath in overlay_paths.iter() {
+        let content = fs::read_to_string(filepath)
+            .unwrap_or_else(|e| panic!("IANA_OVERLAY {:?}: {}", filepath, e));
+        if let Err(e) = overlay.parse_csv(&content) {
+            panic!("IANA_OVERLAY {:?}: {:?}", filepath, e);
+        }
+    }
+    overlay
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/rir");
+    println!("cargo:rerun-if-env-changed=IANA_DATA_PATH");
+    println!("cargo:rerun-if-env-changed=IANA_OVERLAY");
+    println!("cargo:rustc-check-cfg=cfg(iana_db_incomplete)");
+
+    let data_path = PathBuf::from(env::var_os("IANA_DATA_PATH").unwrap_or_else(|| "data".into()));
+    let overlay_paths: Vec<PathBuf> = env::var_os("IANA_OVERLAY")
+        .map(|paths| env::split_paths(&paths).filter(|path| !path.as_os_str().is_empty()).collect())
+        .unwrap_or_default();
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+
+    println!("cargo:rerun-if-changed={}", data_path.display());
+    for &(filename, _url) in IANA_RIR_FILES.iter() {
+        println!("cargo:rerun-if-changed={}", data_path.join(filename).display());
+    }
+    for filepath in overlay_paths.iter() {
+        println!("cargo:rerun-if-changed={}", filepath.display());
+    }
+
+    let missing: Vec<&str> = IANA_RIR_FILES.iter()
+        .map(|&(filename, _url)| filename)
+        .filter(|filename| !data_path.join(filename).is_file())
+        .collect();
+    if missing.len() == IANA_RIR_FILES.len() {
+        println!("cargo:warning=No RIR files in {:?}, building with empty tables. Run `iana sync` and rebuild.", data_path);
+    } else if !missing.is_empty() {
+        println!("cargo:warning=Missing {} in {:?}, the tables are incomplete.", missing.join(", "), data_path);
+    }
+    if !missing.is_empty() {
+        // Tests looking up real addresses need every file.
+        println!("cargo:rustc-cfg=iana_db_incomplete");
+    }
+
+    let mut record_sets = parse(&data_path);
+    let overlay = load_overlay(&overlay_paths);
+    if !overlay.is_empty() {
+        record_sets = overlay.apply(record_sets);
+    }
+
+    let mut records: Vec<&Record> = record_sets.iter()
+        .filter(|record| record.src_registry() != Registry::Iana)
+        .collect();
+    records.sort_unstable();
+    let (v4_records, v6_records): (Vec<&Record>, Vec<&Record>) = records.into_iter().partition(|record| record.is_ipv4());
+
+    codegen(&out_dir.join("v4_db.rs"), "IPV4_RECORDS", "u32", &v4_records).unwrap();
+    codegen(&out_dir.join("v6_db.rs"), "IPV6_RECORDS", "u128", &v6_records).unwrap();
+    if env::var_os("CARGO_FEATURE_PROVENANCE").is_some() {
+        codegen_provenance(&out_dir.join("provenance_db.rs"), &v4_records, &v6_records).unwrap();
+    }
+}
@@ -0,0 +1,404 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+use crate::format::csv_split;
+
+use std::fs;
+use std::process;
+use std::fmt::Write;
+
+
+const HEADER: &str = "\
+// Generated by `iana countries` from `src/country.csv`, edit the CSV instead.
+
+
+/// Country Code List: ISO 3166-1993 (E)
+///
+/// Download from: https://github.com/lukes/ISO-3166-Countries-with-Regional-Codes
+///
+/// Followed by the codes the RIRs use outside ISO 3166-1, see `Country::kind`.
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Country {
+";
+
+/// One row of `src/country.csv`.
+#[derive(Debug, Clone)]
+struct Row {
+    name: String,
+    /// Other names in use, e.g. `Ivory Coast`.
+    common_names: Vec<String>,
+    alpha2: String,
+    alpha3: String,
+    numeric: Option<u16>,
+    /// The smallest M49 area: intermediate region, sub-region or region.
+    area: String,
+    index: u8,
+    kind: String,
+    /// The RIR serving the country, empty for `ZZ`.
+    registry: String,
+}
+
+fn parse_csv(content: &str) -> Result<Vec<Row>, String> {
+    let mut lines = content.lines().enumerate();
+    let header = match lines.next() {
+        Some((_, header)) => csv_split(header),
+        None => return Err("Empty CSV".to_string()),
+    };
+    let column = |name: &str| header.iter().position(|field| field == name)
+        .ok_or_else(|| format!("Missing column {}", name));
+    let (name, alpha2, alpha3, numeric) = (column("name")?, column("alpha-2")?, column("alpha-3")?, column("country-code")?);
+    let (region, sub_region, intermediate_region) = (column("region")?, column("sub-region")?, column("intermediate-region")?);
+    let (index, kind, common_names) = (column("index")?, column("kind")?, column("common-names")?);
+    let registry = column("registry")?;
+
+    let mut rows: Vec<Row> = Vec::new();
+    for (line_idx, line) in lines {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let fields = csv_split(line);
+        let invalid = |descp: &str| format!("{} (line {}: {})", descp, line_idx + 1, line);
+        if fields.len() != header.len() {
+            return Err(invalid("Wrong number of fields"));
+        }
+
+        let area = [intermediate_region, sub_region, region].iter()
+            .map(|&column| fields[column].clone())
+            .find(|area| !area.is_empty())
+            .unwrap_or_default();
+        let row = Row {
+            name: fields[name].clone(),
+            common_names: fields[common_names].split(';')
+                .map(|common_name| common_name.trim().to_string())
+                .filter(|common_name| !common_name.is_empty())
+                .collect(),
+            alpha2: fields[alpha2].clone(),
+            alpha3: fields[alpha3].clone(),
+            numeric: match fields[numeric].as_str() {
+                "" => None,
+                numeric => Some(numeric.parse().map_err(|_| invalid("Invalid country-code"))?),
+            },
+            area,
+            index: fields[index].parse().map_err(|_| invalid("Invalid index"))?,
+            kind: fields[kind].clone(),
+            registry: fields[registry].clone(),
+        };
+
+        if row.alpha2.len() != 2 || !row.alpha2.bytes().all(|b| b.is_ascii_uppercase()) {
+            return Err(invalid("Invalid alpha-2"));
+        }
+        if !["iso", "region", "unspecified"].contains(&row.kind.as_str()) {
+            return Err(invalid("Invalid kind"));
+        }
+        if !["afrinic", "apnic", "arin", "lacnic", "ripencc", ""].contains(&row.registry.as_str()) {
+            return Err(invalid("Invalid registry"));
+        }
+        if (row.kind == "iso") != (row.alpha3.len() == 3 && row.numeric.is_some()) {
+            return Err(invalid("ISO countries need alpha-3 and country-code, the other codes neither"));
+        }
+        if rows.iter().any(|other| other.alpha2 == row.alpha2 || other.index == row.index
+                                   || (row.numeric.is_some() && other.numeric == row.numeric)
+                                   || (!row.alpha3.is_empty() && other.alpha3 == row.alpha3)) {
+            return Err(invalid("Duplicate code or index"));
+        }
+        rows.push(row);
+    }
+
+    rows.sort_by_key(|row| row.index);
+    Ok(rows)
+}
+
+/// `src/country_names.csv`: the locale tags, then the names of every country in these locales.
+fn parse_names_csv(content: &str, rows: &[Row]) -> Result<(Vec<String>, Vec<Vec<String>>), String> {
+    let mut lines = content.lines().enumerate();
+    let header = match lines.next() {
+        Some((_, header)) => csv_split(header),
+        None => return Err("Empty CSV".to_string()),
+    };
+    if header.first().map(|field| field.as_str()) != Some("alpha-2") || header.len() < 2 {
+        return Err("The first column must be alpha-2, followed by the locales".to_string());
+    }
+    let locales = header[1..].to_vec();
+
+    let mut names: Vec<Option<Vec<String>>> = vec![None; rows.len()];
+    for (line_idx, line) in lines {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let mut fields = csv_split(line);
+        let invalid = |descp: &str| format!("{} (line {}: {})", descp, line_idx + 1, line);
+        if fields.len() != header.len() {
+            return Err(invalid("Wrong number of fields"));
+        }
+        let row_idx = rows.iter().position(|row| row.alpha2 == fields[0])
+            .ok_or_else(|| invalid("Unknow alpha-2"))?;
+        if names[row_idx].is_some() {
+            return Err(invalid("Duplicate alpha-2"));
+        }
+        names[row_idx] = Some(fields.split_off(1));
+    }
+
+    let names = names.into_iter().zip(rows.iter())
+        .map(|(names, row)| names.ok_or_else(|| format!("Missing {}", row.alpha2)))
+        .collect::<Result<Vec<Vec<String>>, String>>()?;
+    Ok((locales, names))
+}
+
+/// `Sub-Saharan Africa` as `SubSaharanAfrica`.
+fn variant_name(name: &str) -> String {
+    name.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map(|first| first.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+        })
+        .collect()
+}
+
+fn codegen(rows: &[Row]) -> String {
+    let mut code = String::from(HEADER);
+    let others = |filter: &dyn Fn(&Row) -> bool| rows.iter()
+        .filter(|row| filter(row))
+        .map(|row| format!("Country::{}", row.alpha2))
+        .collect::<Vec<String>>()
+        .join(" | ");
+
+    for row in rows.iter() {
+        writeln!(code, "    {} = {}u8,", row.alpha2, row.index).unwrap();
+    }
+    code.push_str("}\n\n");
+
+    writeln!(code, "static COUNTRIES: [Country; {}] = [", rows.len()).unwrap();
+    for row in rows.iter() {
+        writeln!(code, "    Country::{},", row.alpha2).unwrap();
+    }
+    code.push_str("];\n\n\nimpl Country {\n\n");
+
+    code.push_str("    /// `Region` for the RIR codes covering several countries, `EU` and `AP`,\n");
+    code.push_str("    /// `Unspecified` for `ZZ`, which also stands for an empty code.\n");
+    code.push_str("    pub fn kind(&self) -> CountryKind {\n        match *self {\n");
+    for (kind, variant) in [("region", "Region"), ("unspecified", "Unspecified")] {
+        let codes = others(&|row| row.kind == kind);
+        if !codes.is_empty() {
+            writeln!(code, "            {} => CountryKind::{},", codes, variant).unwrap();
+        }
+    }
+    code.push_str("            _ => CountryKind::Iso,\n        }\n    }\n\n");
+
+    code.push_str("    pub fn from_index(index: u8) -> Result<Self, Error> {\n        match index {\n");
+    for row in rows.iter() {
+        writeln!(code, "            {} => Ok(Country::{}),", row.index, row.alpha2).unwrap();
+    }
+    code.push_str("            e => Err(Error::ParseCountryError(format!(\"Unknow Country Index ({})\", e))),\n        }\n    }\n\n");
+
+    code.push_str("    pub fn index(&self) -> u8 {\n        match *self {\n");
+    for row in rows.iter() {
+        writeln!(code, "            Country::{} => {},", row.alpha2, row.index).unwrap();
+    }
+    code.push_str("        }\n    }\n\n");
+
+    code.push_str("    pub fn name(&self) -> &'static str {\n        match *self {\n");
+    for row in rows.iter() {
+        writeln!(code, "            Country::{} => {:?},", row.alpha2, row.name).unwrap();
+    }
+    code.push_str("        }\n    }\n\n");
+
+    code.push_str("    /// Other names in use, e.g. `Ivory Coast` for `CI`, see `Country::parse_lenient`.\n");
+    code.push_str("    pub fn common_names(&self) -> &'static [&'static str] {\n        match *self {\n");
+    for row in rows.iter().filter(|row| !row.common_names.is_empty()) {
+        writeln!(code, "            Country::{} => &{:?},", row.alpha2, row.common_names).unwrap();
+    }
+    code.push_str("            _ => &[],\n        }\n    }\n\n");
+
+    let non_iso = others(&|row| row.kind != "iso");
+    code.push_str("    /// ISO 3166-1 alpha-3 code, e.g. `USA`, `None` for the RIR codes outside ISO 3166-1.\n");
+    code.push_str("    pub fn alpha3(&self) -> Option<&'static str> {\n        match *self {\n");
+    for row in rows.iter().filter(|row| row.kind == "iso") {
+        writeln!(code, "            Country::{} => Some({:?}),", row.alpha2, row.alpha3).unwrap();
+    }
+    if !non_iso.is_empty() {
+        writeln!(code, "            {} => None,", non_iso).unwrap();
+    }
+    code.push_str("        }\n    }\n\n");
+
+    code.push_str("    /// ISO 3166-1 numeric code, e.g. `840`, `None` for the RIR codes outside ISO 3166-1.\n");
+    code.push_str("    pub fn numeric(&self) -> Option<u16> {\n        match *self {\n");
+    for row in rows.iter().filter(|row| row.kind == "iso") {
+        writeln!(code, "            Country::{} => Some({}),", row.alpha2, row.numeric.unwrap()).unwrap();
+    }
+    if !non_iso.is_empty() {
+        writeln!(code, "            {} => None,", non_iso).unwrap();
+    }
+    code.push_str("        }\n    }\n\n");
+
+    code.push_str("    /// The smallest UN M49 area containing the country, see `Country::region`.\n");
+    code.push_str("    pub(crate) fn area(&self) -> Option<Region> {\n        match *self {\n");
+    for row in rows.iter().filter(|row| !row.area.is_empty()) {
+        writeln!(code, "            Country::{} => Some(Region::{}),", row.alpha2, variant_name(&row.area)).unwrap();
+    }
+    let no_area = others(&|row| row.area.is_empty());
+    if !no_area.is_empty() {
+        writeln!(code, "            {} => None,", no_area).unwrap();
+    }
+    code.push_str("        }\n    }\n\n");
+
+    code.push_str("    /// The RIR whose service region covers the country, `None` for `ZZ`.\n");
+    code.push_str("    pub fn registry(&self) -> Option<Registry> {\n        match *self {\n");
+    for row in rows.iter().filter(|row| !row.registry.is_empty()) {
+        writeln!(code, "            Country::{} => Some(Registry::{}),", row.alpha2, variant_name(&row.registry)).unwrap();
+    }
+    let no_registry = others(&|row| row.registry.is_empty());
+    if !no_registry.is_empty() {
+        writeln!(code, "            {} => None,", no_registry).unwrap();
+    }
+    code.push_str("        }\n    }\n\n");
+
+    code.push_str("    pub fn from_alpha3(code: &str) -> Result<Self, Error> {\n        match code {\n");
+    for row in rows.iter().filter(|row| row.kind == "iso") {
+        writeln!(code, "            {:?} => Ok(Country::{}),", row.alpha3, row.alpha2).unwrap();
+    }
+    code.push_str("            e => Err(Error::ParseCountryError(format!(\"Unknow Country Alpha-3 Code ({})\", e))),\n        }\n    }\n\n");
+
+    let mut by_numeric: Vec<&Row> = rows.iter().filter(|row| row.kind == "iso").collect();
+    by_numeric.sort_by_key(|row| row.numeric);
+    code.push_str("    pub fn from_numeric(code: u16) -> Result<Self, Error> {\n        match code {\n");
+    for row in by_numeric.iter() {
+        writeln!(code, "            {} => Ok(Country::{}),", row.numeric.unwrap(), row.alpha2).unwrap();
+    }
+    code.push_str("            e => Err(Error::ParseCountryError(format!(\"Unknow Country Numeric Code ({})\", e))),\n        }\n    }\n}\n\n\n");
+
+    code.push_str("impl FromStr for Country {\n    type Err = Error;\n    fn from_str(s: &str) -> Result<Self, Self::Err> {\n        match s {\n");
+    for row in rows.iter() {
+        writeln!(code, "            {:?} => Ok(Country::{}),", row.alpha2, row.alpha2).unwrap();
+    }
+    code.push_str("            e => Err(Error::ParseCountryError(format!(\"Unknow Country Code ({})\", e))),\n        }\n    }\n}\n\n\n");
+
+    code.push_str("impl fmt::Display for Country {\n    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {\n        match *self {\n");
+    for row in rows.iter() {
+        writeln!(code, "            Country::{} => write!(f, {:?}),", row.alpha2, row.alpha2).unwrap();
+    }
+    code.push_str("        }\n    }\n}\n");
+
+    code
+}
+
+fn codegen_names(rows: &[Row], locales: &[String], names: &[Vec<String>]) -> String {
+    let mut code = String::from("\
+// Generated by `iana countries` from `src/country_names.csv`, edit the CSV instead.
+
+
+/// Locales of `Country::localized_name`, as BCP 47 tags.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd)]
+pub enum Locale {
+");
+    for locale in locales.iter() {
+        writeln!(code, "    {},", variant_name(locale)).unwrap();
+    }
+    code.push_str("}\n\n");
+
+    writeln!(code, "const LOCALES: [Locale; {}] = [", locales.len()).unwrap();
+    for locale in locales.iter() {
+        writeln!(code, "    Locale::{},", variant_name(locale)).unwrap();
+    }
+    code.push_str("];\n\n\nimpl Locale {\n");
+
+    code.push_str("    pub fn from_index(index: u8) -> Result<Self, Error> {\n        match index {\n");
+    for (index, locale) in locales.iter().enumerate() {
+        writeln!(code, "            {} => Ok(Locale::{}),", index, variant_name(locale)).unwrap();
+    }
+    code.push_str("            e => Err(Error::ParseLocaleError(format!(\"Unknow Locale Index ({})\", e))),\n        }\n    }\n\n");
+
+    code.push_str("    pub fn index(&self) -> u8 {\n        match *self {\n");
+    for (index, locale) in locales.iter().enumerate() {
+        writeln!(code, "            Locale::{} => {},", variant_name(locale), index).unwrap();
+    }
+    code.push_str("        }\n    }\n\n");
+
+    code.push_str("    /// BCP 47 tag, e.g. `zh-Hant`.\n");
+    code.push_str("    pub fn tag(&self) -> &'static str {\n        match *self {\n");
+    for locale in locales.iter() {
+        writeln!(code, "            Locale::{} => {:?},", variant_name(locale), locale).unwrap();
+    }
+    code.push_str("        }\n    }\n}\n\n\nimpl Country {\n");
+
+    code.push_str("    /// Names in the order of `LOCALES`, empty when CLDR has none.\n");
+    writeln!(code, "    fn localized_names(&self) -> &'static [&'static str; {}] {{\n        match *self {{", locales.len()).unwrap();
+    for (row, names) in rows.iter().zip(names.iter()) {
+        writeln!(code, "            Country::{} => &{:?},", row.alpha2, names).unwrap();
+    }
+    code.push_str("        }\n    }\n}\n");
+
+    code
+}
+
+
+pub fn command<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("countries")
+        .about("Generate the Country enum and its tables from the ISO 3166 CSV")
+        .arg(
+            Arg::with_name("csv")
+                .long("csv")
+                .required(false)
+                .default_value("src/country.csv")
+                .help("Countries with their codes, M49 regions, index, kind, common names and registry")
+        )
+        .arg(
+            Arg::with_name("output")
+                .long("output")
+                .short("o")
+                .required(false)
+                .default_value("src/rir/country_table.rs")
+                .help("Generated Rust file, included by `src/country.rs`")
+        )
+        .arg(
+            Arg::with_name("names")
+                .long("names")
+                .required(false)
+                .default_value("src/country_names.csv")
+                .help("Country names by locale, from CLDR")
+        )
+        .arg(
+            Arg::with_name("names-output")
+                .long("names-output")
+                .required(false)
+                .default_value("src/locale_table.rs")
+                .help("Generated Rust file, included by `src/locale.rs`")
+        )
+}
+
+pub fn run(matches: &ArgMatches) {
+    let csv_path = matches.value_of("csv").unwrap();
+    let output = matches.value_of("output").unwrap();
+    let names_path = matches.value_of("names").unwrap();
+    let names_output = matches.value_of("names-output").unwrap();
+
+    let rows = fs::read_to_string(csv_path)
+        .map_err(|e| format!("{}", e))
+        .and_then(|content| parse_csv(&content))
+        .unwrap_or_else(|e| {
+            error!("{:?}: {}", csv_path, e);
+            process::exit(1);
+        });
+
+    if let Err(e) = fs::write(output, codegen(&rows)) {
+        error!("{:?}: {}", output, e);
+        process::exit(1);
+    }
+    info!("{} countries written to {:?}", rows.len(), output);
+
+    let (locales, names) = fs::read_to_string(names_path)
+        .map_err(|e| format!("{}", e))
+        .and_then(|content| parse_names_csv(&content, &rows))
+        .unwrap_or_else(|e| {
+            error!("{:?}: {}", names_path, e);
+            process::exit(1);
+        });
+
+    if let Err(e) = fs::write(names_output, codegen_names(&rows, &locales, &names)) {
+        error!("{:?}: {}", names_output, e);
+        process::exit(1);
+    }
+    info!("{} locales written to {:?}", locales.len(), names_output);
+}
@@ -0,0 +1,219 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+use iana::{Registry, Delta, Delegation, ChangeKind};
+
+use serde::Serialize;
+
+use crate::export::load_records;
+use crate::format::Format;
+
+use std::process;
+use std::path::Path;
+use std::net::IpAddr;
+use std::collections::BTreeMap;
+use std::io::{self, Write, BufWriter};
+
+
+/// Changes of one kind in one registry.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Summary {
+    pub ranges: usize,
+    pub ipv4_addrs: u128,
+    /// IPv6 sizes in /64 networks, a changed range smaller than a /64 counts as one.
+    pub ipv6_nets: u128,
+}
+
+/// Totals keyed by registry index and change kind.
+pub fn summarize(deltas: &[Delta]) -> BTreeMap<(u8, ChangeKind), Summary> {
+    let mut summaries: BTreeMap<(u8, ChangeKind), Summary> = BTreeMap::new();
+    for delta in deltas.iter() {
+        let summary = summaries.entry((delta.registry().index(), delta.kind())).or_default();
+        summary.ranges += 1;
+        match delta.first {
+            IpAddr::V4(_) => summary.ipv4_addrs += delta.size(),
+            IpAddr::V6(_) => summary.ipv6_nets = summary.ipv6_nets.saturating_add(((delta.size() - 1) >> 64) + 1),
+        }
+    }
+    summaries
+}
+
+fn delegation_columns(delta: &Delta) -> (String, String) {
+    let column = |delegation: Option<iana::Delegation>| delegation.map(|delegation| delegation.to_string()).unwrap_or_else(|| "-".to_string());
+    (column(delta.old), column(delta.new))
+}
+
+pub fn write_text<W: Write + ?Sized>(output: &mut W, deltas: &[Delta], summaries: &BTreeMap<(u8, ChangeKind), Summary>, summary_only: bool) -> io::Result<()> {
+    if !summary_only {
+        for delta in deltas.iter() {
+            let (old, new) = delegation_columns(delta);
+            writeln!(output, "{}\t{}\t{} - {}\t{}\t{}", delta.kind(), delta.registry(), delta.first, delta.last, old, new)?;
+        }
+    }
+    for (&(registry, kind), summary) in summaries.iter() {
+        writeln!(output, "# {} {}: {} ranges, {} IPv4 addresses, {} IPv6 /64s",
+                 Registry::from_index(registry).unwrap(), kind, summary.ranges, summary.ipv4_addrs, summary.ipv6_nets)?;
+    }
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct JsonDelta {
+    kind: ChangeKind,
+    registry: Registry,
+    first: IpAddr,
+    last: IpAddr,
+    old: Option<Delegation>,
+    new: Option<Delegation>,
+}
+
+#[derive(Serialize)]
+struct JsonSummary {
+    summary: bool,
+    registry: Registry,
+    kind: ChangeKind,
+    ranges: usize,
+    ipv4_addrs: u128,
+    ipv6_nets: u128,
+}
+
+/// JSON Lines, one object per change then one per registry and kind with `"summary":true`.
+pub fn write_json<W: Write + ?Sized>(output: &mut W, deltas: &[Delta], summaries: &BTreeMap<(u8, ChangeKind), Summary>, summary_only: bool) -> io::Result<()> {
+    if !summary_only {
+        for delta in deltas.iter() {
+            let row = JsonDelta {
+                kind: delta.kind(),
+                registry: delta.registry(),
+                first: delta.first,
+                last: delta.last,
+                old: delta.old,
+                new: delta.new,
+            };
+            serde_json::to_writer(&mut *output, &row)?;
+            writeln!(output)?;
+        }
+    }
+    for (&(registry, kind), summary) in summaries.iter() {
+        let row = JsonSummary {
+            summary: true,
+            registry: Registry::from_index(registry).unwrap(),
+            kind,
+            ranges: summary.ranges,
+            ipv4_addrs: summary.ipv4_addrs,
+            ipv6_nets: summary.ipv6_nets,
+        };
+        serde_json::to_writer(&mut *output, &row)?;
+        writeln!(output)?;
+    }
+    Ok(())
+}
+
+
+pub fn command<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("diff")
+        .about("Compare two builds and report added, removed, reassigned, country and status changes")
+        .arg(
+            Arg::with_name("old")
+                .long("old")
+                .takes_value(true)
+                .required(true)
+                .help("Data path of the previous build")
+        )
+        .arg(
+            Arg::with_name("new")
+                .long("new")
+                .takes_value(true)
+                .required(false)
+                .default_value("data")
+                .help("Data path of the current build")
+        )
+        .arg(crate::overlay_arg())
+        .arg(
+            Arg::with_name("format")
+                .long("format")
+                .required(false)
+                .default_value("text")
+                .possible_values(&["text", "json"])
+                .help("Output format, json writes JSON Lines")
+        )
+        .arg(
+            Arg::with_name("summary")
+                .long("summary")
+                .help("Only print the totals per registry and kind of change")
+        )
+        .arg(
+            Arg::with_name("alert-ipv4")
+                .long("alert-ipv4")
+                .takes_value(true)
+                .help("Exit with status 3 when a registry moves more than this many IPv4 addresses")
+        )
+}
+
+pub fn run(matches: &ArgMatches) {
+    let format = matches.value_of("format").unwrap().parse::<Format>().unwrap();
+    let summary_only = matches.is_present("summary");
+    let alert_ipv4 = matches.value_of("alert-ipv4").map(|n| n.parse::<u128>().unwrap_or_else(|e| {
+        error!("--alert-ipv4: {}", e);
+        process::exit(2);
+    }));
+
+    let old = load_records(matches, Path::new(matches.value_of("old").unwrap()));
+    let new = load_records(matches, Path::new(matches.value_of("new").unwrap()));
+
+    let deltas = iana::diff(&old, &new);
+    let summaries = summarize(&deltas);
+
+    let stdout = io::stdout();
+    let mut output = BufWriter::new(stdout.lock());
+    let result = match format {
+        Format::Json => write_json(&mut output, &deltas, &summaries, summary_only),
+        _ => write_text(&mut output, &deltas, &summaries, summary_only),
+    };
+    if let Err(e) = result.and_then(|_| output.flush()) {
+        error!("{}", e);
+        process::exit(1);
+    }
+
+    if let Some(limit) = alert_ipv4 {
+        let mut moved: BTreeMap<u8, u128> = BTreeMap::new();
+        for (&(registry, _), summary) in summaries.iter() {
+            *moved.entry(registry).or_default() += summary.ipv4_addrs;
+        }
+        let mut alert = false;
+        for (&registry, &addrs) in moved.iter().filter(|&(_, &addrs)| addrs > limit) {
+            warn!("{} moved {} IPv4 addresses, more than {}", Registry::from_index(registry).unwrap(), addrs, limit);
+            alert = true;
+        }
+        if alert {
+            process::exit(3);
+        }
+    }
+}
+
+
+#[test]
+fn test_write_json() {
+    use iana::{Country, Status};
+
+    let delegation = Delegation { registry: Registry::Afrinic, country: Country::ZA, status: Status::Allocated };
+    let deltas = vec![
+        Delta { first: "41.0.0.0".parse().unwrap(), last: "41.0.0.255".parse().unwrap(), old: None, new: Some(delegation) },
+        Delta {
+            first: "2001:4200::".parse().unwrap(),
+            last: "2001:4200:ffff:ffff:ffff:ffff:ffff:ffff".parse().unwrap(),
+            old: Some(delegation),
+            new: Some(Delegation { country: Country::EG, ..delegation }),
+        },
+    ];
+
+    let mut output = Vec::new();
+    write_json(&mut output, &deltas, &summarize(&deltas), false).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "\
+{\"kind\":\"added\",\"registry\":\"afrinic\",\"first\":\"41.0.0.0\",\"last\":\"41.0.0.255\",\"old\":null,\
+\"new\":{\"registry\":\"afrinic\",\"country\":\"ZA\",\"status\":\"allocated\"}}
+{\"kind\":\"country\",\"registry\":\"afrinic\",\"first\":\"2001:4200::\",\"last\":\"2001:4200:ffff:ffff:ffff:ffff:ffff:ffff\",\
+\"old\":{\"registry\":\"afrinic\",\"country\":\"ZA\",\"status\":\"allocated\"},\
+\"new\":{\"registry\":\"afrinic\",\"country\":\"EG\",\"status\":\"allocated\"}}
+{\"summary\":true,\"registry\":\"afrinic\",\"kind\":\"added\",\"ranges\":1,\"ipv4_addrs\":256,\"ipv6_nets\":0}
+{\"summary\":true,\"registry\":\"afrinic\",\"kind\":\"country\",\"ranges\":1,\"ipv4_addrs\":0,\"ipv6_nets\":4294967296}
+");
+}
@@ -0,0 +1,125 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+use iana::Extractor;
+
+use std::iter;
+use std::process;
+use std::fs::File;
+use std::io::{self, Write, BufRead, BufReader, BufWriter};
+
+
+pub fn command<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("enrich")
+        .about("Append country, registry and status to every line of a log")
+        .arg(
+            Arg::with_name("field")
+                .long("field")
+                .short("f")
+                .takes_value(true)
+                .help("Only search this field for an address, counted from 1 (default: whole line)")
+        )
+        .arg(
+            Arg::with_name("delimiter")
+                .long("delimiter")
+                .short("d")
+                .takes_value(true)
+                .help("Field delimiter, a single character (default: whitespace)")
+        )
+        .arg(
+            Arg::with_name("output-delimiter")
+                .long("output-delimiter")
+                .default_value("\t")
+                .help("Separator written before each annotation")
+        )
+        .arg(
+            Arg::with_name("file")
+                .multiple(true)
+                .help("Log files, stdin when omitted or `-`")
+        )
+}
+
+/// Lines without their terminator, invalid UTF-8 is replaced with `U+FFFD` instead of failing.
+fn lossy_lines<R: BufRead>(mut input: R) -> impl Iterator<Item = io::Result<String>> {
+    iter::from_fn(move || {
+        let mut buf = Vec::new();
+        match input.read_until(b'\n', &mut buf) {
+            Ok(0) => None,
+            Ok(_) => {
+                while buf.last().is_some_and(|&b| b == b'\n' || b == b'\r') {
+                    buf.pop();
+                }
+                Some(Ok(String::from_utf8(buf).unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned())))
+            },
+            Err(e) => Some(Err(e)),
+        }
+    })
+}
+
+fn enrich<R: BufRead, W: Write>(input: R, output: &mut W, extractor: &Extractor, delimiter: &str) -> io::Result<()> {
+    let mut error = None;
+    let lines = lossy_lines(input).map_while(|line| line.map_err(|e| error = Some(e)).ok());
+    for enriched in iana::enrich(lines, *extractor) {
+        enriched.write_annotated(output, delimiter)?;
+    }
+
+    error.map_or(Ok(()), Err)
+}
+
+pub fn run(matches: &ArgMatches) {
+    let mut extractor = Extractor::new();
+    if let Some(field) = matches.value_of("field") {
+        match field.parse::<usize>() {
+            Ok(field) if field > 0 => extractor = extractor.field(field),
+            _ => {
+                error!("Invalid field ({}), fields are counted from 1", field);
+                process::exit(2);
+            }
+        }
+    }
+    if let Some(delimiter) = matches.value_of("delimiter") {
+        let mut chars = delimiter.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => extractor = extractor.delimiter(c),
+            _ => {
+                error!("Invalid delimiter ({:?}), expect a single character", delimiter);
+                process::exit(2);
+            }
+        }
+    }
+    let delimiter = matches.value_of("output-delimiter").unwrap();
+    let files: Vec<&str> = matches.values_of("file").map(|values| values.collect()).unwrap_or_default();
+
+    let stdout = io::stdout();
+    let mut output = BufWriter::with_capacity(64 * 1024, stdout.lock());
+
+    let result = if files.is_empty() || files == ["-"] {
+        let stdin = io::stdin();
+        let input = BufReader::with_capacity(64 * 1024, stdin.lock());
+        enrich(input, &mut output, &extractor, delimiter)
+    } else {
+        files.iter().try_for_each(|filepath| {
+            let input = BufReader::with_capacity(64 * 1024, File::open(filepath)?);
+            enrich(input, &mut output, &extractor, delimiter)
+        })
+    };
+
+    // Flush what was enriched before a read error too.
+    let flushed = output.flush();
+    if let Err(e) = result.and(flushed) {
+        // The reader went away (`| head`), not worth a message.
+        if e.kind() != io::ErrorKind::BrokenPipe {
+            error!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+
+#[test]
+fn test_enrich_invalid_utf8() {
+    let input = b"192.0.2.1 ok\n\xff\xfe 192.0.2.2 bad\r\n10.0.0.1 after";
+    let mut output = Vec::new();
+    enrich(&input[..], &mut output, &Extractor::new(), "\t").unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(),
+               "192.0.2.1 ok\t-\t-\t-\n\u{FFFD}\u{FFFD} 192.0.2.2 bad\t-\t-\t-\n10.0.0.1 after\t-\t-\t-\n");
+}
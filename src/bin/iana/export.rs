@@ -0,0 +1,467 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+use iana::{Record, IpBlock, Country, Region, Continent, Registry, Status, Origin, MmdbWriter, IpSet, Ipv4Cidr, Ipv6Cidr, Overlay};
+
+use serde::Serialize;
+
+use crate::format::csv_field;
+use crate::firewall::{write_ipset, write_nft};
+use crate::routes::{Via, write_bird, write_ip_route, write_wireguard};
+
+use std::fs::{self, File};
+use std::process;
+use std::path::Path;
+use std::net::IpAddr;
+use std::collections::HashSet;
+use std::io::{self, Write, BufWriter};
+
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ExportFormat {
+    Records,
+    Mmdb,
+    Ipset,
+    Nft,
+    Bird,
+    IpRoute,
+    Wireguard,
+    Csv,
+    Jsonl,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Family {
+    Ipv4,
+    Ipv6,
+    All,
+}
+
+/// Parse the data path and apply the `--overlay` files.
+pub fn load_records(matches: &ArgMatches, data_path: &Path) -> HashSet<Record> {
+    let mut overlay = Overlay::new();
+    for filepath in matches.values_of("overlay").into_iter().flatten() {
+        let result = fs::read_to_string(filepath)
+            .map_err(|e| format!("{}", e))
+            .and_then(|content| overlay.parse_csv(&content).map_err(|e| format!("{:?}", e)));
+        if let Err(e) = result {
+            error!("Overlay {:?}: {}", filepath, e);
+            process::exit(1);
+        }
+    }
+
+    let records = iana::parse(data_path);
+    if overlay.is_empty() {
+        return records;
+    }
+    info!("Apply {} overlay rows", overlay.len());
+    overlay.apply(records)
+}
+
+/// Sorted RIR records of one address family, or the IANA delegations to the RIRs.
+pub fn select(records: &HashSet<Record>, family: Family, iana: bool) -> Vec<&Record> {
+    let mut records: Vec<&Record> = records.iter().filter(|record| {
+        let family_matches = match family {
+            Family::Ipv4 => record.is_ipv4(),
+            Family::Ipv6 => record.is_ipv6(),
+            Family::All => true,
+        };
+        let source_matches = if iana {
+            record.dst_registry().is_some() && record.src_registry() == Registry::Iana
+        } else {
+            record.src_registry() != Registry::Iana
+        };
+
+        family_matches && source_matches
+    }).collect();
+
+    // IPv4 records sort before IPv6 records.
+    records.sort_unstable();
+    records
+}
+
+/// Aggregated IPv4 and IPv6 networks of the records, or with `invert` of every public address
+/// outside them, the blocks that are not globally reachable (private, loopback, multicast, ...)
+/// left out.
+pub fn cidrs(records: &[&Record], family: Family, invert: bool) -> (Vec<Ipv4Cidr>, Vec<Ipv6Cidr>) {
+    let mut set: IpSet = records.iter().cloned().collect();
+    if invert {
+        let public = set.complement().difference(&IpSet::special_purpose());
+        set = match family {
+            Family::Ipv4 => public.ipv4(),
+            Family::Ipv6 => public.ipv6(),
+            Family::All => public,
+        };
+    }
+
+    (set.ipv4_cidrs(), set.ipv6_cidrs())
+}
+
+pub fn write_records<W: Write + ?Sized>(output: &mut W, records: &[&Record]) -> io::Result<()> {
+    for record in records.iter() {
+        writeln!(output, "{}", record)?;
+    }
+    Ok(())
+}
+
+/// The CIDRs of a record's block, an IPv4 range may need several.
+fn block_cidrs(ip_block: IpBlock) -> Vec<String> {
+    match ip_block {
+        IpBlock::Ipv4Range(v4_range) => v4_range.cidrs().map(|cidr| cidr.to_string()).collect(),
+        IpBlock::Ipv4Cidr(v4_cidr) => vec![v4_cidr.to_string()],
+        IpBlock::Ipv6Cidr(v6_cidr) => vec![v6_cidr.to_string()],
+    }
+}
+
+/// `YYYYMMDD` as `YYYY-MM-DD`, `None` when it is not a valid date.
+fn iso_date(date: u32) -> Option<String> {
+    iana::parse_date(&format!("{:08}", date))
+        .map(|date| format!("{:04}-{:02}-{:02}", date / 10000, date / 100 % 100, date % 100))
+}
+
+pub fn write_csv<W: Write + ?Sized>(output: &mut W, records: &[&Record]) -> io::Result<()> {
+    writeln!(output, "family,first,last,cidrs,country,registry,status,date,opaque_id,origin")?;
+    for record in records.iter() {
+        let ip_block = record.ip_block();
+        writeln!(output, "{},{},{},{},{},{},{},{},{},{}",
+                 record.ip_version(),
+                 ip_block.first(),
+                 ip_block.last(),
+                 block_cidrs(ip_block).join(" "),
+                 record.country(),
+                 record.src_registry(),
+                 record.status(),
+                 record.date().and_then(iso_date).unwrap_or_default(),
+                 record.opaque_id().map(csv_field).unwrap_or_default(),
+                 record.origin())?;
+    }
+    Ok(())
+}
+
+/// One line of the jsonl format, the columns of the csv format.
+#[derive(Serialize)]
+struct JsonRecord<'a> {
+    family: String,
+    first: IpAddr,
+    last: IpAddr,
+    cidrs: Vec<String>,
+    country: Country,
+    registry: Registry,
+    status: Status,
+    date: Option<String>,
+    opaque_id: Option<&'a str>,
+    origin: Origin,
+}
+
+pub fn write_jsonl<W: Write + ?Sized>(output: &mut W, records: &[&Record]) -> io::Result<()> {
+    for record in records.iter() {
+        let ip_block = record.ip_block();
+        let row = JsonRecord {
+            family: record.ip_version(),
+            first: ip_block.first(),
+            last: ip_block.last(),
+            cidrs: block_cidrs(ip_block),
+            country: record.country(),
+            registry: record.src_registry(),
+            status: record.status(),
+            date: record.date().and_then(iso_date),
+            opaque_id: record.opaque_id(),
+            origin: record.origin(),
+        };
+        serde_json::to_writer(&mut *output, &row)?;
+        writeln!(output)?;
+    }
+    Ok(())
+}
+
+
+pub fn command<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("export")
+        .about("Export the merged RIR records")
+        .arg(crate::data_path_arg())
+        .arg(crate::overlay_arg())
+        .arg(
+            Arg::with_name("family")
+                .long("family")
+                .required(false)
+                .default_value("all")
+                .possible_values(&["ipv4", "ipv6", "all"])
+                .help("Address family to export")
+        )
+        .arg(
+            Arg::with_name("format")
+                .long("format")
+                .required(false)
+                .default_value("records")
+                .possible_values(&["records", "mmdb", "ipset", "nft", "bird", "ip-route", "wireguard", "csv", "jsonl"])
+                .help("Output format, mmdb writes a GeoLite2-Country compatible MaxMind DB, \
+                       ipset and nft write aggregated sets for `ipset restore` and `nft -f`, \
+                       bird, ip-route and wireguard write aggregated routes, \
+                       csv and jsonl write one row per record with explicit columns")
+        )
+        .arg(
+            Arg::with_name("invert")
+                .long("invert")
+                .help("Export every globally reachable address outside the selection, e.g. to route all but \
+                       one country, only for the ipset, nft, bird, ip-route and wireguard formats")
+        )
+        .arg(
+            Arg::with_name("via")
+                .long("via")
+                .takes_value(true)
+                .help("Gateway address or interface for bird and ip-route, \
+                       a gateway only routes its own address family")
+        )
+        .arg(
+            Arg::with_name("country")
+                .long("country")
+                .takes_value(true)
+                .multiple(true)
+                .use_delimiter(true)
+                .help("Only export these countries, e.g. `CN,RU`, `deu` or `Germany`")
+        )
+        .arg(
+            Arg::with_name("region")
+                .long("region")
+                .takes_value(true)
+                .multiple(true)
+                .use_delimiter(true)
+                .help("Only export the countries within these UN M49 regions, by name or code, e.g. `Western Africa` or `011`")
+        )
+        .arg(
+            Arg::with_name("continent")
+                .long("continent")
+                .takes_value(true)
+                .multiple(true)
+                .use_delimiter(true)
+                .help("Only export the countries of these continents, e.g. `AF,EU`")
+        )
+        .arg(
+            Arg::with_name("registry")
+                .long("registry")
+                .takes_value(true)
+                .multiple(true)
+                .use_delimiter(true)
+                .help("Only export these registries, e.g. `apnic`")
+        )
+        .arg(
+            Arg::with_name("set-name")
+                .long("set-name")
+                .required(false)
+                .default_value("iana")
+                .help("Set name for ipset and nft, protocol name for bird, `_v4` and `_v6` are appended")
+        )
+        .arg(
+            Arg::with_name("nft-table")
+                .long("nft-table")
+                .required(false)
+                .default_value("inet iana")
+                .help("Family and name of the nftables table holding the sets")
+        )
+        .arg(
+            Arg::with_name("iana")
+                .long("iana")
+                .help("Export the IANA delegations to the RIRs instead of the RIR records")
+        )
+        .arg(
+            Arg::with_name("output")
+                .long("output")
+                .short("o")
+                .required(false)
+                .default_value("-")
+                .help("Output file, `-` for stdout")
+        )
+}
+
+pub fn run(matches: &ArgMatches) {
+    let data_path = Path::new(matches.value_of("data-path").unwrap());
+    let family = match matches.value_of("family").unwrap() {
+        "ipv4" => Family::Ipv4,
+        "ipv6" => Family::Ipv6,
+        _ => Family::All,
+    };
+
+    let format = match matches.value_of("format").unwrap() {
+        "mmdb" => ExportFormat::Mmdb,
+        "ipset" => ExportFormat::Ipset,
+        "nft" => ExportFormat::Nft,
+        "bird" => ExportFormat::Bird,
+        "ip-route" => ExportFormat::IpRoute,
+        "wireguard" => ExportFormat::Wireguard,
+        "csv" => ExportFormat::Csv,
+        "jsonl" => ExportFormat::Jsonl,
+        _ => ExportFormat::Records,
+    };
+    let invert = matches.is_present("invert");
+    if invert && [ExportFormat::Records, ExportFormat::Mmdb, ExportFormat::Csv, ExportFormat::Jsonl].contains(&format) {
+        error!("--invert is only supported by the ipset, nft, bird, ip-route and wireguard formats");
+        process::exit(2);
+    }
+    let via = match matches.value_of("via") {
+        Some(via) => Some(via.parse::<Via>().unwrap()),
+        None if format == ExportFormat::Bird || format == ExportFormat::IpRoute => {
+            error!("--via is required for the bird and ip-route formats");
+            process::exit(2);
+        },
+        None => None,
+    };
+    let countries: Vec<Country> = matches.values_of("country").into_iter().flatten()
+        .map(|country| Country::parse_lenient(country).unwrap_or_else(|e| {
+            error!("{:?}", e);
+            process::exit(2);
+        }))
+        .collect();
+    let regions: Vec<Region> = matches.values_of("region").into_iter().flatten()
+        .map(|region| region.parse().unwrap_or_else(|e| {
+            error!("{:?}", e);
+            process::exit(2);
+        }))
+        .collect();
+    let continents: Vec<Continent> = matches.values_of("continent").into_iter().flatten()
+        .map(|continent| continent.parse().unwrap_or_else(|e| {
+            error!("{:?}", e);
+            process::exit(2);
+        }))
+        .collect();
+    let registries: Vec<Registry> = matches.values_of("registry").into_iter().flatten()
+        .map(|registry| registry.parse().unwrap_or_else(|e| {
+            error!("{:?}", e);
+            process::exit(2);
+        }))
+        .collect();
+    let set_name = matches.value_of("set-name").unwrap();
+    let nft_table = matches.value_of("nft-table").unwrap();
+
+    let record_sets = load_records(matches, data_path);
+    let mut records = select(&record_sets, family, matches.is_present("iana"));
+    records.retain(|record| {
+        let country = record.country();
+        (countries.is_empty() || countries.contains(&country))
+            && (regions.is_empty() || regions.iter().any(|&region| country.is_in(region)))
+            && (continents.is_empty() || country.continent().map(|continent| continents.contains(&continent)).unwrap_or(false))
+            && (registries.is_empty() || registries.contains(&record.src_registry()))
+    });
+
+    let write = |output: &mut dyn Write| -> io::Result<()> {
+        match format {
+            ExportFormat::Records => write_records(output, &records),
+            ExportFormat::Mmdb => MmdbWriter::new().write(output, records.iter().cloned()),
+            ExportFormat::Ipset => {
+                let (v4, v6) = cidrs(&records, family, invert);
+                write_ipset(output, set_name, &v4, &v6)
+            },
+            ExportFormat::Nft => {
+                let (v4, v6) = cidrs(&records, family, invert);
+                write_nft(output, nft_table, set_name, &v4, &v6)
+            },
+            ExportFormat::Bird => {
+                let (v4, v6) = cidrs(&records, family, invert);
+                write_bird(output, set_name, via.as_ref().unwrap(), &v4, &v6)
+            },
+            ExportFormat::IpRoute => {
+                let (v4, v6) = cidrs(&records, family, invert);
+                write_ip_route(output, via.as_ref().unwrap(), &v4, &v6)
+            },
+            ExportFormat::Wireguard => {
+                let (v4, v6) = cidrs(&records, family, invert);
+                write_wireguard(output, &v4, &v6)
+            },
+            ExportFormat::Csv => write_csv(output, &records),
+            ExportFormat::Jsonl => write_jsonl(output, &records),
+        }
+    };
+
+    let result = match matches.value_of("output").unwrap() {
+        "-" => {
+            let stdout = io::stdout();
+            let mut output = BufWriter::new(stdout.lock());
+            write(&mut output).and_then(|_| output.flush())
+        },
+        filepath => {
+            File::create(filepath)
+                .and_then(|file| {
+                    let mut output = BufWriter::new(file);
+                    write(&mut output).and_then(|_| output.flush())
+                })
+        },
+    };
+
+    if let Err(e) = result {
+        error!("{}", e);
+        process::exit(1);
+    }
+}
+
+
+#[test]
+fn test_cidrs() {
+    let records = iana::parse_str("2|afrinic|20190911|3|00000000|20190911|00000\n\
+                                   afrinic|*|ipv4|*|2|summary\n\
+                                   afrinic|ZA|ipv4|41.0.0.0|2097152|20071126|allocated\n\
+                                   afrinic|ZA|ipv4|41.32.0.0|1048576|20091105|allocated\n\
+                                   afrinic|ZA|ipv6|2001:4200::|32|20051021|allocated\n");
+    let records: Vec<&Record> = records.iter().collect();
+
+    let (v4, v6) = cidrs(&records, Family::All, false);
+    assert_eq!(v4, vec!["41.0.0.0/11".parse().unwrap(), "41.32.0.0/12".parse().unwrap()]);
+    assert_eq!(v6, vec!["2001:4200::/32".parse().unwrap()]);
+
+    let (v4, v6) = cidrs(&records, Family::Ipv4, true);
+    assert!(v6.is_empty());
+    let inverted: IpSet = v4.iter().fold(IpSet::new(), |mut set, &cidr| {
+        set.insert_ipv4_cidr(cidr);
+        set
+    });
+    for ip in ["1.1.1.1", "40.255.255.255", "41.48.0.0", "223.255.255.255", "192.31.196.1"].iter() {
+        assert!(inverted.contains(&ip.parse().unwrap()), "{}", ip);
+    }
+    for ip in ["41.0.0.1", "41.47.255.255", "10.1.1.1", "127.0.0.1", "192.168.1.1", "224.0.0.1"].iter() {
+        assert!(!inverted.contains(&ip.parse().unwrap()), "{}", ip);
+    }
+
+    let (v4, v6) = cidrs(&records, Family::All, true);
+    assert!(!v4.is_empty());
+    let inverted: IpSet = v6.iter().fold(IpSet::new(), |mut set, &cidr| {
+        set.insert_ipv6_cidr(cidr);
+        set
+    });
+    assert!(inverted.contains(&"2a00:1450::1".parse().unwrap()));
+    assert!(!inverted.contains(&"2001:4200::1".parse().unwrap()));
+    assert!(!inverted.contains(&"fe80::1".parse().unwrap()));
+    assert!(!inverted.contains(&"ff02::1".parse().unwrap()));
+}
+
+#[test]
+fn test_iso_date() {
+    assert_eq!(iso_date(20190911), Some("2019-09-11".to_string()));
+    assert_eq!(iso_date(0), None);
+    assert_eq!(iso_date(2019), None);
+    assert_eq!(iso_date(20191345), None);
+    assert_eq!(iso_date(120190911), None);
+}
+
+#[test]
+fn test_write_csv_jsonl() {
+    let mut records = iana::parse_str("2|afrinic|20190911|3|00000000|20190911|00000\n\
+                                       afrinic|ZA|ipv4|41.0.0.0|1536|20071126|allocated|F36B9F4B\n\
+                                       afrinic|ZZ|ipv4|41.0.8.0|256||reserved\n\
+                                       afrinic|ZA|ipv6|2001:4200::|32|20051021|allocated\n");
+    records[1].opaque_id = Some("Quoted \"id\", with comma".to_string());
+    let records: Vec<&Record> = records.iter().collect();
+
+    let mut output = Vec::new();
+    write_csv(&mut output, &records).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "\
+family,first,last,cidrs,country,registry,status,date,opaque_id,origin
+ipv4,41.0.0.0,41.0.5.255,41.0.0.0/22 41.0.4.0/23,ZA,afrinic,allocated,2007-11-26,F36B9F4B,rir
+ipv4,41.0.8.0,41.0.8.255,41.0.8.0/24,ZZ,afrinic,reserved,,\"Quoted \"\"id\"\", with comma\",rir
+ipv6,2001:4200::,2001:4200:ffff:ffff:ffff:ffff:ffff:ffff,2001:4200::/32,ZA,afrinic,allocated,2005-10-21,,rir
+");
+
+    let mut output = Vec::new();
+    write_jsonl(&mut output, &records).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "\
+{\"family\":\"ipv4\",\"first\":\"41.0.0.0\",\"last\":\"41.0.5.255\",\"cidrs\":[\"41.0.0.0/22\",\"41.0.4.0/23\"],\"country\":\"ZA\",\"registry\":\"afrinic\",\"status\":\"allocated\",\"date\":\"2007-11-26\",\"opaque_id\":\"F36B9F4B\",\"origin\":\"rir\"}
+{\"family\":\"ipv4\",\"first\":\"41.0.8.0\",\"last\":\"41.0.8.255\",\"cidrs\":[\"41.0.8.0/24\"],\"country\":\"ZZ\",\"registry\":\"afrinic\",\"status\":\"reserved\",\"date\":null,\"opaque_id\":\"Quoted \\\"id\\\", with comma\",\"origin\":\"rir\"}
+{\"family\":\"ipv6\",\"first\":\"2001:4200::\",\"last\":\"2001:4200:ffff:ffff:ffff:ffff:ffff:ffff\",\"cidrs\":[\"2001:4200::/32\"],\"country\":\"ZA\",\"registry\":\"afrinic\",\"status\":\"allocated\",\"date\":\"2005-10-21\",\"opaque_id\":null,\"origin\":\"rir\"}
+");
+}
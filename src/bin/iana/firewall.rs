@@ -0,0 +1,131 @@
+use iana::{Ipv4Cidr, Ipv6Cidr};
+
+use std::cmp;
+use std::io::{self, Write};
+
+
+fn write_ipset_family<W, T>(output: &mut W, name: &str, family: &str, cidrs: &[T]) -> io::Result<()>
+    where W: Write + ?Sized,
+          T: ToString
+{
+    let tmp_name = format!("{}-tmp", name);
+    let maxelem = cmp::max(65536, cidrs.len());
+
+    writeln!(output, "create {} hash:net family {} maxelem {} -exist", name, family, maxelem)?;
+    writeln!(output, "create {} hash:net family {} maxelem {} -exist", tmp_name, family, maxelem)?;
+    writeln!(output, "flush {}", tmp_name)?;
+    for cidr in cidrs.iter() {
+        writeln!(output, "add {} {}", tmp_name, cidr.to_string())?;
+    }
+    writeln!(output, "swap {} {}", tmp_name, name)?;
+    writeln!(output, "destroy {}", tmp_name)
+}
+
+/// `ipset restore` script, the sets are filled under a temporary name and swapped in.
+pub fn write_ipset<W: Write + ?Sized>(output: &mut W, name: &str, v4: &[Ipv4Cidr], v6: &[Ipv6Cidr]) -> io::Result<()> {
+    write_ipset_family(output, &format!("{}_v4", name), "inet", v4)?;
+    write_ipset_family(output, &format!("{}_v6", name), "inet6", v6)
+}
+
+fn write_nft_family<W, T>(output: &mut W, table: &str, name: &str, type_: &str, cidrs: &[T]) -> io::Result<()>
+    where W: Write + ?Sized,
+          T: ToString
+{
+    writeln!(output, "add set {} {} {{ type {}; flags interval; }}", table, name, type_)?;
+    writeln!(output, "flush set {} {}", table, name)?;
+    if !cidrs.is_empty() {
+        writeln!(output, "add element {} {} {{", table, name)?;
+        for (i, cidr) in cidrs.iter().enumerate() {
+            let separator = if i + 1 < cidrs.len() { "," } else { "" };
+            writeln!(output, "    {}{}", cidr.to_string(), separator)?;
+        }
+        writeln!(output, "}}")?;
+    }
+    Ok(())
+}
+
+/// `nft -f` script, which nftables applies as one transaction.
+pub fn write_nft<W: Write + ?Sized>(output: &mut W, table: &str, name: &str, v4: &[Ipv4Cidr], v6: &[Ipv6Cidr]) -> io::Result<()> {
+    writeln!(output, "add table {}", table)?;
+    write_nft_family(output, table, &format!("{}_v4", name), "ipv4_addr", v4)?;
+    write_nft_family(output, table, &format!("{}_v6", name), "ipv6_addr", v6)
+}
+
+
+#[test]
+fn test_write_ipset() {
+    let v4: Vec<Ipv4Cidr> = vec!["41.0.0.0/11".parse().unwrap(), "102.0.0.0/8".parse().unwrap()];
+    let v6: Vec<Ipv6Cidr> = vec!["2c0f:f000::/20".parse().unwrap()];
+
+    let mut output = Vec::new();
+    write_ipset(&mut output, "africa", &v4, &v6).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "\
+create africa_v4 hash:net family inet maxelem 65536 -exist
+create africa_v4-tmp hash:net family inet maxelem 65536 -exist
+flush africa_v4-tmp
+add africa_v4-tmp 41.0.0.0/11
+add africa_v4-tmp 102.0.0.0/8
+swap africa_v4-tmp africa_v4
+destroy africa_v4-tmp
+create africa_v6 hash:net family inet6 maxelem 65536 -exist
+create africa_v6-tmp hash:net family inet6 maxelem 65536 -exist
+flush africa_v6-tmp
+add africa_v6-tmp 2c0f:f000::/20
+swap africa_v6-tmp africa_v6
+destroy africa_v6-tmp
+");
+
+    // An empty set is still created, so rules referencing it load.
+    let mut output = Vec::new();
+    write_ipset(&mut output, "iana", &[], &[]).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "\
+create iana_v4 hash:net family inet maxelem 65536 -exist
+create iana_v4-tmp hash:net family inet maxelem 65536 -exist
+flush iana_v4-tmp
+swap iana_v4-tmp iana_v4
+destroy iana_v4-tmp
+create iana_v6 hash:net family inet6 maxelem 65536 -exist
+create iana_v6-tmp hash:net family inet6 maxelem 65536 -exist
+flush iana_v6-tmp
+swap iana_v6-tmp iana_v6
+destroy iana_v6-tmp
+");
+}
+
+#[test]
+fn test_write_nft() {
+    let v4: Vec<Ipv4Cidr> = vec!["41.0.0.0/11".parse().unwrap(), "102.0.0.0/8".parse().unwrap()];
+    let v6: Vec<Ipv6Cidr> = vec!["2c0f:f000::/20".parse().unwrap()];
+
+    let mut output = Vec::new();
+    write_nft(&mut output, "inet filter", "africa", &v4, &v6).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "\
+add table inet filter
+add set inet filter africa_v4 { type ipv4_addr; flags interval; }
+flush set inet filter africa_v4
+add element inet filter africa_v4 {
+    41.0.0.0/11,
+    102.0.0.0/8
+}
+add set inet filter africa_v6 { type ipv6_addr; flags interval; }
+flush set inet filter africa_v6
+add element inet filter africa_v6 {
+    2c0f:f000::/20
+}
+");
+
+    // `add element ... { }` is a syntax error, empty sets are only declared and flushed.
+    let mut output = Vec::new();
+    write_nft(&mut output, "ip iana", "iana", &v4, &[]).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "\
+add table ip iana
+add set ip iana iana_v4 { type ipv4_addr; flags interval; }
+flush set ip iana iana_v4
+add element ip iana iana_v4 {
+    41.0.0.0/11,
+    102.0.0.0/8
+}
+add set ip iana iana_v6 { type ipv6_addr; flags interval; }
+flush set ip iana iana_v6
+");
+}
@@ -0,0 +1,199 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+use iana::{lookup, parse_date, LookupResult, Snapshots, Country, Registry, Status, Origin};
+#[cfg(feature = "provenance")]
+use iana::Provenance;
+
+use serde::Serialize;
+
+use crate::format::{Format, csv_field};
+
+use std::process;
+use std::net::IpAddr;
+use std::io::{self, Write, BufRead, BufWriter};
+
+
+pub fn command<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("lookup")
+        .about("Lookup the country of ip addresses")
+        .arg(
+            Arg::with_name("format")
+                .long("format")
+                .required(false)
+                .default_value("text")
+                .possible_values(&["text", "json", "csv"])
+                .help("Output format, json prints one object per line")
+        )
+        .arg(
+            Arg::with_name("at")
+                .long("at")
+                .takes_value(true)
+                .help("Answer from the snapshot in force on this date (YYYY-MM-DD), e.g. to place old logs")
+        )
+        .arg(
+            Arg::with_name("snapshots")
+                .long("snapshots")
+                .required(false)
+                .default_value("data")
+                .help("Directory holding the dated snapshots used by --at, e.g. `data/2019-09-11/`")
+        )
+        .arg(
+            Arg::with_name("address")
+                .multiple(true)
+                .help("IPv4 or IPv6 addresses, read one per line from stdin when omitted or `-`")
+        )
+}
+
+pub fn run(matches: &ArgMatches) {
+    let format: Format = matches.value_of("format").unwrap().parse().unwrap();
+    let addresses: Vec<&str> = matches.values_of("address").map(|values| values.collect()).unwrap_or_default();
+    let at = matches.value_of("at").map(|date| match parse_date(date) {
+        Some(date) => date,
+        None => {
+            error!("Invalid date ({}), expected YYYY-MM-DD", date);
+            process::exit(2);
+        }
+    });
+    let snapshots = at.map(|date| {
+        let snapshots = Snapshots::open(matches.value_of("snapshots").unwrap()).unwrap_or_else(|e| {
+            error!("Snapshots: {}", e);
+            process::exit(1);
+        });
+        match snapshots.snapshot_date(date) {
+            Some(snapshot_date) => info!("Use the snapshot of {}", snapshot_date),
+            None => warn!("No snapshot on or before {}", date),
+        }
+        snapshots
+    });
+
+    let stdout = io::stdout();
+    let mut output = BufWriter::new(stdout.lock());
+    let mut failures = 0usize;
+
+    let mut lookup_one = |address: &str, output: &mut dyn Write| -> io::Result<()> {
+        let address = address.trim();
+        if address.is_empty() {
+            return Ok(());
+        }
+
+        match address.parse::<IpAddr>() {
+            Ok(ip) => {
+                let result = match (&snapshots, at) {
+                    (Some(snapshots), Some(date)) => snapshots.lookup_at(&ip, date),
+                    _ => lookup(&ip),
+                };
+                write_result(output, format, &ip, result)
+            },
+            Err(_) => {
+                error!("Invalid IP address ({})", address);
+                failures += 1;
+                Ok(())
+            }
+        }
+    };
+
+    let result = (|| {
+        if format == Format::Csv {
+            let header = if cfg!(feature = "provenance") { ",provenance" } else { "" };
+            writeln!(output, "ip,first,last,country,country_name,registry,status,origin{}", header)?;
+        }
+
+        if addresses.is_empty() || addresses == ["-"] {
+            let stdin = io::stdin();
+            for line in stdin.lock().lines() {
+                lookup_one(&line?, &mut output)?;
+            }
+        } else {
+            for address in addresses.iter() {
+                lookup_one(address, &mut output)?;
+            }
+        }
+        output.flush()
+    })();
+
+    if let Err(e) = result {
+        // The reader went away (`| head`), not worth a message.
+        if e.kind() != io::ErrorKind::BrokenPipe {
+            error!("{}", e);
+            process::exit(1);
+        }
+    }
+
+    if failures > 0 {
+        process::exit(1);
+    }
+}
+
+/// One line of the json format, every field but `ip` is `null` when the address is not found.
+#[derive(Serialize)]
+struct JsonResult {
+    ip: IpAddr,
+    first: Option<IpAddr>,
+    last: Option<IpAddr>,
+    country: Option<Country>,
+    country_name: Option<&'static str>,
+    registry: Option<Registry>,
+    status: Option<Status>,
+    origin: Option<Origin>,
+    #[cfg(feature = "provenance")]
+    provenance: Option<Provenance>,
+}
+
+impl JsonResult {
+    fn new(ip: IpAddr, result: Option<LookupResult>) -> Self {
+        JsonResult {
+            ip,
+            first: result.map(|result| result.first),
+            last: result.map(|result| result.last),
+            country: result.map(|result| result.country),
+            country_name: result.map(|result| result.country.name()),
+            registry: result.map(|result| result.registry),
+            status: result.map(|result| result.status),
+            origin: result.map(|result| result.origin),
+            #[cfg(feature = "provenance")]
+            provenance: result.and_then(|result| result.provenance),
+        }
+    }
+}
+
+/// The `provenance` column of the text and csv formats, empty without the `provenance` feature.
+#[cfg(feature = "provenance")]
+fn provenance_column(format: Format, result: Option<&LookupResult>) -> String {
+    let provenance = result.and_then(|result| result.provenance);
+    match (format, provenance) {
+        (Format::Text, Some(provenance)) => format!("\t{}", provenance),
+        (Format::Text, None) => "\t-".to_string(),
+        (Format::Csv, Some(provenance)) => format!(",{}", csv_field(&provenance.to_string())),
+        (Format::Csv, None) => ",".to_string(),
+        (Format::Json, _) => String::new(),
+    }
+}
+
+#[cfg(not(feature = "provenance"))]
+fn provenance_column(_format: Format, _result: Option<&LookupResult>) -> String {
+    String::new()
+}
+
+fn write_result(output: &mut dyn Write, format: Format, ip: &IpAddr, result: Option<LookupResult>) -> io::Result<()> {
+    let provenance = provenance_column(format, result.as_ref());
+    match (format, result) {
+        (Format::Text, Some(result)) => {
+            writeln!(output, "{}\t{} - {}\t{}\t{}\t{}\t{}\t{}{}",
+                     ip, result.first, result.last, result.country, result.country.name(), result.registry, result.status, result.origin, provenance)
+        },
+        (Format::Text, None) => {
+            writeln!(output, "{}\t-{}", ip, provenance)
+        },
+        (Format::Json, result) => {
+            serde_json::to_writer(&mut *output, &JsonResult::new(*ip, result))?;
+            writeln!(output)
+        },
+        (Format::Csv, Some(result)) => {
+            writeln!(output, "{},{},{},{},{},{},{},{}{}",
+                     ip, result.first, result.last, result.country, csv_field(result.country.name()), result.registry, result.status, result.origin, provenance)
+        },
+        (Format::Csv, None) => {
+            writeln!(output, "{},,,,,,,{}", ip, provenance)
+        },
+    }
+}
@@ -0,0 +1,84 @@
+#[macro_use]
+extern crate log;
+extern crate env_logger;
+extern crate clap;
+#[cfg(feature = "sync")]
+extern crate tokio;
+
+extern crate iana;
+
+#[cfg(feature = "sync")]
+mod sync;
+mod parse;
+mod lookup;
+mod export;
+mod firewall;
+mod routes;
+mod enrich;
+mod diff;
+mod countries;
+#[cfg(feature = "rdap")]
+mod rdap;
+mod format;
+
+use clap::{App, AppSettings, Arg};
+
+use std::env;
+
+
+pub fn data_path_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("data-path")
+        .long("data-path")
+        .required(false)
+        .default_value("data")
+        .help("Specify the default data path")
+}
+
+pub fn overlay_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("overlay")
+        .long("overlay")
+        .takes_value(true)
+        .multiple(true)
+        .number_of_values(1)
+        .help("CSV of `network,country[,priority]` correcting the RIR countries, may be repeated")
+}
+
+
+fn main () {
+    if env::var_os("RUST_LOG").is_none() {
+        env::set_var("RUST_LOG", "iana=info");
+    }
+    env_logger::init();
+
+    let app = App::new("IANA IP DB TOOLS")
+        .version("0.1")
+        .author("Luozijun <luozijun.assistant@gmail.com>")
+        .about("Sync, parse, lookup and export the IANA/RIR ip db")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .subcommand(parse::command())
+        .subcommand(lookup::command())
+        .subcommand(export::command())
+        .subcommand(enrich::command())
+        .subcommand(diff::command())
+        .subcommand(countries::command());
+    #[cfg(feature = "sync")]
+    let app = app.subcommand(sync::command());
+    #[cfg(feature = "rdap")]
+    let app = app.subcommand(rdap::command());
+
+    let matches = app.get_matches();
+
+    match matches.subcommand() {
+        #[cfg(feature = "sync")]
+        ("sync", Some(matches)) => sync::run(matches),
+        ("parse", Some(matches)) => parse::run(matches),
+        ("lookup", Some(matches)) => lookup::run(matches),
+        ("export", Some(matches)) => export::run(matches),
+        ("enrich", Some(matches)) => enrich::run(matches),
+        ("diff", Some(matches)) => diff::run(matches),
+        ("countries", Some(matches)) => countries::run(matches),
+        #[cfg(feature = "rdap")]
+        ("rdap", Some(matches)) => rdap::run(matches),
+        _ => unreachable!(),
+    }
+}
@@ -0,0 +1,44 @@
+use clap::{App, ArgMatches, SubCommand};
+
+use iana::Record;
+
+use crate::export::{Family, load_records, select, write_records};
+
+use std::fs::{self, File};
+use std::path::Path;
+use std::io::{Write, BufWriter};
+
+
+pub fn command<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("parse")
+        .about("Parse IANA RIR db file into sorted record files, the lookup tables are generated by `build.rs`")
+        .arg(crate::data_path_arg())
+        .arg(crate::overlay_arg())
+}
+
+fn write_file(filepath: &Path, records: &[&Record]) {
+    let mut file = BufWriter::new(File::create(filepath).unwrap());
+    write_records(&mut file, records).unwrap();
+    file.flush().unwrap();
+}
+
+pub fn run(matches: &ArgMatches) {
+    let data_path = Path::new(matches.value_of("data-path").unwrap());
+    if !data_path.exists() {
+        fs::create_dir(data_path).unwrap();
+    }
+
+    let record_sets = load_records(matches, data_path);
+
+    let v4_records = select(&record_sets, Family::Ipv4, false);
+    let v6_records = select(&record_sets, Family::Ipv6, false);
+    let iana_v4_records = select(&record_sets, Family::Ipv4, true);
+    let iana_v6_records = select(&record_sets, Family::Ipv6, true);
+
+    write_file(&data_path.join("v4_records"), &v4_records);
+    write_file(&data_path.join("v6_records"), &v6_records);
+    write_file(&data_path.join("iana_v4_records"), &iana_v4_records);
+    write_file(&data_path.join("iana_v6_records"), &iana_v6_records);
+
+    info!("{} IPv4 and {} IPv6 records written to {:?}", v4_records.len(), v6_records.len(), data_path);
+}
@@ -0,0 +1,94 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+use iana::RdapBootstrap;
+
+use std::process;
+use std::net::IpAddr;
+use std::io::{self, Write, BufRead, BufWriter};
+
+
+pub fn command<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("rdap")
+        .about("Print the RDAP query URL of ip addresses and AS numbers, from the synced bootstrap files")
+        .arg(crate::data_path_arg())
+        .arg(
+            Arg::with_name("target")
+                .multiple(true)
+                .help("IPv4 or IPv6 addresses and AS numbers (`AS15169` or `15169`), \
+                       read one per line from stdin when omitted or `-`")
+        )
+}
+
+/// The RDAP query of an address or an AS number.
+fn rdap_url(bootstrap: &RdapBootstrap, target: &str) -> Result<Option<String>, String> {
+    if let Ok(ip) = target.parse::<IpAddr>() {
+        return Ok(bootstrap.rdap_url_for(&ip));
+    }
+
+    let asn = target.strip_prefix("AS").or_else(|| target.strip_prefix("as")).unwrap_or(target);
+    match asn.parse::<u32>() {
+        Ok(asn) => Ok(bootstrap.rdap_url_for_asn(asn)),
+        Err(_) => Err(format!("Invalid address or AS number ({})", target)),
+    }
+}
+
+pub fn run(matches: &ArgMatches) {
+    let data_path = matches.value_of("data-path").unwrap();
+    let targets: Vec<&str> = matches.values_of("target").map(|values| values.collect()).unwrap_or_default();
+
+    let bootstrap = RdapBootstrap::open(data_path).unwrap_or_else(|e| {
+        error!("{:?}", e);
+        process::exit(1);
+    });
+    if bootstrap.is_empty() {
+        error!("No RDAP bootstrap files in {:?}, run `iana sync` first", data_path);
+        process::exit(1);
+    }
+
+    let stdout = io::stdout();
+    let mut output = BufWriter::new(stdout.lock());
+    let mut failures = 0usize;
+
+    let mut print = |target: &str, output: &mut dyn Write| -> io::Result<()> {
+        let target = target.trim();
+        if target.is_empty() {
+            return Ok(());
+        }
+        match rdap_url(&bootstrap, target) {
+            Ok(Some(url)) => writeln!(output, "{}\t{}", target, url),
+            Ok(None) => writeln!(output, "{}\t-", target),
+            Err(e) => {
+                error!("{}", e);
+                failures += 1;
+                Ok(())
+            },
+        }
+    };
+
+    let result = (|| {
+        if targets.is_empty() || targets == ["-"] {
+            let stdin = io::stdin();
+            for line in stdin.lock().lines() {
+                print(&line?, &mut output)?;
+            }
+        } else {
+            for target in targets.iter() {
+                print(target, &mut output)?;
+            }
+        }
+        output.flush()
+    })();
+
+    if let Err(e) = result {
+        // The reader went away (`| head`), not worth a message.
+        if e.kind() != io::ErrorKind::BrokenPipe {
+            error!("{}", e);
+            process::exit(1);
+        }
+    }
+
+    // Like `lookup`, the valid targets are printed and invalid ones fail the run.
+    if failures > 0 {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,168 @@
+use iana::{Ipv4Cidr, Ipv6Cidr};
+
+use std::fmt;
+use std::str::FromStr;
+use std::net::IpAddr;
+use std::io::{self, Write};
+
+
+/// Next hop of the exported routes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Via {
+    Gateway(IpAddr),
+    Device(String),
+}
+
+impl Via {
+    fn routes_ipv4(&self) -> bool {
+        !matches!(*self, Via::Gateway(IpAddr::V6(_)))
+    }
+
+    fn routes_ipv6(&self) -> bool {
+        !matches!(*self, Via::Gateway(IpAddr::V4(_)))
+    }
+}
+
+impl FromStr for Via {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<IpAddr>() {
+            Ok(gateway) => Ok(Via::Gateway(gateway)),
+            Err(_) if !s.is_empty() => Ok(Via::Device(s.to_string())),
+            Err(_) => Err(format!("Unknow Via ({})", s)),
+        }
+    }
+}
+
+
+fn write_bird_protocol<W, T>(output: &mut W, name: &str, channel: &str, via: &Via, cidrs: &[T]) -> io::Result<()>
+    where W: Write + ?Sized,
+          T: fmt::Display
+{
+    if cidrs.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(output, "protocol static {} {{", name)?;
+    writeln!(output, "    {};", channel)?;
+    for cidr in cidrs.iter() {
+        match *via {
+            Via::Gateway(ref gateway) => writeln!(output, "    route {} via {};", cidr, gateway)?,
+            Via::Device(ref device) => writeln!(output, "    route {} via \"{}\";", cidr, device)?,
+        }
+    }
+    writeln!(output, "}}")
+}
+
+/// BIRD 2 static protocols, one per address family.
+pub fn write_bird<W: Write + ?Sized>(output: &mut W, name: &str, via: &Via, v4: &[Ipv4Cidr], v6: &[Ipv6Cidr]) -> io::Result<()> {
+    if via.routes_ipv4() {
+        write_bird_protocol(output, &format!("{}_v4", name), "ipv4", via, v4)?;
+    }
+    if via.routes_ipv6() {
+        write_bird_protocol(output, &format!("{}_v6", name), "ipv6", via, v6)?;
+    }
+    Ok(())
+}
+
+fn write_ip_route_family<W, T>(output: &mut W, via: &Via, cidrs: &[T]) -> io::Result<()>
+    where W: Write + ?Sized,
+          T: fmt::Display
+{
+    for cidr in cidrs.iter() {
+        match *via {
+            Via::Gateway(ref gateway) => writeln!(output, "route replace {} via {}", cidr, gateway)?,
+            Via::Device(ref device) => writeln!(output, "route replace {} dev {}", cidr, device)?,
+        }
+    }
+    Ok(())
+}
+
+/// Batch file for `ip -batch`, `replace` makes it safe to load again.
+pub fn write_ip_route<W: Write + ?Sized>(output: &mut W, via: &Via, v4: &[Ipv4Cidr], v6: &[Ipv6Cidr]) -> io::Result<()> {
+    if via.routes_ipv4() {
+        write_ip_route_family(output, via, v4)?;
+    }
+    if via.routes_ipv6() {
+        write_ip_route_family(output, via, v6)?;
+    }
+    Ok(())
+}
+
+/// `AllowedIPs` line for the `[Peer]` section of a WireGuard config.
+pub fn write_wireguard<W: Write + ?Sized>(output: &mut W, v4: &[Ipv4Cidr], v6: &[Ipv6Cidr]) -> io::Result<()> {
+    let cidrs: Vec<String> = v4.iter().map(|cidr| cidr.to_string())
+        .chain(v6.iter().map(|cidr| cidr.to_string()))
+        .collect();
+    writeln!(output, "AllowedIPs = {}", cidrs.join(", "))
+}
+
+
+#[test]
+fn test_via() {
+    assert_eq!("10.8.0.1".parse::<Via>(), Ok(Via::Gateway("10.8.0.1".parse().unwrap())));
+    assert_eq!("fe80::1".parse::<Via>(), Ok(Via::Gateway("fe80::1".parse().unwrap())));
+    assert_eq!("wg0".parse::<Via>(), Ok(Via::Device("wg0".to_string())));
+    assert!("".parse::<Via>().is_err());
+}
+
+#[test]
+fn test_write_bird() {
+    let v4: Vec<Ipv4Cidr> = vec!["41.0.0.0/11".parse().unwrap(), "102.0.0.0/8".parse().unwrap()];
+    let v6: Vec<Ipv6Cidr> = vec!["2c0f:f000::/20".parse().unwrap()];
+
+    let mut output = Vec::new();
+    write_bird(&mut output, "africa", &"wg0".parse().unwrap(), &v4, &v6).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "\
+protocol static africa_v4 {
+    ipv4;
+    route 41.0.0.0/11 via \"wg0\";
+    route 102.0.0.0/8 via \"wg0\";
+}
+protocol static africa_v6 {
+    ipv6;
+    route 2c0f:f000::/20 via \"wg0\";
+}
+");
+
+    // A gateway only routes its own family, an empty protocol is left out.
+    let mut output = Vec::new();
+    write_bird(&mut output, "iana", &"10.8.0.1".parse().unwrap(), &v4, &v6).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "\
+protocol static iana_v4 {
+    ipv4;
+    route 41.0.0.0/11 via 10.8.0.1;
+    route 102.0.0.0/8 via 10.8.0.1;
+}
+");
+    let mut output = Vec::new();
+    write_bird(&mut output, "iana", &"wg0".parse().unwrap(), &[], &[]).unwrap();
+    assert!(output.is_empty());
+}
+
+#[test]
+fn test_write_ip_route() {
+    let v4: Vec<Ipv4Cidr> = vec!["41.0.0.0/11".parse().unwrap()];
+    let v6: Vec<Ipv6Cidr> = vec!["2c0f:f000::/20".parse().unwrap()];
+
+    let mut output = Vec::new();
+    write_ip_route(&mut output, &"wg0".parse().unwrap(), &v4, &v6).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "\
+route replace 41.0.0.0/11 dev wg0
+route replace 2c0f:f000::/20 dev wg0
+");
+
+    let mut output = Vec::new();
+    write_ip_route(&mut output, &"fe80::1".parse().unwrap(), &v4, &v6).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "route replace 2c0f:f000::/20 via fe80::1\n");
+}
+
+#[test]
+fn test_write_wireguard() {
+    let v4: Vec<Ipv4Cidr> = vec!["41.0.0.0/11".parse().unwrap(), "102.0.0.0/8".parse().unwrap()];
+    let v6: Vec<Ipv6Cidr> = vec!["2c0f:f000::/20".parse().unwrap()];
+
+    let mut output = Vec::new();
+    write_wireguard(&mut output, &v4, &v6).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "AllowedIPs = 41.0.0.0/11, 102.0.0.0/8, 2c0f:f000::/20\n");
+}
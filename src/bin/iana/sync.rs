@@ -0,0 +1,104 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+use iana::{Syncer, Progress, Outcome};
+
+use std::process;
+use std::time::Duration;
+use std::path::Path;
+
+
+pub fn command<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("sync")
+        .about("Sync ip db from IANA")
+        .arg(crate::data_path_arg())
+        .arg(
+            Arg::with_name("mirror")
+                .long("mirror")
+                .required(false)
+                .takes_value(true)
+                .help("Fetch every file from this base URL instead (http://, https://, ftp:// or file://)")
+        )
+        .arg(
+            Arg::with_name("concurrency")
+                .long("concurrency")
+                .required(false)
+                .default_value("4")
+                .help("Maximum number of files downloaded at the same time")
+        )
+        .arg(
+            Arg::with_name("retries")
+                .long("retries")
+                .required(false)
+                .default_value("2")
+                .help("Number of retries after a failed download")
+        )
+        .arg(
+            Arg::with_name("timeout")
+                .long("timeout")
+                .required(false)
+                .default_value("120")
+                .help("Time limit for a single download, in seconds")
+        )
+}
+
+pub fn run(matches: &ArgMatches) {
+    let data_path = Path::new(matches.value_of("data-path").unwrap());
+    let concurrency: usize = matches.value_of("concurrency").unwrap().parse().expect("Invalid concurrency");
+    let retries: usize = matches.value_of("retries").unwrap().parse().expect("Invalid retries");
+    let timeout: u64 = matches.value_of("timeout").unwrap().parse().expect("Invalid timeout");
+
+    let mut syncer = Syncer::new(data_path)
+        .concurrency(concurrency)
+        .retries(retries)
+        .timeout(Duration::from_secs(timeout))
+        .on_progress(|progress| {
+            match *progress {
+                Progress::Started { ref name, ref url } => info!("Start:\tFile: {:50} {}", name, url),
+                Progress::Retrying { ref name, attempt, ref error } => warn!("Retry:\tFile: {:50} #{} {:?}", name, attempt, error),
+                Progress::Finished { ref name, ref outcome } => info!("Event:\tFile: {:50} State: {:?}", name, outcome),
+                Progress::Downloading { .. } => { },
+            }
+        });
+    if let Some(mirror) = matches.value_of("mirror") {
+        syncer = syncer.mirror(mirror);
+    }
+
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let cancel = syncer.cancel_token();
+    runtime.spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            warn!("Interrupted, cancel pending downloads ...");
+            cancel.cancel();
+        }
+    });
+
+    let reports = match runtime.block_on(syncer.run()) {
+        Ok(reports) => reports,
+        Err(e) => {
+            error!("{:?}", e);
+            process::exit(1);
+        }
+    };
+
+    let failures = reports.iter().filter(|report| {
+        match report.outcome {
+            Outcome::UpToDate | Outcome::Updated { .. } | Outcome::Skipped(_) => false,
+            Outcome::Failed(_) | Outcome::Cancelled => true,
+        }
+    }).count();
+
+    for report in reports.iter() {
+        if let Outcome::Skipped(ref e) = report.outcome {
+            warn!("Skip {}, not found: {}", report.name, e);
+        }
+    }
+
+    if failures > 0 {
+        error!("{} of {} files failed to sync.", failures, reports.len());
+        process::exit(1);
+    }
+}
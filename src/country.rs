@@ -1,1560 +0,0 @@
-
-
-use std::str::FromStr;
-use std::fmt;
-
-use crate::error::Error;
-
-
-/// Country Code List: ISO 3166-1993 (E)
-/// 
-/// Download from: https://github.com/lukes/ISO-3166-Countries-with-Regional-Codes
-#[repr(u8)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd)]
-pub enum Country {
-    AF = 0u8,
-    AX = 1u8,
-    AL = 2u8,
-    DZ = 3u8,
-    AS = 4u8,
-    AD = 5u8,
-    AO = 6u8,
-    AI = 7u8,
-    AQ = 8u8,
-    AG = 9u8,
-    AR = 10u8,
-    AM = 11u8,
-    AW = 12u8,
-    AU = 13u8,
-    AT = 14u8,
-    AZ = 15u8,
-    BS = 16u8,
-    BH = 17u8,
-    BD = 18u8,
-    BB = 19u8,
-    BY = 20u8,
-    BE = 21u8,
-    BZ = 22u8,
-    BJ = 23u8,
-    BM = 24u8,
-    BT = 25u8,
-    BO = 26u8,
-    BQ = 27u8,
-    BA = 28u8,
-    BW = 29u8,
-    BV = 30u8,
-    BR = 31u8,
-    IO = 32u8,
-    BN = 33u8,
-    BG = 34u8,
-    BF = 35u8,
-    BI = 36u8,
-    KH = 37u8,
-    CM = 38u8,
-    CA = 39u8,
-    CV = 40u8,
-    KY = 41u8,
-    CF = 42u8,
-    TD = 43u8,
-    CL = 44u8,
-    CN = 45u8,
-    CX = 46u8,
-    CC = 47u8,
-    CO = 48u8,
-    KM = 49u8,
-    CG = 50u8,
-    CD = 51u8,
-    CK = 52u8,
-    CR = 53u8,
-    CI = 54u8,
-    HR = 55u8,
-    CU = 56u8,
-    CW = 57u8,
-    CY = 58u8,
-    CZ = 59u8,
-    DK = 60u8,
-    DJ = 61u8,
-    DM = 62u8,
-    DO = 63u8,
-    EC = 64u8,
-    EG = 65u8,
-    SV = 66u8,
-    GQ = 67u8,
-    ER = 68u8,
-    EE = 69u8,
-    ET = 70u8,
-    FK = 71u8,
-    FO = 72u8,
-    FJ = 73u8,
-    FI = 74u8,
-    FR = 75u8,
-    GF = 76u8,
-    PF = 77u8,
-    TF = 78u8,
-    GA = 79u8,
-    GM = 80u8,
-    GE = 81u8,
-    DE = 82u8,
-    GH = 83u8,
-    GI = 84u8,
-    GR = 85u8,
-    GL = 86u8,
-    GD = 87u8,
-    GP = 88u8,
-    GU = 89u8,
-    GT = 90u8,
-    GG = 91u8,
-    GN = 92u8,
-    GW = 93u8,
-    GY = 94u8,
-    HT = 95u8,
-    HM = 96u8,
-    VA = 97u8,
-    HN = 98u8,
-    HK = 99u8,
-    HU = 100u8,
-    IS = 101u8,
-    IN = 102u8,
-    ID = 103u8,
-    IR = 104u8,
-    IQ = 105u8,
-    IE = 106u8,
-    IM = 107u8,
-    IL = 108u8,
-    IT = 109u8,
-    JM = 110u8,
-    JP = 111u8,
-    JE = 112u8,
-    JO = 113u8,
-    KZ = 114u8,
-    KE = 115u8,
-    KI = 116u8,
-    KP = 117u8,
-    KR = 118u8,
-    KW = 119u8,
-    KG = 120u8,
-    LA = 121u8,
-    LV = 122u8,
-    LB = 123u8,
-    LS = 124u8,
-    LR = 125u8,
-    LY = 126u8,
-    LI = 127u8,
-    LT = 128u8,
-    LU = 129u8,
-    MO = 130u8,
-    MK = 131u8,
-    MG = 132u8,
-    MW = 133u8,
-    MY = 134u8,
-    MV = 135u8,
-    ML = 136u8,
-    MT = 137u8,
-    MH = 138u8,
-    MQ = 139u8,
-    MR = 140u8,
-    MU = 141u8,
-    YT = 142u8,
-    MX = 143u8,
-    FM = 144u8,
-    MD = 145u8,
-    MC = 146u8,
-    MN = 147u8,
-    ME = 148u8,
-    MS = 149u8,
-    MA = 150u8,
-    MZ = 151u8,
-    MM = 152u8,
-    NA = 153u8,
-    NR = 154u8,
-    NP = 155u8,
-    NL = 156u8,
-    NC = 157u8,
-    NZ = 158u8,
-    NI = 159u8,
-    NE = 160u8,
-    NG = 161u8,
-    NU = 162u8,
-    NF = 163u8,
-    MP = 164u8,
-    NO = 165u8,
-    OM = 166u8,
-    PK = 167u8,
-    PW = 168u8,
-    PS = 169u8,
-    PA = 170u8,
-    PG = 171u8,
-    PY = 172u8,
-    PE = 173u8,
-    PH = 174u8,
-    PN = 175u8,
-    PL = 176u8,
-    PT = 177u8,
-    PR = 178u8,
-    QA = 179u8,
-    RE = 180u8,
-    RO = 181u8,
-    RU = 182u8,
-    RW = 183u8,
-    BL = 184u8,
-    SH = 185u8,
-    KN = 186u8,
-    LC = 187u8,
-    MF = 188u8,
-    PM = 189u8,
-    VC = 190u8,
-    WS = 191u8,
-    SM = 192u8,
-    ST = 193u8,
-    SA = 194u8,
-    SN = 195u8,
-    RS = 196u8,
-    SC = 197u8,
-    SL = 198u8,
-    SG = 199u8,
-    SX = 200u8,
-    SK = 201u8,
-    SI = 202u8,
-    SB = 203u8,
-    SO = 204u8,
-    ZA = 205u8,
-    GS = 206u8,
-    SS = 207u8,
-    ES = 208u8,
-    LK = 209u8,
-    SD = 210u8,
-    SR = 211u8,
-    SJ = 212u8,
-    SZ = 213u8,
-    SE = 214u8,
-    CH = 215u8,
-    SY = 216u8,
-    TW = 217u8,
-    TJ = 218u8,
-    TZ = 219u8,
-    TH = 220u8,
-    TL = 221u8,
-    TG = 222u8,
-    TK = 223u8,
-    TO = 224u8,
-    TT = 225u8,
-    TN = 226u8,
-    TR = 227u8,
-    TM = 228u8,
-    TC = 229u8,
-    TV = 230u8,
-    UG = 231u8,
-    UA = 232u8,
-    AE = 233u8,
-    GB = 234u8,
-    US = 235u8,
-    UM = 236u8,
-    UY = 237u8,
-    UZ = 238u8,
-    VU = 239u8,
-    VE = 240u8,
-    VN = 241u8,
-    VG = 242u8,
-    VI = 243u8,
-    WF = 244u8,
-    EH = 245u8,
-    YE = 246u8,
-    ZM = 247u8,
-    ZW = 248u8,
-    EU = 249u8,
-    ZZ = 250u8,
-}
-
-
-impl Country {
-
-    pub fn from_index(index: u8) -> Result<Self, ()> {
-        match index {
-            0 => Ok(Country::AF),
-            1 => Ok(Country::AX),
-            2 => Ok(Country::AL),
-            3 => Ok(Country::DZ),
-            4 => Ok(Country::AS),
-            5 => Ok(Country::AD),
-            6 => Ok(Country::AO),
-            7 => Ok(Country::AI),
-            8 => Ok(Country::AQ),
-            9 => Ok(Country::AG),
-            10 => Ok(Country::AR),
-            11 => Ok(Country::AM),
-            12 => Ok(Country::AW),
-            13 => Ok(Country::AU),
-            14 => Ok(Country::AT),
-            15 => Ok(Country::AZ),
-            16 => Ok(Country::BS),
-            17 => Ok(Country::BH),
-            18 => Ok(Country::BD),
-            19 => Ok(Country::BB),
-            20 => Ok(Country::BY),
-            21 => Ok(Country::BE),
-            22 => Ok(Country::BZ),
-            23 => Ok(Country::BJ),
-            24 => Ok(Country::BM),
-            25 => Ok(Country::BT),
-            26 => Ok(Country::BO),
-            27 => Ok(Country::BQ),
-            28 => Ok(Country::BA),
-            29 => Ok(Country::BW),
-            30 => Ok(Country::BV),
-            31 => Ok(Country::BR),
-            32 => Ok(Country::IO),
-            33 => Ok(Country::BN),
-            34 => Ok(Country::BG),
-            35 => Ok(Country::BF),
-            36 => Ok(Country::BI),
-            37 => Ok(Country::KH),
-            38 => Ok(Country::CM),
-            39 => Ok(Country::CA),
-            40 => Ok(Country::CV),
-            41 => Ok(Country::KY),
-            42 => Ok(Country::CF),
-            43 => Ok(Country::TD),
-            44 => Ok(Country::CL),
-            45 => Ok(Country::CN),
-            46 => Ok(Country::CX),
-            47 => Ok(Country::CC),
-            48 => Ok(Country::CO),
-            49 => Ok(Country::KM),
-            50 => Ok(Country::CG),
-            51 => Ok(Country::CD),
-            52 => Ok(Country::CK),
-            53 => Ok(Country::CR),
-            54 => Ok(Country::CI),
-            55 => Ok(Country::HR),
-            56 => Ok(Country::CU),
-            57 => Ok(Country::CW),
-            58 => Ok(Country::CY),
-            59 => Ok(Country::CZ),
-            60 => Ok(Country::DK),
-            61 => Ok(Country::DJ),
-            62 => Ok(Country::DM),
-            63 => Ok(Country::DO),
-            64 => Ok(Country::EC),
-            65 => Ok(Country::EG),
-            66 => Ok(Country::SV),
-            67 => Ok(Country::GQ),
-            68 => Ok(Country::ER),
-            69 => Ok(Country::EE),
-            70 => Ok(Country::ET),
-            71 => Ok(Country::FK),
-            72 => Ok(Country::FO),
-            73 => Ok(Country::FJ),
-            74 => Ok(Country::FI),
-            75 => Ok(Country::FR),
-            76 => Ok(Country::GF),
-            77 => Ok(Country::PF),
-            78 => Ok(Country::TF),
-            79 => Ok(Country::GA),
-            80 => Ok(Country::GM),
-            81 => Ok(Country::GE),
-            82 => Ok(Country::DE),
-            83 => Ok(Country::GH),
-            84 => Ok(Country::GI),
-            85 => Ok(Country::GR),
-            86 => Ok(Country::GL),
-            87 => Ok(Country::GD),
-            88 => Ok(Country::GP),
-            89 => Ok(Country::GU),
-            90 => Ok(Country::GT),
-            91 => Ok(Country::GG),
-            92 => Ok(Country::GN),
-            93 => Ok(Country::GW),
-            94 => Ok(Country::GY),
-            95 => Ok(Country::HT),
-            96 => Ok(Country::HM),
-            97 => Ok(Country::VA),
-            98 => Ok(Country::HN),
-            99 => Ok(Country::HK),
-            100 => Ok(Country::HU),
-            101 => Ok(Country::IS),
-            102 => Ok(Country::IN),
-            103 => Ok(Country::ID),
-            104 => Ok(Country::IR),
-            105 => Ok(Country::IQ),
-            106 => Ok(Country::IE),
-            107 => Ok(Country::IM),
-            108 => Ok(Country::IL),
-            109 => Ok(Country::IT),
-            110 => Ok(Country::JM),
-            111 => Ok(Country::JP),
-            112 => Ok(Country::JE),
-            113 => Ok(Country::JO),
-            114 => Ok(Country::KZ),
-            115 => Ok(Country::KE),
-            116 => Ok(Country::KI),
-            117 => Ok(Country::KP),
-            118 => Ok(Country::KR),
-            119 => Ok(Country::KW),
-            120 => Ok(Country::KG),
-            121 => Ok(Country::LA),
-            122 => Ok(Country::LV),
-            123 => Ok(Country::LB),
-            124 => Ok(Country::LS),
-            125 => Ok(Country::LR),
-            126 => Ok(Country::LY),
-            127 => Ok(Country::LI),
-            128 => Ok(Country::LT),
-            129 => Ok(Country::LU),
-            130 => Ok(Country::MO),
-            131 => Ok(Country::MK),
-            132 => Ok(Country::MG),
-            133 => Ok(Country::MW),
-            134 => Ok(Country::MY),
-            135 => Ok(Country::MV),
-            136 => Ok(Country::ML),
-            137 => Ok(Country::MT),
-            138 => Ok(Country::MH),
-            139 => Ok(Country::MQ),
-            140 => Ok(Country::MR),
-            141 => Ok(Country::MU),
-            142 => Ok(Country::YT),
-            143 => Ok(Country::MX),
-            144 => Ok(Country::FM),
-            145 => Ok(Country::MD),
-            146 => Ok(Country::MC),
-            147 => Ok(Country::MN),
-            148 => Ok(Country::ME),
-            149 => Ok(Country::MS),
-            150 => Ok(Country::MA),
-            151 => Ok(Country::MZ),
-            152 => Ok(Country::MM),
-            153 => Ok(Country::NA),
-            154 => Ok(Country::NR),
-            155 => Ok(Country::NP),
-            156 => Ok(Country::NL),
-            157 => Ok(Country::NC),
-            158 => Ok(Country::NZ),
-            159 => Ok(Country::NI),
-            160 => Ok(Country::NE),
-            161 => Ok(Country::NG),
-            162 => Ok(Country::NU),
-            163 => Ok(Country::NF),
-            164 => Ok(Country::MP),
-            165 => Ok(Country::NO),
-            166 => Ok(Country::OM),
-            167 => Ok(Country::PK),
-            168 => Ok(Country::PW),
-            169 => Ok(Country::PS),
-            170 => Ok(Country::PA),
-            171 => Ok(Country::PG),
-            172 => Ok(Country::PY),
-            173 => Ok(Country::PE),
-            174 => Ok(Country::PH),
-            175 => Ok(Country::PN),
-            176 => Ok(Country::PL),
-            177 => Ok(Country::PT),
-            178 => Ok(Country::PR),
-            179 => Ok(Country::QA),
-            180 => Ok(Country::RE),
-            181 => Ok(Country::RO),
-            182 => Ok(Country::RU),
-            183 => Ok(Country::RW),
-            184 => Ok(Country::BL),
-            185 => Ok(Country::SH),
-            186 => Ok(Country::KN),
-            187 => Ok(Country::LC),
-            188 => Ok(Country::MF),
-            189 => Ok(Country::PM),
-            190 => Ok(Country::VC),
-            191 => Ok(Country::WS),
-            192 => Ok(Country::SM),
-            193 => Ok(Country::ST),
-            194 => Ok(Country::SA),
-            195 => Ok(Country::SN),
-            196 => Ok(Country::RS),
-            197 => Ok(Country::SC),
-            198 => Ok(Country::SL),
-            199 => Ok(Country::SG),
-            200 => Ok(Country::SX),
-            201 => Ok(Country::SK),
-            202 => Ok(Country::SI),
-            203 => Ok(Country::SB),
-            204 => Ok(Country::SO),
-            205 => Ok(Country::ZA),
-            206 => Ok(Country::GS),
-            207 => Ok(Country::SS),
-            208 => Ok(Country::ES),
-            209 => Ok(Country::LK),
-            210 => Ok(Country::SD),
-            211 => Ok(Country::SR),
-            212 => Ok(Country::SJ),
-            213 => Ok(Country::SZ),
-            214 => Ok(Country::SE),
-            215 => Ok(Country::CH),
-            216 => Ok(Country::SY),
-            217 => Ok(Country::TW),
-            218 => Ok(Country::TJ),
-            219 => Ok(Country::TZ),
-            220 => Ok(Country::TH),
-            221 => Ok(Country::TL),
-            222 => Ok(Country::TG),
-            223 => Ok(Country::TK),
-            224 => Ok(Country::TO),
-            225 => Ok(Country::TT),
-            226 => Ok(Country::TN),
-            227 => Ok(Country::TR),
-            228 => Ok(Country::TM),
-            229 => Ok(Country::TC),
-            230 => Ok(Country::TV),
-            231 => Ok(Country::UG),
-            232 => Ok(Country::UA),
-            233 => Ok(Country::AE),
-            234 => Ok(Country::GB),
-            235 => Ok(Country::US),
-            236 => Ok(Country::UM),
-            237 => Ok(Country::UY),
-            238 => Ok(Country::UZ),
-            239 => Ok(Country::VU),
-            240 => Ok(Country::VE),
-            241 => Ok(Country::VN),
-            242 => Ok(Country::VG),
-            243 => Ok(Country::VI),
-            244 => Ok(Country::WF),
-            245 => Ok(Country::EH),
-            246 => Ok(Country::YE),
-            247 => Ok(Country::ZM),
-            248 => Ok(Country::ZW),
-            249 => Ok(Country::EU),
-            250 => Ok(Country::ZZ),
-            _ => Err(()),
-        }
-    }
-
-    pub fn index(&self) -> u8 {
-        match *self {
-            Country::AF => 0,
-            Country::AX => 1,
-            Country::AL => 2,
-            Country::DZ => 3,
-            Country::AS => 4,
-            Country::AD => 5,
-            Country::AO => 6,
-            Country::AI => 7,
-            Country::AQ => 8,
-            Country::AG => 9,
-            Country::AR => 10,
-            Country::AM => 11,
-            Country::AW => 12,
-            Country::AU => 13,
-            Country::AT => 14,
-            Country::AZ => 15,
-            Country::BS => 16,
-            Country::BH => 17,
-            Country::BD => 18,
-            Country::BB => 19,
-            Country::BY => 20,
-            Country::BE => 21,
-            Country::BZ => 22,
-            Country::BJ => 23,
-            Country::BM => 24,
-            Country::BT => 25,
-            Country::BO => 26,
-            Country::BQ => 27,
-            Country::BA => 28,
-            Country::BW => 29,
-            Country::BV => 30,
-            Country::BR => 31,
-            Country::IO => 32,
-            Country::BN => 33,
-            Country::BG => 34,
-            Country::BF => 35,
-            Country::BI => 36,
-            Country::KH => 37,
-            Country::CM => 38,
-            Country::CA => 39,
-            Country::CV => 40,
-            Country::KY => 41,
-            Country::CF => 42,
-            Country::TD => 43,
-            Country::CL => 44,
-            Country::CN => 45,
-            Country::CX => 46,
-            Country::CC => 47,
-            Country::CO => 48,
-            Country::KM => 49,
-            Country::CG => 50,
-            Country::CD => 51,
-            Country::CK => 52,
-            Country::CR => 53,
-            Country::CI => 54,
-            Country::HR => 55,
-            Country::CU => 56,
-            Country::CW => 57,
-            Country::CY => 58,
-            Country::CZ => 59,
-            Country::DK => 60,
-            Country::DJ => 61,
-            Country::DM => 62,
-            Country::DO => 63,
-            Country::EC => 64,
-            Country::EG => 65,
-            Country::SV => 66,
-            Country::GQ => 67,
-            Country::ER => 68,
-            Country::EE => 69,
-            Country::ET => 70,
-            Country::FK => 71,
-            Country::FO => 72,
-            Country::FJ => 73,
-            Country::FI => 74,
-            Country::FR => 75,
-            Country::GF => 76,
-            Country::PF => 77,
-            Country::TF => 78,
-            Country::GA => 79,
-            Country::GM => 80,
-            Country::GE => 81,
-            Country::DE => 82,
-            Country::GH => 83,
-            Country::GI => 84,
-            Country::GR => 85,
-            Country::GL => 86,
-            Country::GD => 87,
-            Country::GP => 88,
-            Country::GU => 89,
-            Country::GT => 90,
-            Country::GG => 91,
-            Country::GN => 92,
-            Country::GW => 93,
-            Country::GY => 94,
-            Country::HT => 95,
-            Country::HM => 96,
-            Country::VA => 97,
-            Country::HN => 98,
-            Country::HK => 99,
-            Country::HU => 100,
-            Country::IS => 101,
-            Country::IN => 102,
-            Country::ID => 103,
-            Country::IR => 104,
-            Country::IQ => 105,
-            Country::IE => 106,
-            Country::IM => 107,
-            Country::IL => 108,
-            Country::IT => 109,
-            Country::JM => 110,
-            Country::JP => 111,
-            Country::JE => 112,
-            Country::JO => 113,
-            Country::KZ => 114,
-            Country::KE => 115,
-            Country::KI => 116,
-            Country::KP => 117,
-            Country::KR => 118,
-            Country::KW => 119,
-            Country::KG => 120,
-            Country::LA => 121,
-            Country::LV => 122,
-            Country::LB => 123,
-            Country::LS => 124,
-            Country::LR => 125,
-            Country::LY => 126,
-            Country::LI => 127,
-            Country::LT => 128,
-            Country::LU => 129,
-            Country::MO => 130,
-            Country::MK => 131,
-            Country::MG => 132,
-            Country::MW => 133,
-            Country::MY => 134,
-            Country::MV => 135,
-            Country::ML => 136,
-            Country::MT => 137,
-            Country::MH => 138,
-            Country::MQ => 139,
-            Country::MR => 140,
-            Country::MU => 141,
-            Country::YT => 142,
-            Country::MX => 143,
-            Country::FM => 144,
-            Country::MD => 145,
-            Country::MC => 146,
-            Country::MN => 147,
-            Country::ME => 148,
-            Country::MS => 149,
-            Country::MA => 150,
-            Country::MZ => 151,
-            Country::MM => 152,
-            Country::NA => 153,
-            Country::NR => 154,
-            Country::NP => 155,
-            Country::NL => 156,
-            Country::NC => 157,
-            Country::NZ => 158,
-            Country::NI => 159,
-            Country::NE => 160,
-            Country::NG => 161,
-            Country::NU => 162,
-            Country::NF => 163,
-            Country::MP => 164,
-            Country::NO => 165,
-            Country::OM => 166,
-            Country::PK => 167,
-            Country::PW => 168,
-            Country::PS => 169,
-            Country::PA => 170,
-            Country::PG => 171,
-            Country::PY => 172,
-            Country::PE => 173,
-            Country::PH => 174,
-            Country::PN => 175,
-            Country::PL => 176,
-            Country::PT => 177,
-            Country::PR => 178,
-            Country::QA => 179,
-            Country::RE => 180,
-            Country::RO => 181,
-            Country::RU => 182,
-            Country::RW => 183,
-            Country::BL => 184,
-            Country::SH => 185,
-            Country::KN => 186,
-            Country::LC => 187,
-            Country::MF => 188,
-            Country::PM => 189,
-            Country::VC => 190,
-            Country::WS => 191,
-            Country::SM => 192,
-            Country::ST => 193,
-            Country::SA => 194,
-            Country::SN => 195,
-            Country::RS => 196,
-            Country::SC => 197,
-            Country::SL => 198,
-            Country::SG => 199,
-            Country::SX => 200,
-            Country::SK => 201,
-            Country::SI => 202,
-            Country::SB => 203,
-            Country::SO => 204,
-            Country::ZA => 205,
-            Country::GS => 206,
-            Country::SS => 207,
-            Country::ES => 208,
-            Country::LK => 209,
-            Country::SD => 210,
-            Country::SR => 211,
-            Country::SJ => 212,
-            Country::SZ => 213,
-            Country::SE => 214,
-            Country::CH => 215,
-            Country::SY => 216,
-            Country::TW => 217,
-            Country::TJ => 218,
-            Country::TZ => 219,
-            Country::TH => 220,
-            Country::TL => 221,
-            Country::TG => 222,
-            Country::TK => 223,
-            Country::TO => 224,
-            Country::TT => 225,
-            Country::TN => 226,
-            Country::TR => 227,
-            Country::TM => 228,
-            Country::TC => 229,
-            Country::TV => 230,
-            Country::UG => 231,
-            Country::UA => 232,
-            Country::AE => 233,
-            Country::GB => 234,
-            Country::US => 235,
-            Country::UM => 236,
-            Country::UY => 237,
-            Country::UZ => 238,
-            Country::VU => 239,
-            Country::VE => 240,
-            Country::VN => 241,
-            Country::VG => 242,
-            Country::VI => 243,
-            Country::WF => 244,
-            Country::EH => 245,
-            Country::YE => 246,
-            Country::ZM => 247,
-            Country::ZW => 248,
-            Country::EU => 249,
-            Country::ZZ => 250,
-        }
-    }
-
-    pub fn name(&self) -> &'static str {
-        match *self {
-            Country::AF => "Afghanistan",
-            Country::AX => "Åland Islands",
-            Country::AL => "Albania",
-            Country::DZ => "Algeria",
-            Country::AS => "American Samoa",
-            Country::AD => "Andorra",
-            Country::AO => "Angola",
-            Country::AI => "Anguilla",
-            Country::AQ => "Antarctica",
-            Country::AG => "Antigua and Barbuda",
-            Country::AR => "Argentina",
-            Country::AM => "Armenia",
-            Country::AW => "Aruba",
-            Country::AU => "Australia",
-            Country::AT => "Austria",
-            Country::AZ => "Azerbaijan",
-            Country::BS => "Bahamas",
-            Country::BH => "Bahrain",
-            Country::BD => "Bangladesh",
-            Country::BB => "Barbados",
-            Country::BY => "Belarus",
-            Country::BE => "Belgium",
-            Country::BZ => "Belize",
-            Country::BJ => "Benin",
-            Country::BM => "Bermuda",
-            Country::BT => "Bhutan",
-            Country::BO => "Bolivia (Plurinational State of)",
-            Country::BQ => "Bonaire, Sint Eustatius and Saba",
-            Country::BA => "Bosnia and Herzegovina",
-            Country::BW => "Botswana",
-            Country::BV => "Bouvet Island",
-            Country::BR => "Brazil",
-            Country::IO => "British Indian Ocean Territory",
-            Country::BN => "Brunei Darussalam",
-            Country::BG => "Bulgaria",
-            Country::BF => "Burkina Faso",
-            Country::BI => "Burundi",
-            Country::KH => "Cambodia",
-            Country::CM => "Cameroon",
-            Country::CA => "Canada",
-            Country::CV => "Cabo Verde",
-            Country::KY => "Cayman Islands",
-            Country::CF => "Central African Republic",
-            Country::TD => "Chad",
-            Country::CL => "Chile",
-            Country::CN => "China",
-            Country::CX => "Christmas Island",
-            Country::CC => "Cocos (Keeling) Islands",
-            Country::CO => "Colombia",
-            Country::KM => "Comoros",
-            Country::CG => "Congo",
-            Country::CD => "Congo (Democratic Republic of the)",
-            Country::CK => "Cook Islands",
-            Country::CR => "Costa Rica",
-            Country::CI => "Côte d'Ivoire",
-            Country::HR => "Croatia",
-            Country::CU => "Cuba",
-            Country::CW => "Curaçao",
-            Country::CY => "Cyprus",
-            Country::CZ => "Czech Republic",
-            Country::DK => "Denmark",
-            Country::DJ => "Djibouti",
-            Country::DM => "Dominica",
-            Country::DO => "Dominican Republic",
-            Country::EC => "Ecuador",
-            Country::EG => "Egypt",
-            Country::SV => "El Salvador",
-            Country::GQ => "Equatorial Guinea",
-            Country::ER => "Eritrea",
-            Country::EE => "Estonia",
-            Country::ET => "Ethiopia",
-            Country::FK => "Falkland Islands (Malvinas)",
-            Country::FO => "Faroe Islands",
-            Country::FJ => "Fiji",
-            Country::FI => "Finland",
-            Country::FR => "France",
-            Country::GF => "French Guiana",
-            Country::PF => "French Polynesia",
-            Country::TF => "French Southern Territories",
-            Country::GA => "Gabon",
-            Country::GM => "Gambia",
-            Country::GE => "Georgia",
-            Country::DE => "Germany",
-            Country::GH => "Ghana",
-            Country::GI => "Gibraltar",
-            Country::GR => "Greece",
-            Country::GL => "Greenland",
-            Country::GD => "Grenada",
-            Country::GP => "Guadeloupe",
-            Country::GU => "Guam",
-            Country::GT => "Guatemala",
-            Country::GG => "Guernsey",
-            Country::GN => "Guinea",
-            Country::GW => "Guinea-Bissau",
-            Country::GY => "Guyana",
-            Country::HT => "Haiti",
-            Country::HM => "Heard Island and McDonald Islands",
-            Country::VA => "Holy See",
-            Country::HN => "Honduras",
-            Country::HK => "Hong Kong",
-            Country::HU => "Hungary",
-            Country::IS => "Iceland",
-            Country::IN => "India",
-            Country::ID => "Indonesia",
-            Country::IR => "Iran (Islamic Republic of)",
-            Country::IQ => "Iraq",
-            Country::IE => "Ireland",
-            Country::IM => "Isle of Man",
-            Country::IL => "Israel",
-            Country::IT => "Italy",
-            Country::JM => "Jamaica",
-            Country::JP => "Japan",
-            Country::JE => "Jersey",
-            Country::JO => "Jordan",
-            Country::KZ => "Kazakhstan",
-            Country::KE => "Kenya",
-            Country::KI => "Kiribati",
-            Country::KP => "Korea (Democratic People's Republic of)",
-            Country::KR => "Korea (Republic of)",
-            Country::KW => "Kuwait",
-            Country::KG => "Kyrgyzstan",
-            Country::LA => "Lao People's Democratic Republic",
-            Country::LV => "Latvia",
-            Country::LB => "Lebanon",
-            Country::LS => "Lesotho",
-            Country::LR => "Liberia",
-            Country::LY => "Libya",
-            Country::LI => "Liechtenstein",
-            Country::LT => "Lithuania",
-            Country::LU => "Luxembourg",
-            Country::MO => "Macao",
-            Country::MK => "Macedonia (the former Yugoslav Republic of)",
-            Country::MG => "Madagascar",
-            Country::MW => "Malawi",
-            Country::MY => "Malaysia",
-            Country::MV => "Maldives",
-            Country::ML => "Mali",
-            Country::MT => "Malta",
-            Country::MH => "Marshall Islands",
-            Country::MQ => "Martinique",
-            Country::MR => "Mauritania",
-            Country::MU => "Mauritius",
-            Country::YT => "Mayotte",
-            Country::MX => "Mexico",
-            Country::FM => "Micronesia (Federated States of)",
-            Country::MD => "Moldova (Republic of)",
-            Country::MC => "Monaco",
-            Country::MN => "Mongolia",
-            Country::ME => "Montenegro",
-            Country::MS => "Montserrat",
-            Country::MA => "Morocco",
-            Country::MZ => "Mozambique",
-            Country::MM => "Myanmar",
-            Country::NA => "Namibia",
-            Country::NR => "Nauru",
-            Country::NP => "Nepal",
-            Country::NL => "Netherlands",
-            Country::NC => "New Caledonia",
-            Country::NZ => "New Zealand",
-            Country::NI => "Nicaragua",
-            Country::NE => "Niger",
-            Country::NG => "Nigeria",
-            Country::NU => "Niue",
-            Country::NF => "Norfolk Island",
-            Country::MP => "Northern Mariana Islands",
-            Country::NO => "Norway",
-            Country::OM => "Oman",
-            Country::PK => "Pakistan",
-            Country::PW => "Palau",
-            Country::PS => "Palestine, State of",
-            Country::PA => "Panama",
-            Country::PG => "Papua New Guinea",
-            Country::PY => "Paraguay",
-            Country::PE => "Peru",
-            Country::PH => "Philippines",
-            Country::PN => "Pitcairn",
-            Country::PL => "Poland",
-            Country::PT => "Portugal",
-            Country::PR => "Puerto Rico",
-            Country::QA => "Qatar",
-            Country::RE => "Réunion",
-            Country::RO => "Romania",
-            Country::RU => "Russian Federation",
-            Country::RW => "Rwanda",
-            Country::BL => "Saint Barthélemy",
-            Country::SH => "Saint Helena, Ascension and Tristan da Cunha",
-            Country::KN => "Saint Kitts and Nevis",
-            Country::LC => "Saint Lucia",
-            Country::MF => "Saint Martin (French part)",
-            Country::PM => "Saint Pierre and Miquelon",
-            Country::VC => "Saint Vincent and the Grenadines",
-            Country::WS => "Samoa",
-            Country::SM => "San Marino",
-            Country::ST => "Sao Tome and Principe",
-            Country::SA => "Saudi Arabia",
-            Country::SN => "Senegal",
-            Country::RS => "Serbia",
-            Country::SC => "Seychelles",
-            Country::SL => "Sierra Leone",
-            Country::SG => "Singapore",
-            Country::SX => "Sint Maarten (Dutch part)",
-            Country::SK => "Slovakia",
-            Country::SI => "Slovenia",
-            Country::SB => "Solomon Islands",
-            Country::SO => "Somalia",
-            Country::ZA => "South Africa",
-            Country::GS => "South Georgia and the South Sandwich Islands",
-            Country::SS => "South Sudan",
-            Country::ES => "Spain",
-            Country::LK => "Sri Lanka",
-            Country::SD => "Sudan",
-            Country::SR => "Suriname",
-            Country::SJ => "Svalbard and Jan Mayen",
-            Country::SZ => "Swaziland",
-            Country::SE => "Sweden",
-            Country::CH => "Switzerland",
-            Country::SY => "Syrian Arab Republic",
-            Country::TW => "Taiwan, Province of China",
-            Country::TJ => "Tajikistan",
-            Country::TZ => "Tanzania, United Republic of",
-            Country::TH => "Thailand",
-            Country::TL => "Timor-Leste",
-            Country::TG => "Togo",
-            Country::TK => "Tokelau",
-            Country::TO => "Tonga",
-            Country::TT => "Trinidad and Tobago",
-            Country::TN => "Tunisia",
-            Country::TR => "Turkey",
-            Country::TM => "Turkmenistan",
-            Country::TC => "Turks and Caicos Islands",
-            Country::TV => "Tuvalu",
-            Country::UG => "Uganda",
-            Country::UA => "Ukraine",
-            Country::AE => "United Arab Emirates",
-            Country::GB => "United Kingdom of Great Britain and Northern Ireland",
-            Country::US => "United States of America",
-            Country::UM => "United States Minor Outlying Islands",
-            Country::UY => "Uruguay",
-            Country::UZ => "Uzbekistan",
-            Country::VU => "Vanuatu",
-            Country::VE => "Venezuela (Bolivarian Republic of)",
-            Country::VN => "Viet Nam",
-            Country::VG => "Virgin Islands (British)",
-            Country::VI => "Virgin Islands (U.S.)",
-            Country::WF => "Wallis and Futuna",
-            Country::EH => "Western Sahara",
-            Country::YE => "Yemen",
-            Country::ZM => "Zambia",
-            Country::ZW => "Zimbabwe",
-            Country::EU => "Europe",
-            Country::ZZ => "Unknown or unspecified country",
-        }
-    }
-}
-
-
-impl FromStr for Country {
-    type Err = Error;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "AF" => Ok(Country::AF),
-            "AX" => Ok(Country::AX),
-            "AL" => Ok(Country::AL),
-            "DZ" => Ok(Country::DZ),
-            "AS" => Ok(Country::AS),
-            "AD" => Ok(Country::AD),
-            "AO" => Ok(Country::AO),
-            "AI" => Ok(Country::AI),
-            "AQ" => Ok(Country::AQ),
-            "AG" => Ok(Country::AG),
-            "AR" => Ok(Country::AR),
-            "AM" => Ok(Country::AM),
-            "AW" => Ok(Country::AW),
-            "AU" => Ok(Country::AU),
-            "AT" => Ok(Country::AT),
-            "AZ" => Ok(Country::AZ),
-            "BS" => Ok(Country::BS),
-            "BH" => Ok(Country::BH),
-            "BD" => Ok(Country::BD),
-            "BB" => Ok(Country::BB),
-            "BY" => Ok(Country::BY),
-            "BE" => Ok(Country::BE),
-            "BZ" => Ok(Country::BZ),
-            "BJ" => Ok(Country::BJ),
-            "BM" => Ok(Country::BM),
-            "BT" => Ok(Country::BT),
-            "BO" => Ok(Country::BO),
-            "BQ" => Ok(Country::BQ),
-            "BA" => Ok(Country::BA),
-            "BW" => Ok(Country::BW),
-            "BV" => Ok(Country::BV),
-            "BR" => Ok(Country::BR),
-            "IO" => Ok(Country::IO),
-            "BN" => Ok(Country::BN),
-            "BG" => Ok(Country::BG),
-            "BF" => Ok(Country::BF),
-            "BI" => Ok(Country::BI),
-            "KH" => Ok(Country::KH),
-            "CM" => Ok(Country::CM),
-            "CA" => Ok(Country::CA),
-            "CV" => Ok(Country::CV),
-            "KY" => Ok(Country::KY),
-            "CF" => Ok(Country::CF),
-            "TD" => Ok(Country::TD),
-            "CL" => Ok(Country::CL),
-            "CN" => Ok(Country::CN),
-            "CX" => Ok(Country::CX),
-            "CC" => Ok(Country::CC),
-            "CO" => Ok(Country::CO),
-            "KM" => Ok(Country::KM),
-            "CG" => Ok(Country::CG),
-            "CD" => Ok(Country::CD),
-            "CK" => Ok(Country::CK),
-            "CR" => Ok(Country::CR),
-            "CI" => Ok(Country::CI),
-            "HR" => Ok(Country::HR),
-            "CU" => Ok(Country::CU),
-            "CW" => Ok(Country::CW),
-            "CY" => Ok(Country::CY),
-            "CZ" => Ok(Country::CZ),
-            "DK" => Ok(Country::DK),
-            "DJ" => Ok(Country::DJ),
-            "DM" => Ok(Country::DM),
-            "DO" => Ok(Country::DO),
-            "EC" => Ok(Country::EC),
-            "EG" => Ok(Country::EG),
-            "SV" => Ok(Country::SV),
-            "GQ" => Ok(Country::GQ),
-            "ER" => Ok(Country::ER),
-            "EE" => Ok(Country::EE),
-            "ET" => Ok(Country::ET),
-            "FK" => Ok(Country::FK),
-            "FO" => Ok(Country::FO),
-            "FJ" => Ok(Country::FJ),
-            "FI" => Ok(Country::FI),
-            "FR" => Ok(Country::FR),
-            "GF" => Ok(Country::GF),
-            "PF" => Ok(Country::PF),
-            "TF" => Ok(Country::TF),
-            "GA" => Ok(Country::GA),
-            "GM" => Ok(Country::GM),
-            "GE" => Ok(Country::GE),
-            "DE" => Ok(Country::DE),
-            "GH" => Ok(Country::GH),
-            "GI" => Ok(Country::GI),
-            "GR" => Ok(Country::GR),
-            "GL" => Ok(Country::GL),
-            "GD" => Ok(Country::GD),
-            "GP" => Ok(Country::GP),
-            "GU" => Ok(Country::GU),
-            "GT" => Ok(Country::GT),
-            "GG" => Ok(Country::GG),
-            "GN" => Ok(Country::GN),
-            "GW" => Ok(Country::GW),
-            "GY" => Ok(Country::GY),
-            "HT" => Ok(Country::HT),
-            "HM" => Ok(Country::HM),
-            "VA" => Ok(Country::VA),
-            "HN" => Ok(Country::HN),
-            "HK" => Ok(Country::HK),
-            "HU" => Ok(Country::HU),
-            "IS" => Ok(Country::IS),
-            "IN" => Ok(Country::IN),
-            "ID" => Ok(Country::ID),
-            "IR" => Ok(Country::IR),
-            "IQ" => Ok(Country::IQ),
-            "IE" => Ok(Country::IE),
-            "IM" => Ok(Country::IM),
-            "IL" => Ok(Country::IL),
-            "IT" => Ok(Country::IT),
-            "JM" => Ok(Country::JM),
-            "JP" => Ok(Country::JP),
-            "JE" => Ok(Country::JE),
-            "JO" => Ok(Country::JO),
-            "KZ" => Ok(Country::KZ),
-            "KE" => Ok(Country::KE),
-            "KI" => Ok(Country::KI),
-            "KP" => Ok(Country::KP),
-            "KR" => Ok(Country::KR),
-            "KW" => Ok(Country::KW),
-            "KG" => Ok(Country::KG),
-            "LA" => Ok(Country::LA),
-            "LV" => Ok(Country::LV),
-            "LB" => Ok(Country::LB),
-            "LS" => Ok(Country::LS),
-            "LR" => Ok(Country::LR),
-            "LY" => Ok(Country::LY),
-            "LI" => Ok(Country::LI),
-            "LT" => Ok(Country::LT),
-            "LU" => Ok(Country::LU),
-            "MO" => Ok(Country::MO),
-            "MK" => Ok(Country::MK),
-            "MG" => Ok(Country::MG),
-            "MW" => Ok(Country::MW),
-            "MY" => Ok(Country::MY),
-            "MV" => Ok(Country::MV),
-            "ML" => Ok(Country::ML),
-            "MT" => Ok(Country::MT),
-            "MH" => Ok(Country::MH),
-            "MQ" => Ok(Country::MQ),
-            "MR" => Ok(Country::MR),
-            "MU" => Ok(Country::MU),
-            "YT" => Ok(Country::YT),
-            "MX" => Ok(Country::MX),
-            "FM" => Ok(Country::FM),
-            "MD" => Ok(Country::MD),
-            "MC" => Ok(Country::MC),
-            "MN" => Ok(Country::MN),
-            "ME" => Ok(Country::ME),
-            "MS" => Ok(Country::MS),
-            "MA" => Ok(Country::MA),
-            "MZ" => Ok(Country::MZ),
-            "MM" => Ok(Country::MM),
-            "NA" => Ok(Country::NA),
-            "NR" => Ok(Country::NR),
-            "NP" => Ok(Country::NP),
-            "NL" => Ok(Country::NL),
-            "NC" => Ok(Country::NC),
-            "NZ" => Ok(Country::NZ),
-            "NI" => Ok(Country::NI),
-            "NE" => Ok(Country::NE),
-            "NG" => Ok(Country::NG),
-            "NU" => Ok(Country::NU),
-            "NF" => Ok(Country::NF),
-            "MP" => Ok(Country::MP),
-            "NO" => Ok(Country::NO),
-            "OM" => Ok(Country::OM),
-            "PK" => Ok(Country::PK),
-            "PW" => Ok(Country::PW),
-            "PS" => Ok(Country::PS),
-            "PA" => Ok(Country::PA),
-            "PG" => Ok(Country::PG),
-            "PY" => Ok(Country::PY),
-            "PE" => Ok(Country::PE),
-            "PH" => Ok(Country::PH),
-            "PN" => Ok(Country::PN),
-            "PL" => Ok(Country::PL),
-            "PT" => Ok(Country::PT),
-            "PR" => Ok(Country::PR),
-            "QA" => Ok(Country::QA),
-            "RE" => Ok(Country::RE),
-            "RO" => Ok(Country::RO),
-            "RU" => Ok(Country::RU),
-            "RW" => Ok(Country::RW),
-            "BL" => Ok(Country::BL),
-            "SH" => Ok(Country::SH),
-            "KN" => Ok(Country::KN),
-            "LC" => Ok(Country::LC),
-            "MF" => Ok(Country::MF),
-            "PM" => Ok(Country::PM),
-            "VC" => Ok(Country::VC),
-            "WS" => Ok(Country::WS),
-            "SM" => Ok(Country::SM),
-            "ST" => Ok(Country::ST),
-            "SA" => Ok(Country::SA),
-            "SN" => Ok(Country::SN),
-            "RS" => Ok(Country::RS),
-            "SC" => Ok(Country::SC),
-            "SL" => Ok(Country::SL),
-            "SG" => Ok(Country::SG),
-            "SX" => Ok(Country::SX),
-            "SK" => Ok(Country::SK),
-            "SI" => Ok(Country::SI),
-            "SB" => Ok(Country::SB),
-            "SO" => Ok(Country::SO),
-            "ZA" => Ok(Country::ZA),
-            "GS" => Ok(Country::GS),
-            "SS" => Ok(Country::SS),
-            "ES" => Ok(Country::ES),
-            "LK" => Ok(Country::LK),
-            "SD" => Ok(Country::SD),
-            "SR" => Ok(Country::SR),
-            "SJ" => Ok(Country::SJ),
-            "SZ" => Ok(Country::SZ),
-            "SE" => Ok(Country::SE),
-            "CH" => Ok(Country::CH),
-            "SY" => Ok(Country::SY),
-            "TW" => Ok(Country::TW),
-            "TJ" => Ok(Country::TJ),
-            "TZ" => Ok(Country::TZ),
-            "TH" => Ok(Country::TH),
-            "TL" => Ok(Country::TL),
-            "TG" => Ok(Country::TG),
-            "TK" => Ok(Country::TK),
-            "TO" => Ok(Country::TO),
-            "TT" => Ok(Country::TT),
-            "TN" => Ok(Country::TN),
-            "TR" => Ok(Country::TR),
-            "TM" => Ok(Country::TM),
-            "TC" => Ok(Country::TC),
-            "TV" => Ok(Country::TV),
-            "UG" => Ok(Country::UG),
-            "UA" => Ok(Country::UA),
-            "AE" => Ok(Country::AE),
-            "GB" => Ok(Country::GB),
-            "US" => Ok(Country::US),
-            "UM" => Ok(Country::UM),
-            "UY" => Ok(Country::UY),
-            "UZ" => Ok(Country::UZ),
-            "VU" => Ok(Country::VU),
-            "VE" => Ok(Country::VE),
-            "VN" => Ok(Country::VN),
-            "VG" => Ok(Country::VG),
-            "VI" => Ok(Country::VI),
-            "WF" => Ok(Country::WF),
-            "EH" => Ok(Country::EH),
-            "YE" => Ok(Country::YE),
-            "ZM" => Ok(Country::ZM),
-            "ZW" => Ok(Country::ZW),
-            "EU" => Ok(Country::EU),
-            "ZZ" => Ok(Country::ZZ),
-            e @ _ => Err(Error::ParseCountryError(format!("Unknow Country Code ({})", e)))
-        }
-    }
-}
-
-
-impl fmt::Display for Country {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {
-            Country::AF => write!(f, "AF"),
-            Country::AX => write!(f, "AX"),
-            Country::AL => write!(f, "AL"),
-            Country::DZ => write!(f, "DZ"),
-            Country::AS => write!(f, "AS"),
-            Country::AD => write!(f, "AD"),
-            Country::AO => write!(f, "AO"),
-            Country::AI => write!(f, "AI"),
-            Country::AQ => write!(f, "AQ"),
-            Country::AG => write!(f, "AG"),
-            Country::AR => write!(f, "AR"),
-            Country::AM => write!(f, "AM"),
-            Country::AW => write!(f, "AW"),
-            Country::AU => write!(f, "AU"),
-            Country::AT => write!(f, "AT"),
-            Country::AZ => write!(f, "AZ"),
-            Country::BS => write!(f, "BS"),
-            Country::BH => write!(f, "BH"),
-            Country::BD => write!(f, "BD"),
-            Country::BB => write!(f, "BB"),
-            Country::BY => write!(f, "BY"),
-            Country::BE => write!(f, "BE"),
-            Country::BZ => write!(f, "BZ"),
-            Country::BJ => write!(f, "BJ"),
-            Country::BM => write!(f, "BM"),
-            Country::BT => write!(f, "BT"),
-            Country::BO => write!(f, "BO"),
-            Country::BQ => write!(f, "BQ"),
-            Country::BA => write!(f, "BA"),
-            Country::BW => write!(f, "BW"),
-            Country::BV => write!(f, "BV"),
-            Country::BR => write!(f, "BR"),
-            Country::IO => write!(f, "IO"),
-            Country::BN => write!(f, "BN"),
-            Country::BG => write!(f, "BG"),
-            Country::BF => write!(f, "BF"),
-            Country::BI => write!(f, "BI"),
-            Country::KH => write!(f, "KH"),
-            Country::CM => write!(f, "CM"),
-            Country::CA => write!(f, "CA"),
-            Country::CV => write!(f, "CV"),
-            Country::KY => write!(f, "KY"),
-            Country::CF => write!(f, "CF"),
-            Country::TD => write!(f, "TD"),
-            Country::CL => write!(f, "CL"),
-            Country::CN => write!(f, "CN"),
-            Country::CX => write!(f, "CX"),
-            Country::CC => write!(f, "CC"),
-            Country::CO => write!(f, "CO"),
-            Country::KM => write!(f, "KM"),
-            Country::CG => write!(f, "CG"),
-            Country::CD => write!(f, "CD"),
-            Country::CK => write!(f, "CK"),
-            Country::CR => write!(f, "CR"),
-            Country::CI => write!(f, "CI"),
-            Country::HR => write!(f, "HR"),
-            Country::CU => write!(f, "CU"),
-            Country::CW => write!(f, "CW"),
-            Country::CY => write!(f, "CY"),
-            Country::CZ => write!(f, "CZ"),
-            Country::DK => write!(f, "DK"),
-            Country::DJ => write!(f, "DJ"),
-            Country::DM => write!(f, "DM"),
-            Country::DO => write!(f, "DO"),
-            Country::EC => write!(f, "EC"),
-            Country::EG => write!(f, "EG"),
-            Country::SV => write!(f, "SV"),
-            Country::GQ => write!(f, "GQ"),
-            Country::ER => write!(f, "ER"),
-            Country::EE => write!(f, "EE"),
-            Country::ET => write!(f, "ET"),
-            Country::FK => write!(f, "FK"),
-            Country::FO => write!(f, "FO"),
-            Country::FJ => write!(f, "FJ"),
-            Country::FI => write!(f, "FI"),
-            Country::FR => write!(f, "FR"),
-            Country::GF => write!(f, "GF"),
-            Country::PF => write!(f, "PF"),
-            Country::TF => write!(f, "TF"),
-            Country::GA => write!(f, "GA"),
-            Country::GM => write!(f, "GM"),
-            Country::GE => write!(f, "GE"),
-            Country::DE => write!(f, "DE"),
-            Country::GH => write!(f, "GH"),
-            Country::GI => write!(f, "GI"),
-            Country::GR => write!(f, "GR"),
-            Country::GL => write!(f, "GL"),
-            Country::GD => write!(f, "GD"),
-            Country::GP => write!(f, "GP"),
-            Country::GU => write!(f, "GU"),
-            Country::GT => write!(f, "GT"),
-            Country::GG => write!(f, "GG"),
-            Country::GN => write!(f, "GN"),
-            Country::GW => write!(f, "GW"),
-            Country::GY => write!(f, "GY"),
-            Country::HT => write!(f, "HT"),
-            Country::HM => write!(f, "HM"),
-            Country::VA => write!(f, "VA"),
-            Country::HN => write!(f, "HN"),
-            Country::HK => write!(f, "HK"),
-            Country::HU => write!(f, "HU"),
-            Country::IS => write!(f, "IS"),
-            Country::IN => write!(f, "IN"),
-            Country::ID => write!(f, "ID"),
-            Country::IR => write!(f, "IR"),
-            Country::IQ => write!(f, "IQ"),
-            Country::IE => write!(f, "IE"),
-            Country::IM => write!(f, "IM"),
-            Country::IL => write!(f, "IL"),
-            Country::IT => write!(f, "IT"),
-            Country::JM => write!(f, "JM"),
-            Country::JP => write!(f, "JP"),
-            Country::JE => write!(f, "JE"),
-            Country::JO => write!(f, "JO"),
-            Country::KZ => write!(f, "KZ"),
-            Country::KE => write!(f, "KE"),
-            Country::KI => write!(f, "KI"),
-            Country::KP => write!(f, "KP"),
-            Country::KR => write!(f, "KR"),
-            Country::KW => write!(f, "KW"),
-            Country::KG => write!(f, "KG"),
-            Country::LA => write!(f, "LA"),
-            Country::LV => write!(f, "LV"),
-            Country::LB => write!(f, "LB"),
-            Country::LS => write!(f, "LS"),
-            Country::LR => write!(f, "LR"),
-            Country::LY => write!(f, "LY"),
-            Country::LI => write!(f, "LI"),
-            Country::LT => write!(f, "LT"),
-            Country::LU => write!(f, "LU"),
-            Country::MO => write!(f, "MO"),
-            Country::MK => write!(f, "MK"),
-            Country::MG => write!(f, "MG"),
-            Country::MW => write!(f, "MW"),
-            Country::MY => write!(f, "MY"),
-            Country::MV => write!(f, "MV"),
-            Country::ML => write!(f, "ML"),
-            Country::MT => write!(f, "MT"),
-            Country::MH => write!(f, "MH"),
-            Country::MQ => write!(f, "MQ"),
-            Country::MR => write!(f, "MR"),
-            Country::MU => write!(f, "MU"),
-            Country::YT => write!(f, "YT"),
-            Country::MX => write!(f, "MX"),
-            Country::FM => write!(f, "FM"),
-            Country::MD => write!(f, "MD"),
-            Country::MC => write!(f, "MC"),
-            Country::MN => write!(f, "MN"),
-            Country::ME => write!(f, "ME"),
-            Country::MS => write!(f, "MS"),
-            Country::MA => write!(f, "MA"),
-            Country::MZ => write!(f, "MZ"),
-            Country::MM => write!(f, "MM"),
-            Country::NA => write!(f, "NA"),
-            Country::NR => write!(f, "NR"),
-            Country::NP => write!(f, "NP"),
-            Country::NL => write!(f, "NL"),
-            Country::NC => write!(f, "NC"),
-            Country::NZ => write!(f, "NZ"),
-            Country::NI => write!(f, "NI"),
-            Country::NE => write!(f, "NE"),
-            Country::NG => write!(f, "NG"),
-            Country::NU => write!(f, "NU"),
-            Country::NF => write!(f, "NF"),
-            Country::MP => write!(f, "MP"),
-            Country::NO => write!(f, "NO"),
-            Country::OM => write!(f, "OM"),
-            Country::PK => write!(f, "PK"),
-            Country::PW => write!(f, "PW"),
-            Country::PS => write!(f, "PS"),
-            Country::PA => write!(f, "PA"),
-            Country::PG => write!(f, "PG"),
-            Country::PY => write!(f, "PY"),
-            Country::PE => write!(f, "PE"),
-            Country::PH => write!(f, "PH"),
-            Country::PN => write!(f, "PN"),
-            Country::PL => write!(f, "PL"),
-            Country::PT => write!(f, "PT"),
-            Country::PR => write!(f, "PR"),
-            Country::QA => write!(f, "QA"),
-            Country::RE => write!(f, "RE"),
-            Country::RO => write!(f, "RO"),
-            Country::RU => write!(f, "RU"),
-            Country::RW => write!(f, "RW"),
-            Country::BL => write!(f, "BL"),
-            Country::SH => write!(f, "SH"),
-            Country::KN => write!(f, "KN"),
-            Country::LC => write!(f, "LC"),
-            Country::MF => write!(f, "MF"),
-            Country::PM => write!(f, "PM"),
-            Country::VC => write!(f, "VC"),
-            Country::WS => write!(f, "WS"),
-            Country::SM => write!(f, "SM"),
-            Country::ST => write!(f, "ST"),
-            Country::SA => write!(f, "SA"),
-            Country::SN => write!(f, "SN"),
-            Country::RS => write!(f, "RS"),
-            Country::SC => write!(f, "SC"),
-            Country::SL => write!(f, "SL"),
-            Country::SG => write!(f, "SG"),
-            Country::SX => write!(f, "SX"),
-            Country::SK => write!(f, "SK"),
-            Country::SI => write!(f, "SI"),
-            Country::SB => write!(f, "SB"),
-            Country::SO => write!(f, "SO"),
-            Country::ZA => write!(f, "ZA"),
-            Country::GS => write!(f, "GS"),
-            Country::SS => write!(f, "SS"),
-            Country::ES => write!(f, "ES"),
-            Country::LK => write!(f, "LK"),
-            Country::SD => write!(f, "SD"),
-            Country::SR => write!(f, "SR"),
-            Country::SJ => write!(f, "SJ"),
-            Country::SZ => write!(f, "SZ"),
-            Country::SE => write!(f, "SE"),
-            Country::CH => write!(f, "CH"),
-            Country::SY => write!(f, "SY"),
-            Country::TW => write!(f, "TW"),
-            Country::TJ => write!(f, "TJ"),
-            Country::TZ => write!(f, "TZ"),
-            Country::TH => write!(f, "TH"),
-            Country::TL => write!(f, "TL"),
-            Country::TG => write!(f, "TG"),
-            Country::TK => write!(f, "TK"),
-            Country::TO => write!(f, "TO"),
-            Country::TT => write!(f, "TT"),
-            Country::TN => write!(f, "TN"),
-            Country::TR => write!(f, "TR"),
-            Country::TM => write!(f, "TM"),
-            Country::TC => write!(f, "TC"),
-            Country::TV => write!(f, "TV"),
-            Country::UG => write!(f, "UG"),
-            Country::UA => write!(f, "UA"),
-            Country::AE => write!(f, "AE"),
-            Country::GB => write!(f, "GB"),
-            Country::US => write!(f, "US"),
-            Country::UM => write!(f, "UM"),
-            Country::UY => write!(f, "UY"),
-            Country::UZ => write!(f, "UZ"),
-            Country::VU => write!(f, "VU"),
-            Country::VE => write!(f, "VE"),
-            Country::VN => write!(f, "VN"),
-            Country::VG => write!(f, "VG"),
-            Country::VI => write!(f, "VI"),
-            Country::WF => write!(f, "WF"),
-            Country::EH => write!(f, "EH"),
-            Country::YE => write!(f, "YE"),
-            Country::ZM => write!(f, "ZM"),
-            Country::ZW => write!(f, "ZW"),
-            Country::EU => write!(f, "EU"),
-            Country::ZZ => write!(f, "ZZ"),
-        }
-    }
-}
-
@@ -0,0 +1,238 @@
+//! Compare two builds of the database, range by range.
+//!
+//! ```
+//! let old = iana::parse_str("2|afrinic|20190910|1|00000000|20190910|00000\n\
+//!                            afrinic|ZA|ipv4|41.0.0.0|512|20071126|allocated\n");
+//! let new = iana::parse_str("2|afrinic|20190911|1|00000000|20190911|00000\n\
+//!                            afrinic|EG|ipv4|41.0.0.0|256|20071126|allocated\n");
+//!
+//! let deltas = iana::diff(&old, &new);
+//! assert_eq!(deltas[0].kind(), iana::ChangeKind::Country);
+//! assert_eq!(deltas[1].kind(), iana::ChangeKind::Removed);
+//! ```
+
+use crate::rir::country::Country;
+use crate::rir::registry::Registry;
+use crate::rir::status::Status;
+use crate::rir::record::Record;
+use crate::rir::overlay::{Painted, paint};
+use crate::rir::error::Error;
+
+use std::fmt;
+use std::str::FromStr;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+
+/// What a range is delegated as in one build.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Delegation {
+    pub registry: Registry,
+    pub country: Country,
+    pub status: Status,
+}
+
+impl fmt::Display for Delegation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {}", self.registry, self.country, self.status)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ChangeKind {
+    /// Only in the new build.
+    Added,
+    /// Only in the old build.
+    Removed,
+    /// Moved to another registry.
+    Reassigned,
+    /// Same registry, another country.
+    Country,
+    /// Same registry and country, another status.
+    Status,
+}
+
+impl FromStr for ChangeKind {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "added" => Ok(ChangeKind::Added),
+            "removed" => Ok(ChangeKind::Removed),
+            "reassigned" => Ok(ChangeKind::Reassigned),
+            "country" => Ok(ChangeKind::Country),
+            "status" => Ok(ChangeKind::Status),
+            e => Err(Error::ParseChangeKindError(format!("Unknow Change Kind ({})", e))),
+        }
+    }
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ChangeKind::Added => write!(f, "added"),
+            ChangeKind::Removed => write!(f, "removed"),
+            ChangeKind::Reassigned => write!(f, "reassigned"),
+            ChangeKind::Country => write!(f, "country"),
+            ChangeKind::Status => write!(f, "status"),
+        }
+    }
+}
+
+/// A range delegated differently in the two builds.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Delta {
+    pub first: IpAddr,
+    pub last: IpAddr,
+    pub old: Option<Delegation>,
+    pub new: Option<Delegation>,
+}
+
+impl Delta {
+    pub fn kind(&self) -> ChangeKind {
+        match (self.old, self.new) {
+            (None, _) => ChangeKind::Added,
+            (_, None) => ChangeKind::Removed,
+            (Some(old), Some(new)) if old.registry != new.registry => ChangeKind::Reassigned,
+            (Some(old), Some(new)) if old.country != new.country => ChangeKind::Country,
+            _ => ChangeKind::Status,
+        }
+    }
+
+    /// The registry now responsible for the range, or the one that was.
+    pub fn registry(&self) -> Registry {
+        self.new.or(self.old).map(|delegation| delegation.registry).unwrap()
+    }
+
+    /// Number of addresses in the range.
+    pub fn size(&self) -> u128 {
+        match (self.first, self.last) {
+            (IpAddr::V4(first), IpAddr::V4(last)) => (u32::from(last) - u32::from(first)) as u128 + 1,
+            (IpAddr::V6(first), IpAddr::V6(last)) => (u128::from(last) - u128::from(first)).saturating_add(1),
+            _ => unreachable!(),
+        }
+    }
+}
+
+
+fn paint_records<'a, I>(records: I) -> (Painted<Delegation>, Painted<Delegation>)
+    where I: IntoIterator<Item = &'a Record>
+{
+    let mut v4 = Painted::new();
+    let mut v6 = Painted::new();
+    for record in records {
+        // The IANA delegations to the RIRs cover the RIR records.
+        if record.src_registry() == Registry::Iana {
+            continue;
+        }
+
+        let delegation = Delegation { registry: record.src_registry(), country: record.country(), status: record.status() };
+        let ip_block = record.ip_block();
+        match (ip_block.first(), ip_block.last()) {
+            (IpAddr::V4(first), IpAddr::V4(last)) => paint(&mut v4, u32::from(first) as u128, u32::from(last) as u128, delegation),
+            (IpAddr::V6(first), IpAddr::V6(last)) => paint(&mut v6, u128::from(first), u128::from(last), delegation),
+            _ => unreachable!(),
+        }
+    }
+    (v4, v6)
+}
+
+/// The value covering `n`, moving `pos` forward. `n` must not decrease between calls.
+fn value_at(segments: &[(u128, u128, Delegation)], pos: &mut usize, n: u128) -> Option<Delegation> {
+    while *pos < segments.len() && segments[*pos].1 < n {
+        *pos += 1;
+    }
+    segments.get(*pos).filter(|segment| segment.0 <= n).map(|segment| segment.2)
+}
+
+fn diff_family(old: &Painted<Delegation>, new: &Painted<Delegation>) -> Vec<(u128, u128, Option<Delegation>, Option<Delegation>)> {
+    let old: Vec<(u128, u128, Delegation)> = old.iter().map(|(&first, &(last, value))| (first, last, value)).collect();
+    let new: Vec<(u128, u128, Delegation)> = new.iter().map(|(&first, &(last, value))| (first, last, value)).collect();
+
+    // Every address where either build changes, as the start of an elementary range.
+    let mut bounds: Vec<u128> = old.iter().chain(new.iter())
+        .flat_map(|&(first, last, _)| std::iter::once(Some(first)).chain(std::iter::once(last.checked_add(1))))
+        .flatten()
+        .collect();
+    bounds.sort_unstable();
+    bounds.dedup();
+
+    let (mut old_pos, mut new_pos) = (0, 0);
+    let mut deltas: Vec<(u128, u128, Option<Delegation>, Option<Delegation>)> = Vec::new();
+    for (i, &start) in bounds.iter().enumerate() {
+        let end = match bounds.get(i + 1) {
+            Some(next) => next - 1,
+            None => u128::MAX,
+        };
+        let before = value_at(&old, &mut old_pos, start);
+        let after = value_at(&new, &mut new_pos, start);
+        if before == after {
+            continue;
+        }
+
+        match deltas.last_mut() {
+            Some(prev) if prev.1.checked_add(1) == Some(start) && prev.2 == before && prev.3 == after => prev.1 = end,
+            _ => deltas.push((start, end, before, after)),
+        }
+    }
+    deltas
+}
+
+/// The ranges delegated differently between two builds, IPv4 first, in address order.
+///
+/// Adjacent ranges with the same change are joined.
+pub fn diff<'a, I, J>(old: I, new: J) -> Vec<Delta>
+    where I: IntoIterator<Item = &'a Record>,
+          J: IntoIterator<Item = &'a Record>
+{
+    let (old_v4, old_v6) = paint_records(old);
+    let (new_v4, new_v6) = paint_records(new);
+
+    let v4 = diff_family(&old_v4, &new_v4).into_iter().map(|(first, last, old, new)| Delta {
+        first: IpAddr::V4(Ipv4Addr::from(first as u32)),
+        last: IpAddr::V4(Ipv4Addr::from(last as u32)),
+        old,
+        new,
+    });
+    let v6 = diff_family(&old_v6, &new_v6).into_iter().map(|(first, last, old, new)| Delta {
+        first: IpAddr::V6(Ipv6Addr::from(first)),
+        last: IpAddr::V6(Ipv6Addr::from(last)),
+        old,
+        new,
+    });
+
+    v4.chain(v6).collect()
+}
+
+
+#[test]
+fn test_diff() {
+    use crate::parse_str;
+
+    let old = parse_str("2|afrinic|20190910|5|00000000|20190910|00000\n\
+                         afrinic|ZA|ipv4|41.0.0.0|1024|20071126|allocated\n\
+                         afrinic|ZA|ipv4|41.0.4.0|1024|20071126|allocated\n\
+                         afrinic|EG|ipv4|41.0.8.0|256|20071126|allocated\n\
+                         afrinic|ZA|ipv6|2001:4200::|32|20051021|allocated\n\
+                         iana|ZZ|ipv4|41.0.0.0|16777216|20050401|afrinic\n");
+    let new = parse_str("2|afrinic|20190911|6|00000000|20190911|00000\n\
+                         afrinic|ZA|ipv4|41.0.0.0|512|20071126|allocated\n\
+                         afrinic|MA|ipv4|41.0.2.0|512|20071126|allocated\n\
+                         afrinic|ZA|ipv4|41.0.4.0|1024|20071126|assigned\n\
+                         ripencc|EG|ipv4|41.0.8.0|256|20071126|allocated\n\
+                         afrinic|ZA|ipv6|2001:4200::|32|20051021|allocated\n\
+                         afrinic|ZA|ipv6|2001:4210::|32|20051021|allocated\n");
+
+    let deltas = diff(&old, &new);
+    let found: Vec<(String, ChangeKind, Registry)> = deltas.iter()
+        .map(|delta| (format!("{} - {}", delta.first, delta.last), delta.kind(), delta.registry()))
+        .collect();
+    assert_eq!(found, vec![
+        ("41.0.2.0 - 41.0.3.255".to_string(), ChangeKind::Country, Registry::Afrinic),
+        ("41.0.4.0 - 41.0.7.255".to_string(), ChangeKind::Status, Registry::Afrinic),
+        ("41.0.8.0 - 41.0.8.255".to_string(), ChangeKind::Reassigned, Registry::Ripencc),
+        ("2001:4210:: - 2001:4210:ffff:ffff:ffff:ffff:ffff:ffff".to_string(), ChangeKind::Added, Registry::Afrinic),
+    ]);
+    assert_eq!(deltas[0].size(), 512);
+    assert_eq!(deltas[2].old.unwrap().registry, Registry::Afrinic);
+
+    assert!(diff(&old, &old).is_empty());
+}
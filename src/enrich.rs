@@ -0,0 +1,215 @@
+//! Annotate log lines with the country, registry and status of the address they contain.
+//!
+//! ```
+//! let lines = vec!["203.0.113.7 - - [11/Sep/2019:10:00:00 +0000] \"GET / HTTP/1.1\" 200 612"];
+//!
+//! for enriched in iana::enrich(lines, iana::Extractor::new().field(1)) {
+//!     let mut output = Vec::new();
+//!     enriched.write_annotated(&mut output, "\t").unwrap();
+//! }
+//! ```
+
+use crate::{lookup, LookupResult};
+
+use std::io;
+use std::str::FromStr;
+use std::net::IpAddr;
+
+
+/// Where to find the address in a line.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct Extractor {
+    field: Option<usize>,
+    delimiter: Option<char>,
+}
+
+impl Extractor {
+    /// Use the first address found anywhere in the line.
+    pub fn new() -> Self {
+        Extractor { field: None, delimiter: None }
+    }
+
+    /// Only search the given field, counted from 1 like `cut` and `awk`.
+    pub fn field(mut self, field: usize) -> Self {
+        assert!(field > 0, "fields are counted from 1");
+        self.field = Some(field);
+        self
+    }
+
+    /// Field delimiter, runs of whitespace when unset.
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    pub fn extract(&self, line: &str) -> Option<IpAddr> {
+        match self.field {
+            None => find_ip(line),
+            Some(field) => {
+                let text = match self.delimiter {
+                    Some(delimiter) => line.split(delimiter).nth(field - 1),
+                    None => line.split_whitespace().nth(field - 1),
+                };
+                text.and_then(find_ip)
+            }
+        }
+    }
+}
+
+
+fn is_ip_char(c: char) -> bool {
+    c.is_ascii_hexdigit() || c == '.' || c == ':'
+}
+
+/// Parse a candidate token, allowing a trailing port (`1.2.3.4:80`) or trailing punctuation.
+fn parse_token(token: &str) -> Option<IpAddr> {
+    // Keep a leading `::`, it is part of addresses like `::1`.
+    let token = if token.starts_with("::") { token } else { token.trim_start_matches(['.', ':']) };
+    if token.len() < 2 {
+        return None;
+    }
+    if let Ok(ip) = IpAddr::from_str(token) {
+        return Some(ip);
+    }
+
+    // The end of a sentence, `... 2001:db8::.` or `... 192.0.2.1:`, only when the token
+    // does not parse as is, a trailing `::` is part of addresses like `2001:db8::`.
+    if let Ok(ip) = IpAddr::from_str(token.trim_end_matches('.')) {
+        return Some(ip);
+    }
+    let token = token.trim_end_matches(['.', ':']);
+    if let Ok(ip) = IpAddr::from_str(token) {
+        return Some(ip);
+    }
+
+    // `1.2.3.4:8080`
+    if let Some(pos) = token.find(':') {
+        if token[..pos].contains('.') && token[pos + 1..].bytes().all(|b| b.is_ascii_digit()) {
+            return IpAddr::from_str(&token[..pos]).ok();
+        }
+    }
+
+    None
+}
+
+/// Find the first IPv4 or IPv6 address in `text`.
+///
+/// Addresses may be surrounded by any character that can not be part of one,
+/// so `[2001:db8::1]:443`, `client=192.0.2.1,` and `"192.0.2.1:8080"` all match.
+pub fn find_ip(text: &str) -> Option<IpAddr> {
+    let mut rest = text;
+    while !rest.is_empty() {
+        let start = rest.find(is_ip_char)?;
+        rest = &rest[start..];
+        let end = rest.find(|c| !is_ip_char(c)).unwrap_or(rest.len());
+
+        let token = &rest[..end];
+        // A candidate needs a separator, this skips plain words and numbers cheaply.
+        if token.contains(['.', ':']) {
+            if let Some(ip) = parse_token(token) {
+                return Some(ip);
+            }
+        }
+        rest = &rest[end..];
+    }
+
+    None
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Enriched<S> {
+    pub line: S,
+    pub ip: Option<IpAddr>,
+    pub result: Option<LookupResult>,
+}
+
+impl<S: AsRef<str>> Enriched<S> {
+    /// Write the line followed by country, registry and status, `-` when unknown.
+    pub fn write_annotated<W: io::Write>(&self, output: &mut W, delimiter: &str) -> io::Result<()> {
+        let line = self.line.as_ref();
+        match self.result {
+            Some(ref result) => {
+                writeln!(output, "{}{}{}{}{}{}{}",
+                         line, delimiter, result.country, delimiter, result.registry, delimiter, result.status)
+            },
+            None => writeln!(output, "{}{}-{}-{}-", line, delimiter, delimiter, delimiter),
+        }
+    }
+}
+
+/// Iterator adaptor returned by `enrich`.
+#[derive(Debug, Clone)]
+pub struct Enrich<I> {
+    lines: I,
+    extractor: Extractor,
+}
+
+impl<I, S> Iterator for Enrich<I>
+    where I: Iterator<Item = S>,
+          S: AsRef<str>
+{
+    type Item = Enriched<S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.lines.next()?;
+        let ip = self.extractor.extract(line.as_ref());
+        let result = ip.as_ref().and_then(lookup);
+
+        Some(Enriched { line, ip, result })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lines.size_hint()
+    }
+}
+
+/// Lookup the address of every line, lazily.
+pub fn enrich<I, S>(lines: I, extractor: Extractor) -> Enrich<I::IntoIter>
+    where I: IntoIterator<Item = S>,
+          S: AsRef<str>
+{
+    Enrich { lines: lines.into_iter(), extractor }
+}
+
+
+#[test]
+fn test_find_ip() {
+    let ip = |s: &str| s.parse::<IpAddr>().unwrap();
+
+    assert_eq!(find_ip("203.0.113.7 - - [11/Sep/2019:10:00:00 +0000] \"GET / HTTP/1.1\" 200"), Some(ip("203.0.113.7")));
+    assert_eq!(find_ip("[11/Sep/2019:10:00:00 +0000] client=192.0.2.1, port 22"), Some(ip("192.0.2.1")));
+    assert_eq!(find_ip("upstream \"192.0.2.1:8080\""), Some(ip("192.0.2.1")));
+    assert_eq!(find_ip("from [2001:db8::1]:443 to 10.0.0.1"), Some(ip("2001:db8::1")));
+    assert_eq!(find_ip("::ffff:192.0.2.1"), Some(ip("::ffff:192.0.2.1")));
+    assert_eq!(find_ip("version 1.2 at 12:30:45, deadbeef"), None);
+    assert_eq!(find_ip("Connection closed by 198.51.100.4."), Some(ip("198.51.100.4")));
+    assert_eq!(find_ip("route 2001:218:: via wg0"), Some(ip("2001:218::")));
+    assert_eq!(find_ip("Blocked 2001:218::."), Some(ip("2001:218::")));
+    assert_eq!(find_ip("peer 2001:db8::1:, retrying"), Some(ip("2001:db8::1")));
+    assert_eq!(find_ip(""), None);
+}
+
+#[test]
+fn test_extractor() {
+    let line = "2019-09-11T10:00:00 10.1.1.1 203.0.113.7 GET /";
+    assert_eq!(Extractor::new().extract(line), Some("10.1.1.1".parse().unwrap()));
+    assert_eq!(Extractor::new().field(3).extract(line), Some("203.0.113.7".parse().unwrap()));
+    assert_eq!(Extractor::new().field(4).extract(line), None);
+    assert_eq!(Extractor::new().field(9).extract(line), None);
+
+    let line = "10.1.1.1,GET,\"2001:db8::7\"";
+    assert_eq!(Extractor::new().delimiter(',').field(3).extract(line), Some("2001:db8::7".parse().unwrap()));
+}
+
+#[test]
+fn test_enrich() {
+    let lines = vec!["no address here", "1.2.3"];
+    let enriched: Vec<Enriched<&str>> = enrich(lines, Extractor::new()).collect();
+    assert_eq!(enriched.len(), 2);
+    assert_eq!(enriched[0].ip, None);
+
+    let mut output = Vec::new();
+    enriched[0].write_annotated(&mut output, "\t").unwrap();
+    assert_eq!(output, b"no address here\t-\t-\t-\n");
+}
@@ -1,56 +1,107 @@
+// The records, their codes and the parser, shared with `build.rs`.
+mod rir;
+mod enrich;
+mod diff;
+mod snapshot;
+mod mmdb;
+#[cfg(feature = "rdap")]
+mod rdap;
+#[cfg(feature = "sync")]
+mod sync;
+#[cfg(feature = "localized-names")]
+mod locale;
+#[cfg(feature = "serde")]
+mod serde_impls;
 
-mod country;
-mod registry;
-mod status;
-mod error;
-
-#[cfg( all(not(feature = "sync"), not(feature = "parse")) )]
-mod v4_db;
-#[cfg( all(not(feature = "sync"), not(feature = "parse")) )]
-mod v6_db;
-
-#[cfg(any(feature = "sync", feature = "parse"))]
+// Generated by `build.rs` from the RIR files in `IANA_DATA_PATH` (default `data/`).
 mod v4_db {
-    pub static IPV4_RECORDS: [(u32, u32, u8); 0] = [];
+    include!(concat!(env!("OUT_DIR"), "/v4_db.rs"));
 }
-#[cfg(any(feature = "sync", feature = "parse"))]
 mod v6_db {
-    pub static IPV6_RECORDS: [(u128, u128, u8); 0] = [];
+    include!(concat!(env!("OUT_DIR"), "/v6_db.rs"));
+}
+#[cfg(feature = "provenance")]
+mod provenance_db {
+    include!(concat!(env!("OUT_DIR"), "/provenance_db.rs"));
 }
 
 
-pub use crate::country::Country;
-pub use crate::registry::Registry;
-pub use crate::status::Status;
-pub use crate::error::Error;
+pub use crate::rir::country::{Country, CountryKind};
+pub use crate::rir::region::{Region, Continent};
+pub use crate::rir::registry::Registry;
+pub use crate::rir::status::Status;
+pub use crate::rir::origin::Origin;
+pub use crate::rir::error::Error;
+pub use crate::rir::rir_files::{IANA_RIR_FILES, IANA_RDAP_FILES};
+pub use crate::rir::cidr::{Ipv4Cidr, Ipv6Cidr};
+pub use crate::rir::ipset::IpSet;
+pub use crate::rir::record::{Record, IpBlock, Provenance, Ipv4Range, Ipv4AddrsIter, Ipv4CidrIter};
+pub use crate::rir::parse::{parse, parse_str};
+pub use crate::enrich::{enrich, find_ip, Enrich, Enriched, Extractor};
+pub use crate::mmdb::MmdbWriter;
+pub use crate::rir::overlay::Overlay;
+pub use crate::diff::{diff, Delta, Delegation, ChangeKind};
+pub use crate::snapshot::{Snapshots, parse_date};
+#[cfg(feature = "rdap")]
+pub use crate::rdap::RdapBootstrap;
+#[cfg(feature = "localized-names")]
+pub use crate::locale::Locale;
+#[cfg(feature = "sync")]
+pub use crate::sync::{Syncer, Source, Progress, Outcome, Report, CancellationToken};
 
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::cmp::Ordering;
 
 
-// Files not exists
-// ("delegated-arin-latest",             "https://ftp.arin.net/pub/stats/arin/delegated-arin-latest"),
-// ("delegated-iana-extended-latest",    "ftp://ftp.apnic.net/public/stats/iana/delegated-iana-extended-latest"),
-pub static IANA_RIR_FILES: [(&str, &str); 10] = [
-    ("delegated-arin-extended-latest",    "https://ftp.arin.net/pub/stats/arin/delegated-arin-extended-latest"),
-    ("delegated-ripencc-latest",          "https://ftp.ripe.net/pub/stats/ripencc/delegated-ripencc-latest"),
-    ("delegated-ripencc-extended-latest", "https://ftp.ripe.net/pub/stats/ripencc/delegated-ripencc-extended-latest"),
-    ("delegated-apnic-latest",            "https://ftp.apnic.net/stats/apnic/delegated-apnic-latest"),
-    ("delegated-apnic-extended-latest",   "https://ftp.apnic.net/stats/apnic/delegated-apnic-extended-latest"),
-    ("delegated-lacnic-latest",           "http://ftp.lacnic.net/pub/stats/lacnic/delegated-lacnic-latest"),
-    ("delegated-lacnic-extended-latest",  "http://ftp.lacnic.net/pub/stats/lacnic/delegated-lacnic-extended-latest"),
-    ("delegated-afrinic-latest",          "https://ftp.afrinic.net/pub/stats/afrinic/delegated-afrinic-latest"),
-    ("delegated-afrinic-extended-latest", "https://ftp.afrinic.net/pub/stats/afrinic/delegated-afrinic-extended-latest"),
-    ("delegated-iana-latest",             "https://ftp.apnic.net/stats/iana/delegated-iana-latest"),
-];
-
-
-pub fn lookup(ip: &IpAddr) -> Option<(IpAddr, IpAddr, Country)> {
-    match ip {
-        &IpAddr::V4(v4_addr) => {
+/// The delegation record an address belongs to.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct LookupResult {
+    pub first: IpAddr,
+    pub last: IpAddr,
+    pub country: Country,
+    pub registry: Registry,
+    pub status: Status,
+    /// Whether the country comes from the RIR data or from an overlay.
+    pub origin: Origin,
+    /// The file and line of the record, always `None` without the `provenance` feature.
+    pub provenance: Option<Provenance>,
+}
+
+/// `(file_index, line)` of the provenance tables, the index is the position in `IANA_RIR_FILES`.
+#[cfg(feature = "provenance")]
+fn table_provenance((file_idx, line): (u8, u32)) -> Option<Provenance> {
+    let &(file, _url) = IANA_RIR_FILES.get(file_idx as usize)?;
+    let (serial, date) = provenance_db::SOURCE_FILES[file_idx as usize];
+
+    Some(Provenance { file: Some(file), serial, date, line })
+}
+
+#[cfg(feature = "provenance")]
+fn ipv4_provenance(pos: usize) -> Option<Provenance> {
+    table_provenance(provenance_db::IPV4_PROVENANCE[pos])
+}
+
+#[cfg(feature = "provenance")]
+fn ipv6_provenance(pos: usize) -> Option<Provenance> {
+    table_provenance(provenance_db::IPV6_PROVENANCE[pos])
+}
+
+#[cfg(not(feature = "provenance"))]
+fn ipv4_provenance(_pos: usize) -> Option<Provenance> {
+    None
+}
+
+#[cfg(not(feature = "provenance"))]
+fn ipv6_provenance(_pos: usize) -> Option<Provenance> {
+    None
+}
+
+pub fn lookup(ip: &IpAddr) -> Option<LookupResult> {
+    match *ip {
+        IpAddr::V4(v4_addr) => {
             let v4_number = u32::from(v4_addr);
-            let ret = v4_db::IPV4_RECORDS.binary_search_by(|&(first, last, _cc)| {
-                if v4_number >= last {
+            let ret = v4_db::IPV4_RECORDS.binary_search_by(|&(first, last, _cc, _rir, _status, _origin)| {
+                if v4_number > last {
                     Ordering::Less
                 } else if v4_number >= first && v4_number <= last {
                     Ordering::Equal
@@ -62,18 +113,24 @@ pub fn lookup(ip: &IpAddr) -> Option<(IpAddr, IpAddr, Country)> {
             });
             match ret {
                 Ok(pos) => {
-                    let (first, last, cc) = v4_db::IPV4_RECORDS[pos];
-                    Some( (IpAddr::from(Ipv4Addr::from(first)),
-                           IpAddr::from(Ipv4Addr::from(last)),
-                           Country::from_index(cc).unwrap() ))
+                    let (first, last, cc, rir, status, origin) = v4_db::IPV4_RECORDS[pos];
+                    Some(LookupResult {
+                        first: IpAddr::from(Ipv4Addr::from(first)),
+                        last: IpAddr::from(Ipv4Addr::from(last)),
+                        country: Country::from_index(cc).unwrap(),
+                        registry: Registry::from_index(rir).unwrap(),
+                        status: Status::from_index(status).unwrap(),
+                        origin: Origin::from_index(origin).unwrap(),
+                        provenance: ipv4_provenance(pos),
+                    })
                 }
                 Err(_) => None
             }
         }
-        &IpAddr::V6(v6_addr) => {
+        IpAddr::V6(v6_addr) => {
             let v6_number = u128::from(v6_addr);
-            let ret = v6_db::IPV6_RECORDS.binary_search_by(|&(first, last, _cc)| {
-                if v6_number >= last {
+            let ret = v6_db::IPV6_RECORDS.binary_search_by(|&(first, last, _cc, _rir, _status, _origin)| {
+                if v6_number > last {
                     Ordering::Less
                 } else if v6_number >= first && v6_number <= last {
                     Ordering::Equal
@@ -86,10 +143,16 @@ pub fn lookup(ip: &IpAddr) -> Option<(IpAddr, IpAddr, Country)> {
 
             match ret {
                 Ok(pos) => {
-                    let (first, last, cc) = v6_db::IPV6_RECORDS[pos];
-                    Some( (IpAddr::from(Ipv6Addr::from(first)),
-                           IpAddr::from(Ipv6Addr::from(last)),
-                           Country::from_index(cc).unwrap() ))
+                    let (first, last, cc, rir, status, origin) = v6_db::IPV6_RECORDS[pos];
+                    Some(LookupResult {
+                        first: IpAddr::from(Ipv6Addr::from(first)),
+                        last: IpAddr::from(Ipv6Addr::from(last)),
+                        country: Country::from_index(cc).unwrap(),
+                        registry: Registry::from_index(rir).unwrap(),
+                        status: Status::from_index(status).unwrap(),
+                        origin: Origin::from_index(origin).unwrap(),
+                        provenance: ipv6_provenance(pos),
+                    })
                 }
                 Err(_) => None
             }
@@ -98,12 +161,86 @@ pub fn lookup(ip: &IpAddr) -> Option<(IpAddr, IpAddr, Country)> {
 }
 
 
+/// The sets of the lookup tables, `IpSet` itself is shared with `build.rs`.
+impl IpSet {
+    /// Addresses delegated to a country, from the lookup tables.
+    pub fn from_country(country: Country) -> Self {
+        let index = country.index();
+        IpSet::from_tables(|cc, _rir| cc == index)
+    }
+
+    /// Addresses delegated to the countries within a UN M49 region, e.g. `Region::WesternAfrica`.
+    pub fn from_region(region: Region) -> Self {
+        IpSet::from_tables(|cc, _rir| Country::from_index(cc).map(|country| country.is_in(region)).unwrap_or(false))
+    }
+
+    /// Addresses delegated to the countries of a continent.
+    pub fn from_continent(continent: Continent) -> Self {
+        IpSet::from_tables(|cc, _rir| Country::from_index(cc).map(|country| country.continent() == Some(continent)).unwrap_or(false))
+    }
+
+    /// Addresses delegated by a registry, from the lookup tables.
+    pub fn from_registry(registry: Registry) -> Self {
+        let index = registry.index();
+        IpSet::from_tables(|_cc, rir| rir == index)
+    }
+
+    fn from_tables<F: Fn(u8, u8) -> bool>(filter: F) -> Self {
+        let v4 = v4_db::IPV4_RECORDS.iter()
+            .filter(|&&(_first, _last, cc, rir, _status, _origin)| filter(cc, rir))
+            .map(|&(first, last, _cc, _rir, _status, _origin)| (first as u128, last as u128))
+            .collect();
+        let v6 = v6_db::IPV6_RECORDS.iter()
+            .filter(|&&(_first, _last, cc, rir, _status, _origin)| filter(cc, rir))
+            .map(|&(first, last, _cc, _rir, _status, _origin)| (first, last))
+            .collect();
+
+        IpSet::from_ranges(v4, v6)
+    }
+}
+
+
 #[test]
+#[cfg_attr(iana_db_incomplete, ignore = "needs every RIR file in IANA_DATA_PATH")]
 fn test_lookup_ipv4() {
-    assert_eq!(lookup(&IpAddr::from(Ipv4Addr::new(8, 8, 8, 8))).is_some(), true);
+    assert!(lookup(&IpAddr::from(Ipv4Addr::new(8, 8, 8, 8))).is_some());
 }
 
 #[test]
+#[cfg_attr(iana_db_incomplete, ignore = "needs every RIR file in IANA_DATA_PATH")]
 fn test_lookup_ipv6() {
-    assert_eq!(lookup(&"2001:218::".parse().unwrap()).is_some(), true);
+    assert!(lookup(&"2001:218::".parse().unwrap()).is_some());
+}
+
+#[test]
+#[cfg(feature = "provenance")]
+fn test_lookup_provenance() {
+    use std::fs;
+    use std::path::Path;
+
+    // Check the generated tables against the files they were built from: the file index,
+    // the header serial and date, and the line of a record holding the address.
+    let data_path = Path::new(option_env!("IANA_DATA_PATH").unwrap_or("data"));
+    let mut checked = 0;
+    for ip in ["1.0.0.1", "41.0.0.1", "200.0.0.1", "2001:4200::1", "2001:200::1"].iter() {
+        let ip: IpAddr = ip.parse().unwrap();
+        let result = match lookup(&ip) {
+            Some(result) if result.origin == Origin::Rir => result,
+            _ => continue,
+        };
+        let provenance = result.provenance.unwrap();
+        let file = provenance.file.unwrap();
+        assert!(IANA_RIR_FILES.iter().any(|&(filename, _url)| filename == file));
+
+        let records = parse_str(&fs::read_to_string(data_path.join(file)).unwrap());
+        let record = records.iter()
+            .find(|record| record.provenance().map(|record_provenance| record_provenance.line) == Some(provenance.line))
+            .unwrap_or_else(|| panic!("{} has no record at line {}", file, provenance.line));
+        assert!(record.ip_block().first() <= ip && ip <= record.ip_block().last(), "{} {}", ip, record);
+        assert_eq!(record.country(), result.country);
+        let header = record.provenance().unwrap();
+        assert_eq!((header.serial, header.date), (provenance.serial, provenance.date));
+        checked += 1;
+    }
+    assert!(checked > 0);
 }
@@ -0,0 +1,331 @@
+// Generated by `iana countries` from `src/country_names.csv`, edit the CSV instead.
+
+
+/// Locales of `Country::localized_name`, as BCP 47 tags.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd)]
+pub enum Locale {
+    Ar,
+    En,
+    Es,
+    Fa,
+    Fr,
+    Ru,
+    ZhHans,
+    ZhHant,
+}
+
+const LOCALES: [Locale; 8] = [
+    Locale::Ar,
+    Locale::En,
+    Locale::Es,
+    Locale::Fa,
+    Locale::Fr,
+    Locale::Ru,
+    Locale::ZhHans,
+    Locale::ZhHant,
+];
+
+
+impl Locale {
+    pub fn from_index(index: u8) -> Result<Self, Error> {
+        match index {
+            0 => Ok(Locale::Ar),
+            1 => Ok(Locale::En),
+            2 => Ok(Locale::Es),
+            3 => Ok(Locale::Fa),
+            4 => Ok(Locale::Fr),
+            5 => Ok(Locale::Ru),
+            6 => Ok(Locale::ZhHans),
+            7 => Ok(Locale::ZhHant),
+            e => Err(Error::ParseLocaleError(format!("Unknow Locale Index ({})", e))),
+        }
+    }
+
+    pub fn index(&self) -> u8 {
+        match *self {
+            Locale::Ar => 0,
+            Locale::En => 1,
+            Locale::Es => 2,
+            Locale::Fa => 3,
+            Locale::Fr => 4,
+            Locale::Ru => 5,
+            Locale::ZhHans => 6,
+            Locale::ZhHant => 7,
+        }
+    }
+
+    /// BCP 47 tag, e.g. `zh-Hant`.
+    pub fn tag(&self) -> &'static str {
+        match *self {
+            Locale::Ar => "ar",
+            Locale::En => "en",
+            Locale::Es => "es",
+            Locale::Fa => "fa",
+            Locale::Fr => "fr",
+            Locale::Ru => "ru",
+            Locale::ZhHans => "zh-Hans",
+            Locale::ZhHant => "zh-Hant",
+        }
+    }
+}
+
+
+impl Country {
+    /// Names in the order of `LOCALES`, empty when CLDR has none.
+    fn localized_names(&self) -> &'static [&'static str; 8] {
+        match *self {
+            Country::AF => &["أفغانستان", "Afghanistan", "Afganistán", "افغانستان", "Afghanistan", "Афганистан", "阿富汗", "阿富汗"],
+            Country::AX => &["جزر آلاند", "Åland Islands", "Islas Aland", "جزایر آلاند", "Îles Åland", "Аландские о-ва", "奥兰群岛", "奧蘭群島"],
+            Country::AL => &["ألبانيا", "Albania", "Albania", "آلبانی", "Albanie", "Албания", "阿尔巴尼亚", "阿爾巴尼亞"],
+            Country::DZ => &["الجزائر", "Algeria", "Argelia", "الجزایر", "Algérie", "Алжир", "阿尔及利亚", "阿爾及利亞"],
+            Country::AS => &["ساموا الأمريكية", "American Samoa", "Samoa Americana", "ساموآی امریکا", "Samoa américaines", "Американское Самоа", "美属萨摩亚", "美屬薩摩亞"],
+            Country::AD => &["أندورا", "Andorra", "Andorra", "آندورا", "Andorre", "Андорра", "安道尔", "安道爾"],
+            Country::AO => &["أنغولا", "Angola", "Angola", "آنگولا", "Angola", "Ангола", "安哥拉", "安哥拉"],
+            Country::AI => &["أنغويلا", "Anguilla", "Anguila", "آنگویلا", "Anguilla", "Ангилья", "安圭拉", "安奎拉"],
+            Country::AQ => &["أنتاركتيكا", "Antarctica", "Antártida", "جنوبگان", "Antarctique", "Антарктида", "南极洲", "南極洲"],
+            Country::AG => &["أنتيغوا وبربودا", "Antigua & Barbuda", "Antigua y Barbuda", "آنتیگوا و باربودا", "Antigua-et-Barbuda", "Антигуа и Барбуда", "安提瓜和巴布达", "安地卡及巴布達"],
+            Country::AR => &["الأرجنتين", "Argentina", "Argentina", "آرژانتین", "Argentine", "Аргентина", "阿根廷", "阿根廷"],
+            Country::AM => &["أرمينيا", "Armenia", "Armenia", "ارمنستان", "Arménie", "Армения", "亚美尼亚", "亞美尼亞"],
+            Country::AW => &["أروبا", "Aruba", "Aruba", "آروبا", "Aruba", "Аруба", "阿鲁巴", "荷屬阿魯巴"],
+            Country::AU => &["أستراليا", "Australia", "Australia", "استرالیا", "Australie", "Австралия", "澳大利亚", "澳洲"],
+            Country::AT => &["النمسا", "Austria", "Austria", "اتریش", "Autriche", "Австрия", "奥地利", "奧地利"],
+            Country::AZ => &["أذربيجان", "Azerbaijan", "Azerbaiyán", "جمهوری آذربایجان", "Azerbaïdjan", "Азербайджан", "阿塞拜疆", "亞塞拜然"],
+            Country::BS => &["جزر البهاما", "Bahamas", "Bahamas", "باهاما", "Bahamas", "Багамы", "巴哈马", "巴哈馬"],
+            Country::BH => &["البحرين", "Bahrain", "Baréin", "بحرین", "Bahreïn", "Бахрейн", "巴林", "巴林"],
+            Country::BD => &["بنغلاديش", "Bangladesh", "Bangladés", "بنگلادش", "Bangladesh", "Бангладеш", "孟加拉国", "孟加拉"],
+            Country::BB => &["بربادوس", "Barbados", "Barbados", "باربادوس", "Barbade", "Барбадос", "巴巴多斯", "巴貝多"],
+            Country::BY => &["بيلاروس", "Belarus", "Bielorrusia", "بلاروس", "Biélorussie", "Беларусь", "白俄罗斯", "白俄羅斯"],
+            Country::BE => &["بلجيكا", "Belgium", "Bélgica", "بلژیک", "Belgique", "Бельгия", "比利时", "比利時"],
+            Country::BZ => &["بليز", "Belize", "Belice", "بلیز", "Belize", "Белиз", "伯利兹", "貝里斯"],
+            Country::BJ => &["بنين", "Benin", "Benín", "بنین", "Bénin", "Бенин", "贝宁", "貝南"],
+            Country::BM => &["برمودا", "Bermuda", "Bermudas", "برمودا", "Bermudes", "Бермудские о-ва", "百慕大", "百慕達"],
+            Country::BT => &["بوتان", "Bhutan", "Bután", "بوتان", "Bhoutan", "Бутан", "不丹", "不丹"],
+            Country::BO => &["بوليفيا", "Bolivia", "Bolivia", "بولیوی", "Bolivie", "Боливия", "玻利维亚", "玻利維亞"],
+            Country::BQ => &["هولندا الكاريبية", "Caribbean Netherlands", "Caribe neerlandés", "جزایر کارائیب هلند", "Pays-Bas caribéens", "Бонэйр, Синт-Эстатиус и Саба", "荷属加勒比区", "荷蘭加勒比區"],
+            Country::BA => &["البوسنة والهرسك", "Bosnia & Herzegovina", "Bosnia y Herzegovina", "بوسنی و هرزگوین", "Bosnie-Herzégovine", "Босния и Герцеговина", "波斯尼亚和黑塞哥维那", "波士尼亞與赫塞哥維納"],
+            Country::BW => &["بوتسوانا", "Botswana", "Botsuana", "بوتسوانا", "Botswana", "Ботсвана", "博茨瓦纳", "波札那"],
+            Country::BV => &["جزيرة بوفيه", "Bouvet Island", "Isla Bouvet", "جزیره\u{654} بووه", "Île Bouvet", "о-в Буве", "布韦岛", "布威島"],
+            Country::BR => &["البرازيل", "Brazil", "Brasil", "برزیل", "Brésil", "Бразилия", "巴西", "巴西"],
+            Country::IO => &["الإقليم البريطاني في المحيط الهندي", "British Indian Ocean Territory", "Territorio Británico del Océano Índico", "قلمرو بریتانیا در اقیانوس هند", "Territoire britannique de l’océan Indien", "Британская территория в Индийском океане", "英属印度洋领地", "英屬印度洋領地"],
+            Country::BN => &["بروناي", "Brunei", "Brunéi", "برونئی", "Brunei", "Бруней-Даруссалам", "文莱", "汶萊"],
+            Country::BG => &["بلغاريا", "Bulgaria", "Bulgaria", "بلغارستان", "Bulgarie", "Болгария", "保加利亚", "保加利亞"],
+            Country::BF => &["بوركينا فاسو", "Burkina Faso", "Burkina Faso", "بورکینافاسو", "Burkina Faso", "Буркина-Фасо", "布基纳法索", "布吉納法索"],
+            Country::BI => &["بوروندي", "Burundi", "Burundi", "بوروندی", "Burundi", "Бурунди", "布隆迪", "蒲隆地"],
+            Country::KH => &["كمبوديا", "Cambodia", "Camboya", "کامبوج", "Cambodge", "Камбоджа", "柬埔寨", "柬埔寨"],
+            Country::CM => &["الكاميرون", "Cameroon", "Camerún", "کامرون", "Cameroun", "Камерун", "喀麦隆", "喀麥隆"],
+            Country::CA => &["كندا", "Canada", "Canadá", "کانادا", "Canada", "Канада", "加拿大", "加拿大"],
+            Country::CV => &["الرأس الأخضر", "Cape Verde", "Cabo Verde", "کیپ\u{200c}ورد", "Cap-Vert", "Кабо-Верде", "佛得角", "維德角"],
+            Country::KY => &["جزر كايمان", "Cayman Islands", "Islas Caimán", "جزایر ک\u{650}یمن", "Îles Caïmans", "Острова Кайман", "开曼群岛", "開曼群島"],
+            Country::CF => &["جمهورية أفريقيا الوسطى", "Central African Republic", "República Centroafricana", "جمهوری افریقای مرکزی", "République centrafricaine", "Центрально-Африканская Республика", "中非共和国", "中非共和國"],
+            Country::TD => &["تشاد", "Chad", "Chad", "چاد", "Tchad", "Чад", "乍得", "查德"],
+            Country::CL => &["تشيلي", "Chile", "Chile", "شیلی", "Chili", "Чили", "智利", "智利"],
+            Country::CN => &["الصين", "China", "China", "چین", "Chine", "Китай", "中国", "中國"],
+            Country::CX => &["جزيرة كريسماس", "Christmas Island", "Isla de Navidad", "جزیره\u{654} کریسمس", "Île Christmas", "о-в Рождества", "圣诞岛", "聖誕島"],
+            Country::CC => &["جزر كوكوس (كيلينغ)", "Cocos (Keeling) Islands", "Islas Cocos", "جزایر کوکوس", "Îles Cocos", "Кокосовые о-ва", "科科斯（基林）群岛", "科克斯（基靈）群島"],
+            Country::CO => &["كولومبيا", "Colombia", "Colombia", "کلمبیا", "Colombie", "Колумбия", "哥伦比亚", "哥倫比亞"],
+            Country::KM => &["جزر القمر", "Comoros", "Comoras", "کومور", "Comores", "Коморы", "科摩罗", "葛摩"],
+            Country::CG => &["الكونغو - برازافيل", "Congo - Brazzaville", "Congo", "کنگو - برازویل", "Congo-Brazzaville", "Конго - Браззавиль", "刚果（布）", "剛果（布拉薩）"],
+            Country::CD => &["الكونغو - كينشاسا", "Congo - Kinshasa", "República Democrática del Congo", "کنگو - کینشاسا", "Congo-Kinshasa", "Конго - Киншаса", "刚果（金）", "剛果（金夏沙）"],
+            Country::CK => &["جزر كوك", "Cook Islands", "Islas Cook", "جزایر کوک", "Îles Cook", "Острова Кука", "库克群岛", "庫克群島"],
+            Country::CR => &["كوستاريكا", "Costa Rica", "Costa Rica", "کاستاریکا", "Costa Rica", "Коста-Рика", "哥斯达黎加", "哥斯大黎加"],
+            Country::CI => &["ساحل العاج", "Côte d’Ivoire", "Côte d’Ivoire", "ساحل عاج", "Côte d’Ivoire", "Кот-д’Ивуар", "科特迪瓦", "象牙海岸"],
+            Country::HR => &["كرواتيا", "Croatia", "Croacia", "کرواسی", "Croatie", "Хорватия", "克罗地亚", "克羅埃西亞"],
+            Country::CU => &["كوبا", "Cuba", "Cuba", "کوبا", "Cuba", "Куба", "古巴", "古巴"],
+            Country::CW => &["كوراساو", "Curaçao", "Curazao", "کوراسائو", "Curaçao", "Кюрасао", "库拉索", "庫拉索"],
+            Country::CY => &["قبرص", "Cyprus", "Chipre", "قبرس", "Chypre", "Кипр", "塞浦路斯", "賽普勒斯"],
+            Country::CZ => &["التشيك", "Czechia", "Chequia", "چک", "Tchéquie", "Чехия", "捷克", "捷克"],
+            Country::DK => &["الدانمرك", "Denmark", "Dinamarca", "دانمارک", "Danemark", "Дания", "丹麦", "丹麥"],
+            Country::DJ => &["جيبوتي", "Djibouti", "Yibuti", "جیبوتی", "Djibouti", "Джибути", "吉布提", "吉布地"],
+            Country::DM => &["دومينيكا", "Dominica", "Dominica", "دومینیکا", "Dominique", "Доминика", "多米尼克", "多米尼克"],
+            Country::DO => &["جمهورية الدومينيكان", "Dominican Republic", "República Dominicana", "جمهوری دومینیکن", "République dominicaine", "Доминиканская Республика", "多米尼加共和国", "多明尼加共和國"],
+            Country::EC => &["الإكوادور", "Ecuador", "Ecuador", "اکوادور", "Équateur", "Эквадор", "厄瓜多尔", "厄瓜多"],
+            Country::EG => &["مصر", "Egypt", "Egipto", "مصر", "Égypte", "Египет", "埃及", "埃及"],
+            Country::SV => &["السلفادور", "El Salvador", "El Salvador", "السالوادور", "Salvador", "Сальвадор", "萨尔瓦多", "薩爾瓦多"],
+            Country::GQ => &["غينيا الاستوائية", "Equatorial Guinea", "Guinea Ecuatorial", "گینه\u{654} استوایی", "Guinée équatoriale", "Экваториальная Гвинея", "赤道几内亚", "赤道幾內亞"],
+            Country::ER => &["إريتريا", "Eritrea", "Eritrea", "اریتره", "Érythrée", "Эритрея", "厄立特里亚", "厄利垂亞"],
+            Country::EE => &["إستونيا", "Estonia", "Estonia", "استونی", "Estonie", "Эстония", "爱沙尼亚", "愛沙尼亞"],
+            Country::ET => &["إثيوبيا", "Ethiopia", "Etiopía", "اتیوپی", "Éthiopie", "Эфиопия", "埃塞俄比亚", "衣索比亞"],
+            Country::FK => &["جزر فوكلاند", "Falkland Islands", "Islas Malvinas", "جزایر فالکلند", "Îles Malouines", "Фолклендские о-ва", "福克兰群岛", "福克蘭群島"],
+            Country::FO => &["جزر فارو", "Faroe Islands", "Islas Feroe", "جزایر فارو", "Îles Féroé", "Фарерские о-ва", "法罗群岛", "法羅群島"],
+            Country::FJ => &["فيجي", "Fiji", "Fiyi", "فیجی", "Fidji", "Фиджи", "斐济", "斐濟"],
+            Country::FI => &["فنلندا", "Finland", "Finlandia", "فنلاند", "Finlande", "Финляндия", "芬兰", "芬蘭"],
+            Country::FR => &["فرنسا", "France", "Francia", "فرانسه", "France", "Франция", "法国", "法國"],
+            Country::GF => &["غويانا الفرنسية", "French Guiana", "Guayana Francesa", "گویان فرانسه", "Guyane française", "Французская Гвиана", "法属圭亚那", "法屬圭亞那"],
+            Country::PF => &["بولينيزيا الفرنسية", "French Polynesia", "Polinesia Francesa", "پلی\u{200c}نزی فرانسه", "Polynésie française", "Французская Полинезия", "法属波利尼西亚", "法屬玻里尼西亞"],
+            Country::TF => &["الأقاليم الجنوبية الفرنسية", "French Southern Territories", "Territorios Australes Franceses", "سرزمین\u{200c}های جنوب فرانسه", "Terres australes françaises", "Французские Южные территории", "法属南部领地", "法屬南部屬地"],
+            Country::GA => &["الغابون", "Gabon", "Gabón", "گابن", "Gabon", "Габон", "加蓬", "加彭"],
+            Country::GM => &["غامبيا", "Gambia", "Gambia", "گامبیا", "Gambie", "Гамбия", "冈比亚", "甘比亞"],
+            Country::GE => &["جورجيا", "Georgia", "Georgia", "گرجستان", "Géorgie", "Грузия", "格鲁吉亚", "喬治亞"],
+            Country::DE => &["ألمانيا", "Germany", "Alemania", "آلمان", "Allemagne", "Германия", "德国", "德國"],
+            Country::GH => &["غانا", "Ghana", "Ghana", "غنا", "Ghana", "Гана", "加纳", "迦納"],
+            Country::GI => &["جبل طارق", "Gibraltar", "Gibraltar", "جبل\u{200c}الطارق", "Gibraltar", "Гибралтар", "直布罗陀", "直布羅陀"],
+            Country::GR => &["اليونان", "Greece", "Grecia", "یونان", "Grèce", "Греция", "希腊", "希臘"],
+            Country::GL => &["غرينلاند", "Greenland", "Groenlandia", "گرینلند", "Groenland", "Гренландия", "格陵兰", "格陵蘭"],
+            Country::GD => &["غرينادا", "Grenada", "Granada", "گرنادا", "Grenade", "Гренада", "格林纳达", "格瑞那達"],
+            Country::GP => &["غوادلوب", "Guadeloupe", "Guadalupe", "گوادلوپ", "Guadeloupe", "Гваделупа", "瓜德罗普", "瓜地洛普"],
+            Country::GU => &["غوام", "Guam", "Guam", "گوام", "Guam", "Гуам", "关岛", "關島"],
+            Country::GT => &["غواتيمالا", "Guatemala", "Guatemala", "گواتمالا", "Guatemala", "Гватемала", "危地马拉", "瓜地馬拉"],
+            Country::GG => &["غيرنزي", "Guernsey", "Guernesey", "گرنزی", "Guernesey", "Гернси", "根西岛", "根息"],
+            Country::GN => &["غينيا", "Guinea", "Guinea", "گینه", "Guinée", "Гвинея", "几内亚", "幾內亞"],
+            Country::GW => &["غينيا بيساو", "Guinea-Bissau", "Guinea-Bisáu", "گینه\u{654} بیسائو", "Guinée-Bissau", "Гвинея-Бисау", "几内亚比绍", "幾內亞比索"],
+            Country::GY => &["غيانا", "Guyana", "Guyana", "گویان", "Guyana", "Гайана", "圭亚那", "蓋亞那"],
+            Country::HT => &["هايتي", "Haiti", "Haití", "هائیتی", "Haïti", "Гаити", "海地", "海地"],
+            Country::HM => &["جزيرة هيرد وجزر ماكدونالد", "Heard & McDonald Islands", "Islas Heard y McDonald", "هرد و جزایر مک\u{200c}دونالد", "Îles Heard-et-MacDonald", "о-ва Херд и Макдональд", "赫德岛和麦克唐纳群岛", "赫德島及麥唐納群島"],
+            Country::VA => &["الفاتيكان", "Vatican City", "Ciudad del Vaticano", "واتیکان", "État de la Cité du Vatican", "Ватикан", "梵蒂冈", "梵蒂岡"],
+            Country::HN => &["هندوراس", "Honduras", "Honduras", "هندوراس", "Honduras", "Гондурас", "洪都拉斯", "宏都拉斯"],
+            Country::HK => &["هونغ كونغ الصينية (منطقة إدارية خاصة)", "Hong Kong SAR China", "RAE de Hong Kong (China)", "هنگ\u{200c}کنگ، منطقه\u{654} ویژه\u{654} اداری چین", "R.A.S. chinoise de Hong Kong", "Гонконг (САР)", "中国香港特别行政区", "中國香港特別行政區"],
+            Country::HU => &["هنغاريا", "Hungary", "Hungría", "مجارستان", "Hongrie", "Венгрия", "匈牙利", "匈牙利"],
+            Country::IS => &["آيسلندا", "Iceland", "Islandia", "ایسلند", "Islande", "Исландия", "冰岛", "冰島"],
+            Country::IN => &["الهند", "India", "India", "هند", "Inde", "Индия", "印度", "印度"],
+            Country::ID => &["إندونيسيا", "Indonesia", "Indonesia", "اندونزی", "Indonésie", "Индонезия", "印度尼西亚", "印尼"],
+            Country::IR => &["إيران", "Iran", "Irán", "ایران", "Iran", "Иран", "伊朗", "伊朗"],
+            Country::IQ => &["العراق", "Iraq", "Irak", "عراق", "Irak", "Ирак", "伊拉克", "伊拉克"],
+            Country::IE => &["أيرلندا", "Ireland", "Irlanda", "ایرلند", "Irlande", "Ирландия", "爱尔兰", "愛爾蘭"],
+            Country::IM => &["جزيرة مان", "Isle of Man", "Isla de Man", "جزیره\u{654} من", "Île de Man", "о-в Мэн", "马恩岛", "曼島"],
+            Country::IL => &["إسرائيل", "Israel", "Israel", "اسرائیل", "Israël", "Израиль", "以色列", "以色列"],
+            Country::IT => &["إيطاليا", "Italy", "Italia", "ایتالیا", "Italie", "Италия", "意大利", "義大利"],
+            Country::JM => &["جامايكا", "Jamaica", "Jamaica", "جامائیکا", "Jamaïque", "Ямайка", "牙买加", "牙買加"],
+            Country::JP => &["اليابان", "Japan", "Japón", "ژاپن", "Japon", "Япония", "日本", "日本"],
+            Country::JE => &["جيرسي", "Jersey", "Jersey", "جرزی", "Jersey", "Джерси", "泽西岛", "澤西島"],
+            Country::JO => &["الأردن", "Jordan", "Jordania", "اردن", "Jordanie", "Иордания", "约旦", "約旦"],
+            Country::KZ => &["كازاخستان", "Kazakhstan", "Kazajistán", "قزاقستان", "Kazakhstan", "Казахстан", "哈萨克斯坦", "哈薩克"],
+            Country::KE => &["كينيا", "Kenya", "Kenia", "کنیا", "Kenya", "Кения", "肯尼亚", "肯亞"],
+            Country::KI => &["كيريباتي", "Kiribati", "Kiribati", "کیریباتی", "Kiribati", "Кирибати", "基里巴斯", "吉里巴斯"],
+            Country::KP => &["كوريا الشمالية", "North Korea", "Corea del Norte", "کره\u{654} شمالی", "Corée du Nord", "КНДР", "朝鲜", "北韓"],
+            Country::KR => &["كوريا الجنوبية", "South Korea", "Corea del Sur", "کره\u{654} جنوبی", "Corée du Sud", "Республика Корея", "韩国", "南韓"],
+            Country::KW => &["الكويت", "Kuwait", "Kuwait", "کویت", "Koweït", "Кувейт", "科威特", "科威特"],
+            Country::KG => &["قيرغيزستان", "Kyrgyzstan", "Kirguistán", "قرقیزستان", "Kirghizstan", "Киргизия", "吉尔吉斯斯坦", "吉爾吉斯"],
+            Country::LA => &["لاوس", "Laos", "Laos", "لائوس", "Laos", "Лаос", "老挝", "寮國"],
+            Country::LV => &["لاتفيا", "Latvia", "Letonia", "لتونی", "Lettonie", "Латвия", "拉脱维亚", "拉脫維亞"],
+            Country::LB => &["لبنان", "Lebanon", "Líbano", "لبنان", "Liban", "Ливан", "黎巴嫩", "黎巴嫩"],
+            Country::LS => &["ليسوتو", "Lesotho", "Lesoto", "لسوتو", "Lesotho", "Лесото", "莱索托", "賴索托"],
+            Country::LR => &["ليبيريا", "Liberia", "Liberia", "لیبریا", "Liberia", "Либерия", "利比里亚", "賴比瑞亞"],
+            Country::LY => &["ليبيا", "Libya", "Libia", "لیبی", "Libye", "Ливия", "利比亚", "利比亞"],
+            Country::LI => &["ليختنشتاين", "Liechtenstein", "Liechtenstein", "لیختن\u{200c}اشتاین", "Liechtenstein", "Лихтенштейн", "列支敦士登", "列支敦斯登"],
+            Country::LT => &["ليتوانيا", "Lithuania", "Lituania", "لیتوانی", "Lituanie", "Литва", "立陶宛", "立陶宛"],
+            Country::LU => &["لوكسمبورغ", "Luxembourg", "Luxemburgo", "لوکزامبورگ", "Luxembourg", "Люксембург", "卢森堡", "盧森堡"],
+            Country::MO => &["منطقة ماكاو الإدارية الخاصة", "Macao SAR China", "RAE de Macao (China)", "ماکائو، منطقه\u{654} ویژه\u{654} اداری چین", "R.A.S. chinoise de Macao", "Макао (САР)", "中国澳门特别行政区", "中國澳門特別行政區"],
+            Country::MK => &["مقدونيا الشمالية", "North Macedonia", "Macedonia del Norte", "مقدونیه\u{654} شمالی", "Macédoine du Nord", "Северная Македония", "北马其顿", "北馬其頓"],
+            Country::MG => &["مدغشقر", "Madagascar", "Madagascar", "ماداگاسکار", "Madagascar", "Мадагаскар", "马达加斯加", "馬達加斯加"],
+            Country::MW => &["ملاوي", "Malawi", "Malaui", "مالاوی", "Malawi", "Малави", "马拉维", "馬拉威"],
+            Country::MY => &["ماليزيا", "Malaysia", "Malasia", "مالزی", "Malaisie", "Малайзия", "马来西亚", "馬來西亞"],
+            Country::MV => &["جزر المالديف", "Maldives", "Maldivas", "مالدیو", "Maldives", "Мальдивы", "马尔代夫", "馬爾地夫"],
+            Country::ML => &["مالي", "Mali", "Mali", "مالی", "Mali", "Мали", "马里", "馬利"],
+            Country::MT => &["مالطا", "Malta", "Malta", "مالت", "Malte", "Мальта", "马耳他", "馬爾他"],
+            Country::MH => &["جزر مارشال", "Marshall Islands", "Islas Marshall", "جزایر مارشال", "Îles Marshall", "Маршалловы Острова", "马绍尔群岛", "馬紹爾群島"],
+            Country::MQ => &["جزر المارتينيك", "Martinique", "Martinica", "مارتینیک", "Martinique", "Мартиника", "马提尼克", "馬丁尼克"],
+            Country::MR => &["موريتانيا", "Mauritania", "Mauritania", "موریتانی", "Mauritanie", "Мавритания", "毛里塔尼亚", "茅利塔尼亞"],
+            Country::MU => &["موريشيوس", "Mauritius", "Mauricio", "موریس", "Maurice", "Маврикий", "毛里求斯", "模里西斯"],
+            Country::YT => &["مايوت", "Mayotte", "Mayotte", "مایوت", "Mayotte", "Майотта", "马约特", "馬約特島"],
+            Country::MX => &["المكسيك", "Mexico", "México", "مکزیک", "Mexique", "Мексика", "墨西哥", "墨西哥"],
+            Country::FM => &["ميكرونيزيا", "Micronesia", "Micronesia", "میکرونزی", "Micronésie", "Федеративные Штаты Микронезии", "密克罗尼西亚", "密克羅尼西亞"],
+            Country::MD => &["مولدوفا", "Moldova", "Moldavia", "مولداوی", "Moldavie", "Молдова", "摩尔多瓦", "摩爾多瓦"],
+            Country::MC => &["موناكو", "Monaco", "Mónaco", "موناکو", "Monaco", "Монако", "摩纳哥", "摩納哥"],
+            Country::MN => &["منغوليا", "Mongolia", "Mongolia", "مغولستان", "Mongolie", "Монголия", "蒙古", "蒙古"],
+            Country::ME => &["الجبل الأسود", "Montenegro", "Montenegro", "مونته\u{200c}نگرو", "Monténégro", "Черногория", "黑山", "蒙特內哥羅"],
+            Country::MS => &["مونتسرات", "Montserrat", "Montserrat", "مونت\u{200c}سرات", "Montserrat", "Монтсеррат", "蒙特塞拉特", "蒙哲臘"],
+            Country::MA => &["المغرب", "Morocco", "Marruecos", "مراکش", "Maroc", "Марокко", "摩洛哥", "摩洛哥"],
+            Country::MZ => &["موزمبيق", "Mozambique", "Mozambique", "موزامبیک", "Mozambique", "Мозамбик", "莫桑比克", "莫三比克"],
+            Country::MM => &["ميانمار (بورما)", "Myanmar (Burma)", "Myanmar (Birmania)", "میانمار (برمه)", "Myanmar (Birmanie)", "Мьянма (Бирма)", "缅甸", "緬甸"],
+            Country::NA => &["ناميبيا", "Namibia", "Namibia", "نامیبیا", "Namibie", "Намибия", "纳米比亚", "納米比亞"],
+            Country::NR => &["ناورو", "Nauru", "Nauru", "نائورو", "Nauru", "Науру", "瑙鲁", "諾魯"],
+            Country::NP => &["نيبال", "Nepal", "Nepal", "نپال", "Népal", "Непал", "尼泊尔", "尼泊爾"],
+            Country::NL => &["هولندا", "Netherlands", "Países Bajos", "هلند", "Pays-Bas", "Нидерланды", "荷兰", "荷蘭"],
+            Country::NC => &["كاليدونيا الجديدة", "New Caledonia", "Nueva Caledonia", "کالدونیای جدید", "Nouvelle-Calédonie", "Новая Каледония", "新喀里多尼亚", "新喀里多尼亞"],
+            Country::NZ => &["نيوزيلندا", "New Zealand", "Nueva Zelanda", "نیوزیلند", "Nouvelle-Zélande", "Новая Зеландия", "新西兰", "紐西蘭"],
+            Country::NI => &["نيكاراغوا", "Nicaragua", "Nicaragua", "نیکاراگوئه", "Nicaragua", "Никарагуа", "尼加拉瓜", "尼加拉瓜"],
+            Country::NE => &["النيجر", "Niger", "Níger", "نیجر", "Niger", "Нигер", "尼日尔", "尼日"],
+            Country::NG => &["نيجيريا", "Nigeria", "Nigeria", "نیجریه", "Nigeria", "Нигерия", "尼日利亚", "奈及利亞"],
+            Country::NU => &["نيوي", "Niue", "Niue", "نیوئه", "Niue", "Ниуэ", "纽埃", "紐埃島"],
+            Country::NF => &["جزيرة نورفولك", "Norfolk Island", "Isla Norfolk", "جزیره\u{654} نورفولک", "Île Norfolk", "о-в Норфолк", "诺福克岛", "諾福克島"],
+            Country::MP => &["جزر ماريانا الشمالية", "Northern Mariana Islands", "Islas Marianas del Norte", "جزایر ماریانای شمالی", "Îles Mariannes du Nord", "Северные Марианские о-ва", "北马里亚纳群岛", "北馬利安納群島"],
+            Country::NO => &["النرويج", "Norway", "Noruega", "نروژ", "Norvège", "Норвегия", "挪威", "挪威"],
+            Country::OM => &["ع\u{64f}مان", "Oman", "Omán", "عمان", "Oman", "Оман", "阿曼", "阿曼"],
+            Country::PK => &["باكستان", "Pakistan", "Pakistán", "پاکستان", "Pakistan", "Пакистан", "巴基斯坦", "巴基斯坦"],
+            Country::PW => &["بالاو", "Palau", "Palaos", "پالائو", "Palaos", "Палау", "帕劳", "帛琉"],
+            Country::PS => &["الأراضي الفلسطينية", "Palestinian Territories", "Territorios Palestinos", "سرزمین\u{200c}های فلسطینی", "Territoires palestiniens", "Палестинские территории", "巴勒斯坦领土", "巴勒斯坦自治區"],
+            Country::PA => &["بنما", "Panama", "Panamá", "پاناما", "Panama", "Панама", "巴拿马", "巴拿馬"],
+            Country::PG => &["بابوا غينيا الجديدة", "Papua New Guinea", "Papúa Nueva Guinea", "پاپوا گینه\u{654} نو", "Papouasie-Nouvelle-Guinée", "Папуа — Новая Гвинея", "巴布亚新几内亚", "巴布亞紐幾內亞"],
+            Country::PY => &["باراغواي", "Paraguay", "Paraguay", "پاراگوئه", "Paraguay", "Парагвай", "巴拉圭", "巴拉圭"],
+            Country::PE => &["بيرو", "Peru", "Perú", "پرو", "Pérou", "Перу", "秘鲁", "秘魯"],
+            Country::PH => &["الفلبين", "Philippines", "Filipinas", "فیلیپین", "Philippines", "Филиппины", "菲律宾", "菲律賓"],
+            Country::PN => &["جزر بيتكيرن", "Pitcairn Islands", "Islas Pitcairn", "جزایر پیت\u{200c}کرن", "Îles Pitcairn", "о-ва Питкэрн", "皮特凯恩群岛", "皮特肯群島"],
+            Country::PL => &["بولندا", "Poland", "Polonia", "لهستان", "Pologne", "Польша", "波兰", "波蘭"],
+            Country::PT => &["البرتغال", "Portugal", "Portugal", "پرتغال", "Portugal", "Португалия", "葡萄牙", "葡萄牙"],
+            Country::PR => &["بورتوريكو", "Puerto Rico", "Puerto Rico", "پورتوریکو", "Porto Rico", "Пуэрто-Рико", "波多黎各", "波多黎各"],
+            Country::QA => &["قطر", "Qatar", "Catar", "قطر", "Qatar", "Катар", "卡塔尔", "卡達"],
+            Country::RE => &["روينيون", "Réunion", "Reunión", "رئونیون", "La Réunion", "Реюньон", "留尼汪", "留尼旺"],
+            Country::RO => &["رومانيا", "Romania", "Rumanía", "رومانی", "Roumanie", "Румыния", "罗马尼亚", "羅馬尼亞"],
+            Country::RU => &["روسيا", "Russia", "Rusia", "روسیه", "Russie", "Россия", "俄罗斯", "俄羅斯"],
+            Country::RW => &["رواندا", "Rwanda", "Ruanda", "رواندا", "Rwanda", "Руанда", "卢旺达", "盧安達"],
+            Country::BL => &["سان بارتليمي", "St. Barthélemy", "San Bartolomé", "سن بارتلمی", "Saint-Barthélemy", "Сен-Бартелеми", "圣巴泰勒米", "聖巴瑟米"],
+            Country::SH => &["سانت هيلينا", "St. Helena", "Santa Elena", "سنت هلن", "Sainte-Hélène", "о-в Св. Елены", "圣赫勒拿", "聖赫勒拿島"],
+            Country::KN => &["سانت كيتس ونيفيس", "St. Kitts & Nevis", "San Cristóbal y Nieves", "سنت کیتس و نویس", "Saint-Christophe-et-Niévès", "Сент-Китс и Невис", "圣基茨和尼维斯", "聖克里斯多福及尼維斯"],
+            Country::LC => &["سانت لوسيا", "St. Lucia", "Santa Lucía", "سنت لوسیا", "Sainte-Lucie", "Сент-Люсия", "圣卢西亚", "聖露西亞"],
+            Country::MF => &["سان مارتن", "St. Martin", "San Martín", "سنت مارتین", "Saint-Martin", "Сен-Мартен", "法属圣马丁", "法屬聖馬丁"],
+            Country::PM => &["سان بيير ومكويلون", "St. Pierre & Miquelon", "San Pedro y Miquelón", "سن پیر و میکلن", "Saint-Pierre-et-Miquelon", "Сен-Пьер и Микелон", "圣皮埃尔和密克隆群岛", "聖皮埃與密克隆群島"],
+            Country::VC => &["سانت فنسنت وجزر غرينادين", "St. Vincent & Grenadines", "San Vicente y las Granadinas", "سنت وینسنت و گرنادین", "Saint-Vincent-et-les Grenadines", "Сент-Винсент и Гренадины", "圣文森特和格林纳丁斯", "聖文森及格瑞那丁"],
+            Country::WS => &["ساموا", "Samoa", "Samoa", "ساموآ", "Samoa", "Самоа", "萨摩亚", "薩摩亞"],
+            Country::SM => &["سان مارينو", "San Marino", "San Marino", "سان\u{200c}مارینو", "Saint-Marin", "Сан-Марино", "圣马力诺", "聖馬利諾"],
+            Country::ST => &["ساو تومي وبرينسيبي", "São Tomé & Príncipe", "Santo Tomé y Príncipe", "سائوتومه و پرینسیپ", "Sao Tomé-et-Principe", "Сан-Томе и Принсипи", "圣多美和普林西比", "聖多美普林西比"],
+            Country::SA => &["المملكة العربية السعودية", "Saudi Arabia", "Arabia Saudí", "عربستان سعودی", "Arabie saoudite", "Саудовская Аравия", "沙特阿拉伯", "沙烏地阿拉伯"],
+            Country::SN => &["السنغال", "Senegal", "Senegal", "سنگال", "Sénégal", "Сенегал", "塞内加尔", "塞內加爾"],
+            Country::RS => &["صربيا", "Serbia", "Serbia", "صربستان", "Serbie", "Сербия", "塞尔维亚", "塞爾維亞"],
+            Country::SC => &["سيشل", "Seychelles", "Seychelles", "سیشل", "Seychelles", "Сейшельские Острова", "塞舌尔", "塞席爾"],
+            Country::SL => &["سيراليون", "Sierra Leone", "Sierra Leona", "سیرالئون", "Sierra Leone", "Сьерра-Леоне", "塞拉利昂", "獅子山"],
+            Country::SG => &["سنغافورة", "Singapore", "Singapur", "سنگاپور", "Singapour", "Сингапур", "新加坡", "新加坡"],
+            Country::SX => &["سانت مارتن", "Sint Maarten", "Sint Maarten", "سنت مارتن", "Saint-Martin (partie néerlandaise)", "Синт-Мартен", "荷属圣马丁", "荷屬聖馬丁"],
+            Country::SK => &["سلوفاكيا", "Slovakia", "Eslovaquia", "اسلواکی", "Slovaquie", "Словакия", "斯洛伐克", "斯洛伐克"],
+            Country::SI => &["سلوفينيا", "Slovenia", "Eslovenia", "اسلوونی", "Slovénie", "Словения", "斯洛文尼亚", "斯洛維尼亞"],
+            Country::SB => &["جزر سليمان", "Solomon Islands", "Islas Salomón", "جزایر سلیمان", "Îles Salomon", "Соломоновы Острова", "所罗门群岛", "索羅門群島"],
+            Country::SO => &["الصومال", "Somalia", "Somalia", "سومالی", "Somalie", "Сомали", "索马里", "索馬利亞"],
+            Country::ZA => &["جنوب أفريقيا", "South Africa", "Sudáfrica", "افریقای جنوبی", "Afrique du Sud", "Южно-Африканская Республика", "南非", "南非"],
+            Country::GS => &["جورجيا الجنوبية وجزر ساندويتش الجنوبية", "South Georgia & South Sandwich Islands", "Islas Georgia del Sur y Sandwich del Sur", "جورجیای جنوبی و جزایر ساندویچ جنوبی", "Géorgie du Sud-et-les Îles Sandwich du Sud", "Южная Георгия и Южные Сандвичевы о-ва", "南乔治亚和南桑威奇群岛", "南喬治亞與南三明治群島"],
+            Country::SS => &["جنوب السودان", "South Sudan", "Sudán del Sur", "سودان جنوبی", "Soudan du Sud", "Южный Судан", "南苏丹", "南蘇丹"],
+            Country::ES => &["إسبانيا", "Spain", "España", "اسپانیا", "Espagne", "Испания", "西班牙", "西班牙"],
+            Country::LK => &["سريلانكا", "Sri Lanka", "Sri Lanka", "سری\u{200c}لانکا", "Sri Lanka", "Шри-Ланка", "斯里兰卡", "斯里蘭卡"],
+            Country::SD => &["السودان", "Sudan", "Sudán", "سودان", "Soudan", "Судан", "苏丹", "蘇丹"],
+            Country::SR => &["سورينام", "Suriname", "Surinam", "سورینام", "Suriname", "Суринам", "苏里南", "蘇利南"],
+            Country::SJ => &["سفالبارد وجان ماين", "Svalbard & Jan Mayen", "Svalbard y Jan Mayen", "سوالبارد و یان ماین", "Svalbard et Jan Mayen", "Шпицберген и Ян-Майен", "斯瓦尔巴和扬马延", "挪威屬斯瓦巴及尖棉"],
+            Country::SZ => &["إسواتيني", "Eswatini", "Esuatini", "اسواتینی", "Eswatini", "Эсватини", "斯威士兰", "史瓦帝尼"],
+            Country::SE => &["السويد", "Sweden", "Suecia", "سوئد", "Suède", "Швеция", "瑞典", "瑞典"],
+            Country::CH => &["سويسرا", "Switzerland", "Suiza", "سوئیس", "Suisse", "Швейцария", "瑞士", "瑞士"],
+            Country::SY => &["سوريا", "Syria", "Siria", "سوریه", "Syrie", "Сирия", "叙利亚", "敘利亞"],
+            Country::TW => &["تايوان", "Taiwan", "Taiwán", "تایوان", "Taïwan", "Тайвань", "台湾", "台灣"],
+            Country::TJ => &["طاجيكستان", "Tajikistan", "Tayikistán", "تاجیکستان", "Tadjikistan", "Таджикистан", "塔吉克斯坦", "塔吉克"],
+            Country::TZ => &["تنزانيا", "Tanzania", "Tanzania", "تانزانیا", "Tanzanie", "Танзания", "坦桑尼亚", "坦尚尼亞"],
+            Country::TH => &["تايلاند", "Thailand", "Tailandia", "تایلند", "Thaïlande", "Таиланд", "泰国", "泰國"],
+            Country::TL => &["تيمور - ليشتي", "Timor-Leste", "Timor-Leste", "تیمور-لسته", "Timor oriental", "Восточный Тимор", "东帝汶", "東帝汶"],
+            Country::TG => &["توغو", "Togo", "Togo", "توگو", "Togo", "Того", "多哥", "多哥"],
+            Country::TK => &["توكيلو", "Tokelau", "Tokelau", "توکلائو", "Tokelau", "Токелау", "托克劳", "托克勞群島"],
+            Country::TO => &["تونغا", "Tonga", "Tonga", "تونگا", "Tonga", "Тонга", "汤加", "東加"],
+            Country::TT => &["ترينيداد وتوباغو", "Trinidad & Tobago", "Trinidad y Tobago", "ترینیداد و توباگو", "Trinité-et-Tobago", "Тринидад и Тобаго", "特立尼达和多巴哥", "千里達及托巴哥"],
+            Country::TN => &["تونس", "Tunisia", "Túnez", "تونس", "Tunisie", "Тунис", "突尼斯", "突尼西亞"],
+            Country::TR => &["تركيا", "Turkey", "Turquía", "ترکیه", "Turquie", "Турция", "土耳其", "土耳其"],
+            Country::TM => &["تركمانستان", "Turkmenistan", "Turkmenistán", "ترکمنستان", "Turkménistan", "Туркменистан", "土库曼斯坦", "土庫曼"],
+            Country::TC => &["جزر توركس وكايكوس", "Turks & Caicos Islands", "Islas Turcas y Caicos", "جزایر تورکس و کایکوس", "Îles Turques-et-Caïques", "о-ва Тёркс и Кайкос", "特克斯和凯科斯群岛", "土克斯及開科斯群島"],
+            Country::TV => &["توفالو", "Tuvalu", "Tuvalu", "تووالو", "Tuvalu", "Тувалу", "图瓦卢", "吐瓦魯"],
+            Country::UG => &["أوغندا", "Uganda", "Uganda", "اوگاندا", "Ouganda", "Уганда", "乌干达", "烏干達"],
+            Country::UA => &["أوكرانيا", "Ukraine", "Ucrania", "اوکراین", "Ukraine", "Украина", "乌克兰", "烏克蘭"],
+            Country::AE => &["الإمارات العربية المتحدة", "United Arab Emirates", "Emiratos Árabes Unidos", "امارات متحده\u{654} عربی", "Émirats arabes unis", "ОАЭ", "阿拉伯联合酋长国", "阿拉伯聯合大公國"],
+            Country::GB => &["المملكة المتحدة", "United Kingdom", "Reino Unido", "بریتانیا", "Royaume-Uni", "Великобритания", "英国", "英國"],
+            Country::US => &["الولايات المتحدة", "United States", "Estados Unidos", "ایالات متحده", "États-Unis", "Соединенные Штаты", "美国", "美國"],
+            Country::UM => &["جزر الولايات المتحدة النائية", "U.S. Outlying Islands", "Islas menores alejadas de EE. UU.", "جزایر دورافتاده\u{654} ایالات متحده", "Îles mineures éloignées des États-Unis", "Внешние малые о-ва (США)", "美国本土外小岛屿", "美國本土外小島嶼"],
+            Country::UY => &["أورغواي", "Uruguay", "Uruguay", "اروگوئه", "Uruguay", "Уругвай", "乌拉圭", "烏拉圭"],
+            Country::UZ => &["أوزبكستان", "Uzbekistan", "Uzbekistán", "ازبکستان", "Ouzbékistan", "Узбекистан", "乌兹别克斯坦", "烏茲別克"],
+            Country::VU => &["فانواتو", "Vanuatu", "Vanuatu", "وانواتو", "Vanuatu", "Вануату", "瓦努阿图", "萬那杜"],
+            Country::VE => &["فنزويلا", "Venezuela", "Venezuela", "ونزوئلا", "Venezuela", "Венесуэла", "委内瑞拉", "委內瑞拉"],
+            Country::VN => &["فيتنام", "Vietnam", "Vietnam", "ویتنام", "Viêt Nam", "Вьетнам", "越南", "越南"],
+            Country::VG => &["جزر فيرجن البريطانية", "British Virgin Islands", "Islas Vírgenes Británicas", "جزایر ویرجین بریتانیا", "Îles Vierges britanniques", "Виргинские о-ва (Великобритания)", "英属维尔京群岛", "英屬維京群島"],
+            Country::VI => &["جزر فيرجن التابعة للولايات المتحدة", "U.S. Virgin Islands", "Islas Vírgenes de EE. UU.", "جزایر ویرجین ایالات متحده", "Îles Vierges des États-Unis", "Виргинские о-ва (США)", "美属维尔京群岛", "美屬維京群島"],
+            Country::WF => &["جزر والس وفوتونا", "Wallis & Futuna", "Wallis y Futuna", "والیس و فوتونا", "Wallis-et-Futuna", "Уоллис и Футуна", "瓦利斯和富图纳", "瓦利斯群島和富圖那群島"],
+            Country::EH => &["الصحراء الغربية", "Western Sahara", "Sáhara Occidental", "صحرای غربی", "Sahara occidental", "Западная Сахара", "西撒哈拉", "西撒哈拉"],
+            Country::YE => &["اليمن", "Yemen", "Yemen", "یمن", "Yémen", "Йемен", "也门", "葉門"],
+            Country::ZM => &["زامبيا", "Zambia", "Zambia", "زامبیا", "Zambie", "Замбия", "赞比亚", "尚比亞"],
+            Country::ZW => &["زيمبابوي", "Zimbabwe", "Zimbabue", "زیمبابوه", "Zimbabwe", "Зимбабве", "津巴布韦", "辛巴威"],
+            Country::EU => &["الاتحاد الأوروبي", "European Union", "Unión Europea", "اتحادیه\u{654} اروپا", "Union européenne", "Европейский союз", "欧盟", "歐盟"],
+            Country::ZZ => &["منطقة غير معروفة", "Unknown Region", "Región desconocida", "ناحیه\u{654} نامشخص", "région indéterminée", "неизвестный регион", "未知地区", "未知區域"],
+            Country::AP => &["", "", "", "", "", "", "", ""],
+        }
+    }
+}
@@ -0,0 +1,340 @@
+//! Write records as a MaxMind DB file.
+//!
+//! The layout follows GeoLite2-Country, so nginx, HAProxy and Suricata can read it:
+//!
+//! ```text
+//! {
+//!     "country":            { "iso_code": "ZA", "names": { "en": "South Africa" } },
+//!     "registered_country": { "iso_code": "ZA", "names": { "en": "South Africa" } },
+//!     "registry": "afrinic",
+//!     "status": "allocated"
+//! }
+//! ```
+//!
+//! `country` and `registered_country` are left out for `ZZ`. IPv4 networks live under `::/96`,
+//! with `::ffff:0:0/96` pointing at the same subtree.
+//!
+//! Format: `https://maxmind.github.io/MaxMind-DB/`
+
+use crate::rir::country::{Country, CountryKind};
+use crate::rir::registry::Registry;
+use crate::rir::status::Status;
+use crate::rir::record::{Record, IpBlock};
+
+use std::io::{self, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::collections::HashMap;
+
+
+const METADATA_MARKER: &[u8] = b"\xAB\xCD\xEFMaxMind.com";
+const DATA_SECTION_SEPARATOR: usize = 16;
+
+const TYPE_STRING: u8 = 2;
+const TYPE_UINT16: u8 = 5;
+const TYPE_UINT32: u8 = 6;
+const TYPE_MAP: u8 = 7;
+const TYPE_UINT64: u8 = 9;
+const TYPE_ARRAY: u8 = 11;
+
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Link {
+    Empty,
+    Node(usize),
+    /// Offset in the data section.
+    Data(usize),
+}
+
+/// Binary search tree over 128 bit keys, IPv4 addresses are keyed as `::a.b.c.d`.
+#[derive(Debug, Clone)]
+struct Tree {
+    nodes: Vec<[Link; 2]>,
+}
+
+impl Tree {
+    fn new() -> Self {
+        Tree { nodes: vec![[Link::Empty, Link::Empty]] }
+    }
+
+    /// The node reached after `depth` bits of `key`, creating the path when needed.
+    fn node_at(&mut self, key: u128, depth: u8) -> usize {
+        let mut node = 0;
+        for i in 0..depth {
+            let bit = ((key >> (127 - i)) & 1) as usize;
+            node = match self.nodes[node][bit] {
+                Link::Node(next) => next,
+                link => {
+                    // Split a larger network, both halves keep its data.
+                    let next = self.nodes.len();
+                    self.nodes.push([link, link]);
+                    self.nodes[node][bit] = Link::Node(next);
+                    next
+                }
+            };
+        }
+        node
+    }
+
+    fn insert(&mut self, key: u128, prefix_len: u8, link: Link) {
+        assert!(prefix_len > 0 && prefix_len <= 128);
+
+        let node = self.node_at(key, prefix_len - 1);
+        let bit = ((key >> (128 - prefix_len)) & 1) as usize;
+        self.nodes[node][bit] = link;
+    }
+
+    fn record_value(&self, link: Link) -> usize {
+        match link {
+            Link::Empty => self.nodes.len(),
+            Link::Node(node) => node,
+            Link::Data(offset) => self.nodes.len() + DATA_SECTION_SEPARATOR + offset,
+        }
+    }
+}
+
+
+fn write_control(buf: &mut Vec<u8>, type_: u8, size: usize) {
+    let (size_bits, extra): (u8, Vec<u8>) = if size < 29 {
+        (size as u8, vec![])
+    } else if size < 29 + 256 {
+        (29, vec![(size - 29) as u8])
+    } else if size < 285 + 65536 {
+        (30, ((size - 285) as u16).to_be_bytes().to_vec())
+    } else {
+        (31, ((size - 65821) as u32).to_be_bytes()[1..].to_vec())
+    };
+
+    if type_ <= 7 {
+        buf.push(type_ << 5 | size_bits);
+    } else {
+        buf.push(size_bits);
+        buf.push(type_ - 7);
+    }
+    buf.extend_from_slice(&extra);
+}
+
+fn write_string(buf: &mut Vec<u8>, s: &str) {
+    write_control(buf, TYPE_STRING, s.len());
+    buf.extend_from_slice(s.as_bytes());
+}
+
+fn write_uint(buf: &mut Vec<u8>, type_: u8, n: u64) {
+    let bytes = n.to_be_bytes();
+    let skip = bytes.iter().take_while(|&&b| b == 0).count();
+    write_control(buf, type_, bytes.len() - skip);
+    buf.extend_from_slice(&bytes[skip..]);
+}
+
+fn write_country(buf: &mut Vec<u8>, country: Country) {
+    write_control(buf, TYPE_MAP, 2);
+    write_string(buf, "iso_code");
+    write_string(buf, &country.to_string());
+    write_string(buf, "names");
+    write_control(buf, TYPE_MAP, 1);
+    write_string(buf, "en");
+    write_string(buf, country.name());
+}
+
+fn write_record_data(buf: &mut Vec<u8>, country: Country, registry: Registry, status: Status) {
+    match country.kind() {
+        CountryKind::Iso => {
+            write_control(buf, TYPE_MAP, 4);
+            write_string(buf, "country");
+            write_country(buf, country);
+            write_string(buf, "registered_country");
+            write_country(buf, country);
+        },
+        // Not a country, readers expecting ISO codes must not see `AP` or `EU` there.
+        CountryKind::Region => {
+            write_control(buf, TYPE_MAP, 3);
+            write_string(buf, "region");
+            write_country(buf, country);
+        },
+        CountryKind::Unspecified => write_control(buf, TYPE_MAP, 2),
+    }
+    write_string(buf, "registry");
+    write_string(buf, &registry.to_string());
+    write_string(buf, "status");
+    write_string(buf, &status.to_string());
+}
+
+
+/// Writes a GeoLite2-Country compatible `.mmdb` file.
+///
+/// ```
+/// let records = iana::parse_str("afrinic|ZA|ipv4|41.0.0.0|2097152|20071126|allocated\n");
+///
+/// let mut output = Vec::new();
+/// iana::MmdbWriter::new().write(&mut output, &records).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct MmdbWriter {
+    database_type: String,
+    description: String,
+    build_epoch: Option<u64>,
+}
+
+impl Default for MmdbWriter {
+    fn default() -> Self {
+        MmdbWriter::new()
+    }
+}
+
+impl MmdbWriter {
+    pub fn new() -> Self {
+        MmdbWriter {
+            database_type: "GeoLite2-Country".to_string(),
+            description: "IANA and RIR delegation data".to_string(),
+            build_epoch: None,
+        }
+    }
+
+    /// Some readers (Suricata) refuse anything but `GeoLite2-Country` or `GeoIP2-Country`.
+    pub fn database_type(mut self, database_type: &str) -> Self {
+        self.database_type = database_type.to_string();
+        self
+    }
+
+    /// English description stored in the metadata.
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    /// Build time in seconds since the epoch, defaults to now. Set it for reproducible files.
+    pub fn build_epoch(mut self, build_epoch: u64) -> Self {
+        self.build_epoch = Some(build_epoch);
+        self
+    }
+
+    pub fn write<'a, W, I>(&self, output: &mut W, records: I) -> io::Result<()>
+        where W: Write + ?Sized,
+              I: IntoIterator<Item = &'a Record>
+    {
+        let mut data = Vec::new();
+        let mut offsets: HashMap<(Country, Registry, Status), usize> = HashMap::new();
+        let mut networks: Vec<(u128, u8, Link)> = Vec::new();
+
+        for record in records {
+            let key = (record.country(), record.src_registry(), record.status());
+            let offset = *offsets.entry(key).or_insert_with(|| {
+                let offset = data.len();
+                write_record_data(&mut data, key.0, key.1, key.2);
+                offset
+            });
+            let link = Link::Data(offset);
+
+            match record.ip_block() {
+                IpBlock::Ipv4Range(range) => {
+                    for cidr in range.cidrs() {
+                        networks.push((u32::from(cidr.address()) as u128, 96 + cidr.prefix_len(), link));
+                    }
+                },
+                IpBlock::Ipv4Cidr(cidr) => {
+                    networks.push((u32::from(cidr.address()) as u128, 96 + cidr.prefix_len(), link));
+                },
+                IpBlock::Ipv6Cidr(cidr) => {
+                    networks.push((u128::from(cidr.address()), cidr.prefix_len(), link));
+                },
+            }
+        }
+
+        // Larger networks first, so a more specific one splits them instead of being overwritten.
+        networks.sort_by_key(|&(key, prefix_len, _)| (prefix_len, key));
+
+        let mut tree = Tree::new();
+        for (key, prefix_len, link) in networks {
+            if prefix_len > 0 {
+                tree.insert(key, prefix_len, link);
+            }
+        }
+
+        // IPv4-mapped addresses (`::ffff:a.b.c.d`) share the IPv4 subtree.
+        let ipv4_node = tree.node_at(0, 96);
+        tree.insert(0xffff_0000_0000, 96, Link::Node(ipv4_node));
+
+        let max_value = tree.nodes.len() + DATA_SECTION_SEPARATOR + data.len();
+        let record_size: u16 = if max_value < 1 << 24 {
+            24
+        } else if max_value < 1 << 28 {
+            28
+        } else if max_value <= u32::MAX as usize {
+            32
+        } else {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "Too many records for a MaxMind DB"));
+        };
+
+        let mut search_tree = Vec::with_capacity(tree.nodes.len() * record_size as usize / 4);
+        for node in tree.nodes.iter() {
+            let left = tree.record_value(node[0]) as u32;
+            let right = tree.record_value(node[1]) as u32;
+            match record_size {
+                24 => {
+                    search_tree.extend_from_slice(&left.to_be_bytes()[1..]);
+                    search_tree.extend_from_slice(&right.to_be_bytes()[1..]);
+                },
+                28 => {
+                    search_tree.extend_from_slice(&left.to_be_bytes()[1..]);
+                    search_tree.push(((left >> 24) as u8) << 4 | (right >> 24) as u8);
+                    search_tree.extend_from_slice(&right.to_be_bytes()[1..]);
+                },
+                _ => {
+                    search_tree.extend_from_slice(&left.to_be_bytes());
+                    search_tree.extend_from_slice(&right.to_be_bytes());
+                },
+            }
+        }
+
+        let build_epoch = self.build_epoch.unwrap_or_else(|| {
+            SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+        });
+
+        let mut metadata = Vec::new();
+        write_control(&mut metadata, TYPE_MAP, 9);
+        write_string(&mut metadata, "binary_format_major_version");
+        write_uint(&mut metadata, TYPE_UINT16, 2);
+        write_string(&mut metadata, "binary_format_minor_version");
+        write_uint(&mut metadata, TYPE_UINT16, 0);
+        write_string(&mut metadata, "build_epoch");
+        write_uint(&mut metadata, TYPE_UINT64, build_epoch);
+        write_string(&mut metadata, "database_type");
+        write_string(&mut metadata, &self.database_type);
+        write_string(&mut metadata, "description");
+        write_control(&mut metadata, TYPE_MAP, 1);
+        write_string(&mut metadata, "en");
+        write_string(&mut metadata, &self.description);
+        write_string(&mut metadata, "ip_version");
+        write_uint(&mut metadata, TYPE_UINT16, 6);
+        write_string(&mut metadata, "languages");
+        write_control(&mut metadata, TYPE_ARRAY, 1);
+        write_string(&mut metadata, "en");
+        write_string(&mut metadata, "node_count");
+        write_uint(&mut metadata, TYPE_UINT32, tree.nodes.len() as u64);
+        write_string(&mut metadata, "record_size");
+        write_uint(&mut metadata, TYPE_UINT16, record_size as u64);
+
+        output.write_all(&search_tree)?;
+        output.write_all(&[0u8; DATA_SECTION_SEPARATOR])?;
+        output.write_all(&data)?;
+        output.write_all(METADATA_MARKER)?;
+        output.write_all(&metadata)?;
+
+        Ok(())
+    }
+}
+
+
+#[test]
+fn test_write_control() {
+    let mut buf = Vec::new();
+    write_string(&mut buf, "en");
+    assert_eq!(buf, b"\x42en");
+
+    let mut buf = Vec::new();
+    write_control(&mut buf, TYPE_STRING, 300);
+    assert_eq!(buf, [0x5e, 0x00, 15]);
+
+    let mut buf = Vec::new();
+    write_uint(&mut buf, TYPE_UINT64, 0x0102);
+    assert_eq!(buf, [0x02, 0x02, 0x01, 0x02]);
+}
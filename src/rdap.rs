@@ -0,0 +1,223 @@
+//! The RDAP servers of addresses and AS numbers, from the IANA bootstrap files (RFC 9224).
+//!
+//! `iana sync` stores `IANA_RDAP_FILES` next to the delegation stats, so the server of an
+//! abuse query is known without asking IANA first:
+//!
+//! ```
+//! let mut bootstrap = iana::RdapBootstrap::new();
+//! bootstrap.parse_json(r#"{"version": "1.0", "services": [
+//!     [["1.0.0.0/8", "27.0.0.0/8"], ["https://rdap.apnic.net/"]]
+//! ]}"#).unwrap();
+//!
+//! let ip = "1.1.1.1".parse().unwrap();
+//! assert_eq!(bootstrap.rdap_base_for(&ip), Some("https://rdap.apnic.net/"));
+//! assert_eq!(bootstrap.rdap_url_for(&ip).unwrap(), "https://rdap.apnic.net/ip/1.1.1.1");
+//! ```
+
+use log::debug;
+use serde::Deserialize;
+
+use crate::rir::error::Error;
+use crate::rir::cidr::{Ipv4Cidr, Ipv6Cidr};
+use crate::rir::rir_files::IANA_RDAP_FILES;
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use std::net::IpAddr;
+
+
+/// A bootstrap file, the members used here.
+#[derive(Debug, Deserialize)]
+struct BootstrapFile {
+    publication: Option<String>,
+    /// `[entries, urls]` pairs.
+    services: Vec<(Vec<String>, Vec<String>)>,
+}
+
+
+/// A range of a bootstrap file, the longest prefix wins where they overlap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry {
+    first: u128,
+    last: u128,
+    prefix_len: u8,
+    service: usize,
+}
+
+/// Ranges of addresses and AS numbers, and the RDAP base URL of their registry.
+#[derive(Debug, Clone, Default)]
+pub struct RdapBootstrap {
+    /// One base URL per service, `https` preferred, ending with `/`.
+    services: Vec<String>,
+    v4: Vec<Entry>,
+    v6: Vec<Entry>,
+    asn: Vec<Entry>,
+    publication: Vec<String>,
+}
+
+impl RdapBootstrap {
+    pub fn new() -> Self {
+        RdapBootstrap::default()
+    }
+
+    /// Read the `IANA_RDAP_FILES` found in `data_path`, missing files are skipped.
+    pub fn open<P: AsRef<Path>>(data_path: P) -> Result<Self, Error> {
+        let mut bootstrap = RdapBootstrap::new();
+        for &(filename, _url) in IANA_RDAP_FILES.iter() {
+            let filepath = data_path.as_ref().join(filename);
+            let content = match fs::read_to_string(&filepath) {
+                Ok(content) => content,
+                Err(ref e) if e.kind() == io::ErrorKind::NotFound => {
+                    debug!("Skip {:?}, not synced", filepath);
+                    continue;
+                },
+                Err(e) => return Err(Error::IoError(format!("{:?}: {}", filepath, e))),
+            };
+            bootstrap.parse_json(&content)
+                .map_err(|e| match e {
+                    Error::ParseRdapError(e) => Error::ParseRdapError(format!("{:?}: {}", filepath, e)),
+                    e => e,
+                })?;
+        }
+        Ok(bootstrap)
+    }
+
+    /// Add the services of a bootstrap file: `ipv4.json`, `ipv6.json` or `asn.json`,
+    /// told apart by their entries.
+    pub fn parse_json(&mut self, content: &str) -> Result<(), Error> {
+        let invalid = |descp: &str| Error::ParseRdapError(descp.to_string());
+        let file: BootstrapFile = serde_json::from_str(content).map_err(|e| invalid(&e.to_string()))?;
+
+        self.publication.extend(file.publication);
+        for (entries, urls) in file.services.iter() {
+            let url = urls.iter().find(|url| url.starts_with("https://")).or_else(|| urls.first())
+                .ok_or_else(|| invalid("Service without URL"))?;
+            let service_idx = self.services.len();
+            self.services.push(if url.ends_with('/') { url.to_string() } else { format!("{}/", url) });
+
+            for entry in entries.iter() {
+                let invalid_entry = || invalid(&format!("Invalid entry ({})", entry));
+                if entry.contains(':') {
+                    let cidr = Ipv6Cidr::from_str(entry).map_err(|_| invalid_entry())?;
+                    self.v6.push(Entry { first: u128::from(cidr.first()), last: u128::from(cidr.last()),
+                                         prefix_len: cidr.prefix_len(), service: service_idx });
+                } else if entry.contains('.') {
+                    let cidr = Ipv4Cidr::from_str(entry).map_err(|_| invalid_entry())?;
+                    self.v4.push(Entry { first: u32::from(cidr.first()) as u128, last: u32::from(cidr.last()) as u128,
+                                         prefix_len: cidr.prefix_len(), service: service_idx });
+                } else {
+                    // `64512-65534` or a single number.
+                    let mut bounds = entry.splitn(2, '-').map(|bound| bound.trim().parse::<u32>());
+                    let first = bounds.next().and_then(|first| first.ok()).ok_or_else(invalid_entry)?;
+                    let last = match bounds.next() {
+                        Some(last) => last.map_err(|_| invalid_entry())?,
+                        None => first,
+                    };
+                    if last < first {
+                        return Err(invalid_entry());
+                    }
+                    self.asn.push(Entry { first: first as u128, last: last as u128, prefix_len: 0, service: service_idx });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.v4.is_empty() && self.v6.is_empty() && self.asn.is_empty()
+    }
+
+    /// The `publication` dates of the parsed files, e.g. `2024-01-16T19:00:02Z`.
+    pub fn publication(&self) -> &[String] {
+        &self.publication
+    }
+
+    fn find(&self, entries: &[Entry], number: u128) -> Option<&str> {
+        entries.iter()
+            .filter(|entry| entry.first <= number && number <= entry.last)
+            .fold(None, |best: Option<&Entry>, entry| match best {
+                Some(best) if best.prefix_len >= entry.prefix_len => Some(best),
+                _ => Some(entry),
+            })
+            .map(|entry| self.services[entry.service].as_str())
+    }
+
+    /// RDAP base URL of the registry holding `ip`, ending with `/`.
+    pub fn rdap_base_for(&self, ip: &IpAddr) -> Option<&str> {
+        match *ip {
+            IpAddr::V4(v4_addr) => self.find(&self.v4, u32::from(v4_addr) as u128),
+            IpAddr::V6(v6_addr) => self.find(&self.v6, u128::from(v6_addr)),
+        }
+    }
+
+    /// RDAP base URL of the registry holding AS `asn`, ending with `/`.
+    pub fn rdap_base_for_asn(&self, asn: u32) -> Option<&str> {
+        self.find(&self.asn, asn as u128)
+    }
+
+    /// The RDAP query of `ip`, e.g. `https://rdap.arin.net/registry/ip/8.8.8.8`.
+    pub fn rdap_url_for(&self, ip: &IpAddr) -> Option<String> {
+        self.rdap_base_for(ip).map(|base| format!("{}ip/{}", base, ip))
+    }
+
+    /// The RDAP query of AS `asn`, e.g. `https://rdap.arin.net/registry/autnum/15169`.
+    pub fn rdap_url_for_asn(&self, asn: u32) -> Option<String> {
+        self.rdap_base_for_asn(asn).map(|base| format!("{}autnum/{}", base, asn))
+    }
+}
+
+
+#[test]
+fn test_rdap_bootstrap() {
+    let mut bootstrap = RdapBootstrap::new();
+    bootstrap.parse_json(r#"{
+      "description": "RDAP bootstrap file for IPv4 address allocations",
+      "publication": "2024-01-16T19:00:02Z",
+      "services": [
+        [["41.0.0.0/8", "102.0.0.0/8"], ["https://rdap.afrinic.net/rdap/", "http://rdap.afrinic.net/rdap/"]],
+        [["8.0.0.0/8"], ["http://rdap.arin.net/registry", "https://rdap.arin.net/registry"]],
+        [["8.8.0.0/16"], ["https://rdap.example.net/"]]
+      ],
+      "version": "1.0"
+    }"#).unwrap();
+    bootstrap.parse_json(r#"{"version": "1.0", "services": [
+        [["2001:4200::/23", "2c00::/12"], ["https://rdap.afrinic.net/rdap/"]]
+    ]}"#).unwrap();
+    bootstrap.parse_json(r#"{"version": "1.0", "services": [
+        [["36864-37887", "327680-328703"], ["https://rdap.afrinic.net/rdap/"]],
+        [["15169"], ["https://rdap.arin.net/registry/"]]
+    ]}"#).unwrap();
+
+    let ip: IpAddr = "41.0.0.1".parse().unwrap();
+    assert_eq!(bootstrap.rdap_base_for(&ip), Some("https://rdap.afrinic.net/rdap/"));
+    assert_eq!(bootstrap.rdap_url_for(&ip).unwrap(), "https://rdap.afrinic.net/rdap/ip/41.0.0.1");
+    assert_eq!(bootstrap.rdap_base_for(&"8.8.8.8".parse().unwrap()), Some("https://rdap.example.net/"));
+    assert_eq!(bootstrap.rdap_base_for(&"8.9.0.0".parse().unwrap()), Some("https://rdap.arin.net/registry/"));
+    assert_eq!(bootstrap.rdap_base_for(&"9.0.0.0".parse().unwrap()), None);
+    assert_eq!(bootstrap.rdap_base_for(&"2001:4200::1".parse().unwrap()), Some("https://rdap.afrinic.net/rdap/"));
+    assert_eq!(bootstrap.rdap_base_for(&"2001:db8::1".parse().unwrap()), None);
+
+    assert_eq!(bootstrap.rdap_base_for_asn(37000), Some("https://rdap.afrinic.net/rdap/"));
+    assert_eq!(bootstrap.rdap_url_for_asn(15169).unwrap(), "https://rdap.arin.net/registry/autnum/15169");
+    assert_eq!(bootstrap.rdap_base_for_asn(15170), None);
+    assert_eq!(bootstrap.publication(), ["2024-01-16T19:00:02Z".to_string()]);
+
+    assert!(bootstrap.parse_json(r#"{"version": "1.0"}"#).is_err());
+    assert!(bootstrap.parse_json(r#"{"services": [[["1.2.3.4/33"], ["https://x/"]]]}"#).is_err());
+    assert!(bootstrap.parse_json(r#"{"services": [[["200-100"], ["https://x/"]]]}"#).is_err());
+}
+
+#[test]
+fn test_open() {
+    let data_path = tempfile::tempdir().unwrap();
+    assert!(RdapBootstrap::open(data_path.path()).unwrap().is_empty());
+
+    fs::write(data_path.path().join("asn.json"), r#"{"services": [[["1-10"], ["https://rdap.example.net"]]]}"#).unwrap();
+    let bootstrap = RdapBootstrap::open(data_path.path()).unwrap();
+    assert_eq!(bootstrap.rdap_base_for_asn(5), Some("https://rdap.example.net/"));
+
+    fs::write(data_path.path().join("ipv4.json"), "{").unwrap();
+    assert!(matches!(RdapBootstrap::open(data_path.path()), Err(Error::ParseRdapError(_))));
+}
@@ -0,0 +1,146 @@
+use std::fmt;
+use std::str::FromStr;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::rir::error::Error;
+
+
+/// IPv4 network in CIDR notation, host bits are cleared on construction.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Ipv4Cidr {
+    address: Ipv4Addr,
+    prefix_len: u8,
+}
+
+impl Ipv4Cidr {
+    pub fn new(address: Ipv4Addr, prefix_len: u8) -> Self {
+        assert!(prefix_len <= 32);
+
+        let mask = if prefix_len == 0 { 0 } else { u32::MAX << (32 - prefix_len) };
+        Ipv4Cidr { address: Ipv4Addr::from(u32::from(address) & mask), prefix_len }
+    }
+
+    pub fn address(&self) -> Ipv4Addr {
+        self.address
+    }
+
+    pub fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    pub fn first(&self) -> Ipv4Addr {
+        self.address
+    }
+
+    pub fn last(&self) -> Ipv4Addr {
+        let hostmask = if self.prefix_len == 0 { u32::MAX } else { (1u32 << (32 - self.prefix_len)) - 1 };
+        Ipv4Addr::from(u32::from(self.address) | hostmask)
+    }
+
+    pub fn contains(&self, addr: &Ipv4Addr) -> bool {
+        self.first() <= *addr && *addr <= self.last()
+    }
+}
+
+impl fmt::Display for Ipv4Cidr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.address, self.prefix_len)
+    }
+}
+
+impl FromStr for Ipv4Cidr {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (address, prefix_len) = split_cidr(s, 32)?;
+        let address = address.parse::<Ipv4Addr>()
+            .map_err(|_| Error::ParseCidrError(format!("Invalid IPv4 CIDR ({})", s)))?;
+        Ok(Ipv4Cidr::new(address, prefix_len))
+    }
+}
+
+
+/// IPv6 network in CIDR notation, host bits are cleared on construction.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Ipv6Cidr {
+    address: Ipv6Addr,
+    prefix_len: u8,
+}
+
+impl Ipv6Cidr {
+    pub fn new(address: Ipv6Addr, prefix_len: u8) -> Self {
+        assert!(prefix_len <= 128);
+
+        let mask = if prefix_len == 0 { 0 } else { u128::MAX << (128 - prefix_len) };
+        Ipv6Cidr { address: Ipv6Addr::from(u128::from(address) & mask), prefix_len }
+    }
+
+    pub fn address(&self) -> Ipv6Addr {
+        self.address
+    }
+
+    pub fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    pub fn first(&self) -> Ipv6Addr {
+        self.address
+    }
+
+    pub fn last(&self) -> Ipv6Addr {
+        let hostmask = if self.prefix_len == 0 { u128::MAX } else { (1u128 << (128 - self.prefix_len)) - 1 };
+        Ipv6Addr::from(u128::from(self.address) | hostmask)
+    }
+
+    pub fn contains(&self, addr: &Ipv6Addr) -> bool {
+        self.first() <= *addr && *addr <= self.last()
+    }
+}
+
+impl fmt::Display for Ipv6Cidr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.address, self.prefix_len)
+    }
+}
+
+impl FromStr for Ipv6Cidr {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (address, prefix_len) = split_cidr(s, 128)?;
+        let address = address.parse::<Ipv6Addr>()
+            .map_err(|_| Error::ParseCidrError(format!("Invalid IPv6 CIDR ({})", s)))?;
+        Ok(Ipv6Cidr::new(address, prefix_len))
+    }
+}
+
+
+/// Split `address/prefix_len`, a bare address is a host route.
+fn split_cidr(s: &str, max_prefix_len: u8) -> Result<(&str, u8), Error> {
+    let mut parts = s.trim().splitn(2, '/');
+    let address = parts.next().unwrap_or("");
+    let prefix_len = match parts.next() {
+        Some(prefix_len) => prefix_len.parse::<u8>().ok()
+            .filter(|prefix_len| *prefix_len <= max_prefix_len)
+            .ok_or_else(|| Error::ParseCidrError(format!("Invalid prefix length ({})", s)))?,
+        None => max_prefix_len,
+    };
+
+    Ok((address, prefix_len))
+}
+
+
+#[test]
+fn test_ipv4_cidr() {
+    let cidr: Ipv4Cidr = "41.0.0.1/11".parse().unwrap();
+    assert_eq!(cidr.to_string(), "41.0.0.0/11");
+    assert_eq!(cidr.last(), Ipv4Addr::new(41, 31, 255, 255));
+    assert_eq!(Ipv4Cidr::new(Ipv4Addr::new(1, 2, 3, 4), 0).last(), Ipv4Addr::new(255, 255, 255, 255));
+    assert!("41.0.0.0/33".parse::<Ipv4Cidr>().is_err());
+}
+
+#[test]
+fn test_ipv6_cidr() {
+    let cidr: Ipv6Cidr = "2001:4200::/32".parse().unwrap();
+    assert_eq!(cidr.last(), "2001:4200:ffff:ffff:ffff:ffff:ffff:ffff".parse::<Ipv6Addr>().unwrap());
+    assert!(cidr.contains(&"2001:4200:1::1".parse().unwrap()));
+    assert!(!cidr.contains(&"2001:4201::".parse().unwrap()));
+}
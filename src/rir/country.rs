@@ -0,0 +1,282 @@
+use std::str::FromStr;
+use std::fmt;
+
+use crate::rir::region::Region;
+use crate::rir::registry::Registry;
+use crate::rir::error::Error;
+
+
+// The `Country` enum and its tables, generated by `iana countries` from `src/country.csv`.
+include!("country_table.rs");
+
+
+/// What a `Country` code stands for.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd)]
+pub enum CountryKind {
+    /// An ISO 3166-1 country.
+    Iso,
+    /// A region registered as a whole, e.g. `AP` for resources used across Asia Pacific.
+    Region,
+    /// No country given.
+    Unspecified,
+}
+
+impl FromStr for CountryKind {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "iso" => Ok(CountryKind::Iso),
+            "region" => Ok(CountryKind::Region),
+            "unspecified" => Ok(CountryKind::Unspecified),
+            e => Err(Error::ParseCountryError(format!("Unknow Country Kind ({})", e))),
+        }
+    }
+}
+
+impl fmt::Display for CountryKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CountryKind::Iso => write!(f, "iso"),
+            CountryKind::Region => write!(f, "region"),
+            CountryKind::Unspecified => write!(f, "unspecified"),
+        }
+    }
+}
+
+
+/// Lowercase ASCII words of a name: `Côte d'Ivoire` as `cote divoire`, `St. Lucia` as `saint lucia`.
+fn normalize_name(name: &str) -> String {
+    let mut folded = String::with_capacity(name.len());
+    for c in name.chars().flat_map(|c| c.to_lowercase()) {
+        match c {
+            'à'..='å' => folded.push('a'),
+            'ç' => folded.push('c'),
+            'è'..='ë' => folded.push('e'),
+            'ì'..='ï' => folded.push('i'),
+            'ñ' => folded.push('n'),
+            'ò'..='ö' | 'ø' => folded.push('o'),
+            'ù'..='ü' => folded.push('u'),
+            'ý' | 'ÿ' => folded.push('y'),
+            '&' => folded.push_str(" and "),
+            '\'' | '’' | '.' => {},
+            c if c.is_alphanumeric() => folded.push(c),
+            _ => folded.push(' '),
+        }
+    }
+    folded.split_whitespace()
+        .map(|word| if word == "st" { "saint" } else { word })
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Levenshtein distance, for the suggestions of `Country::parse_lenient`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let cost = if ca == cb { prev } else { prev + 1 };
+            prev = row[j + 1];
+            row[j + 1] = cost.min(row[j] + 1).min(prev + 1);
+        }
+    }
+    row[b.len()]
+}
+
+impl Country {
+    /// Every code, in index order.
+    pub fn all() -> impl Iterator<Item = Country> {
+        COUNTRIES.iter().cloned()
+    }
+
+    pub fn is_iso(&self) -> bool {
+        self.kind() == CountryKind::Iso
+    }
+
+    /// Parse what people type: alpha-2, alpha-3 or numeric codes in any case (`de`, `DEU`, `276`),
+    /// the name (`Germany`, `Côte d'Ivoire` or `Cote d'Ivoire`) or a common name (`Ivory Coast`).
+    ///
+    /// The start of a name is enough when only one country matches, otherwise the error lists
+    /// the candidates, e.g. `KP` and `KR` for `Korea`. `FromStr` stays strict, as in the RIR files.
+    pub fn parse_lenient(s: &str) -> Result<Self, Error> {
+        let code = s.trim().to_uppercase();
+        let by_code = match code.len() {
+            1..=3 if code.bytes().all(|b| b.is_ascii_digit()) => {
+                code.parse().ok().and_then(|numeric| Country::from_numeric(numeric).ok())
+            },
+            2 => Country::from_str(&code).ok(),
+            3 => Country::from_alpha3(&code).ok(),
+            _ => None,
+        };
+        if let Some(country) = by_code {
+            return Ok(country);
+        }
+
+        let name = normalize_name(s);
+        let countries: Vec<(Country, Vec<String>)> = Country::all()
+            .map(|country| {
+                let names = std::iter::once(country.name())
+                    .chain(country.common_names().iter().cloned())
+                    .map(normalize_name)
+                    .collect();
+                (country, names)
+            })
+            .collect();
+        let matching = |filter: &dyn Fn(&str) -> bool| -> Vec<Country> {
+            countries.iter()
+                .filter(|(_, names)| names.iter().any(|country_name| filter(country_name)))
+                .map(|&(country, _)| country)
+                .collect()
+        };
+
+        let mut candidates = matching(&|country_name| country_name == name);
+        if candidates.is_empty() && name.len() >= 3 {
+            let prefix = format!(" {}", name);
+            candidates = matching(&|country_name| format!(" {}", country_name).contains(&prefix));
+        }
+
+        let list = |countries: &[Country]| countries.iter()
+            .map(|country| format!("{} ({})", country, country.name()))
+            .collect::<Vec<String>>()
+            .join(", ");
+        match candidates.len() {
+            1 => Ok(candidates[0]),
+            0 => {
+                // Any two characters are within 2 edits of a two letter name like `UK`.
+                let max_distance = if name.chars().count() >= 3 { 2 } else { 1 };
+                let suggestions = matching(&|country_name| edit_distance(country_name, &name) <= max_distance);
+                if suggestions.is_empty() {
+                    Err(Error::ParseCountryError(format!("Unknow Country ({})", s)))
+                } else {
+                    Err(Error::ParseCountryError(format!("Unknow Country ({}), did you mean {}?", s, list(&suggestions))))
+                }
+            },
+            _ => Err(Error::ParseCountryError(format!("Ambiguous Country ({}), did you mean {}?", s, list(&candidates)))),
+        }
+    }
+}
+
+
+#[test]
+fn test_codes() {
+    assert_eq!(Country::US.alpha3(), Some("USA"));
+    assert_eq!(Country::US.numeric(), Some(840));
+    assert_eq!(Country::from_alpha3("USA"), Ok(Country::US));
+    assert_eq!(Country::from_numeric(840), Ok(Country::US));
+    assert_eq!(Country::AF.numeric(), Some(4));
+    assert_eq!(Country::AP.alpha3(), None);
+    assert!(Country::from_alpha3("usa").is_err());
+    assert!(Country::from_numeric(0).is_err());
+
+    for country in Country::all() {
+        if let Some(alpha3) = country.alpha3() {
+            assert_eq!(Country::from_alpha3(alpha3), Ok(country));
+        }
+        if let Some(numeric) = country.numeric() {
+            assert_eq!(Country::from_numeric(numeric), Ok(country));
+        }
+        assert_eq!(country.alpha3().is_some(), country.is_iso());
+    }
+}
+
+
+#[test]
+fn test_parse_lenient() {
+    assert_eq!(Country::parse_lenient("de"), Ok(Country::DE));
+    assert_eq!(Country::parse_lenient("DEU"), Ok(Country::DE));
+    assert_eq!(Country::parse_lenient("deu"), Ok(Country::DE));
+    assert_eq!(Country::parse_lenient("276"), Ok(Country::DE));
+    assert_eq!(Country::parse_lenient("4"), Ok(Country::AF));
+    assert_eq!(Country::parse_lenient("40"), Ok(Country::AT));
+    assert_eq!(Country::parse_lenient("76"), Ok(Country::BR));
+    assert_eq!(Country::parse_lenient("040"), Ok(Country::AT));
+    assert_eq!(Country::parse_lenient(" Germany "), Ok(Country::DE));
+    assert_eq!(Country::parse_lenient("GERMANY"), Ok(Country::DE));
+    assert_eq!(Country::parse_lenient("Côte d'Ivoire"), Ok(Country::CI));
+    assert_eq!(Country::parse_lenient("cote d’ivoire"), Ok(Country::CI));
+    assert_eq!(Country::parse_lenient("Ivory Coast"), Ok(Country::CI));
+    assert_eq!(Country::parse_lenient("Curacao"), Ok(Country::CW));
+    assert_eq!(Country::parse_lenient("Aland Islands"), Ok(Country::AX));
+    assert_eq!(Country::parse_lenient("St. Lucia"), Ok(Country::LC));
+    assert_eq!(Country::parse_lenient("Guinea-Bissau"), Ok(Country::GW));
+    assert_eq!(Country::parse_lenient("Guinea"), Ok(Country::GN));
+    assert_eq!(Country::parse_lenient("Congo"), Ok(Country::CG));
+    assert_eq!(Country::parse_lenient("UK"), Ok(Country::GB));
+    assert_eq!(Country::parse_lenient("United Kingdom"), Ok(Country::GB));
+    assert_eq!(Country::parse_lenient("United States"), Ok(Country::US));
+    assert_eq!(Country::parse_lenient("Bosnia"), Ok(Country::BA));
+    assert_eq!(Country::parse_lenient("ap"), Ok(Country::AP));
+
+    match Country::parse_lenient("Korea") {
+        Err(Error::ParseCountryError(e)) => {
+            assert!(e.starts_with("Ambiguous Country (Korea)"), "{}", e);
+            assert!(e.contains("KP (") && e.contains("KR ("), "{}", e);
+        },
+        result => panic!("{:?}", result),
+    }
+    match Country::parse_lenient("Germny") {
+        Err(Error::ParseCountryError(e)) => assert_eq!(e, "Unknow Country (Germny), did you mean DE (Germany)?"),
+        result => panic!("{:?}", result),
+    }
+    assert_eq!(Country::parse_lenient("xx"), Err(Error::ParseCountryError("Unknow Country (xx)".to_string())));
+    assert_eq!(Country::parse_lenient("99"), Err(Error::ParseCountryError("Unknow Country (99)".to_string())));
+    assert!(Country::parse_lenient("Atlantis").is_err());
+    assert!(Country::parse_lenient("").is_err());
+    assert!(Country::parse_lenient("999").is_err());
+    assert!(Country::from_str("de").is_err());
+}
+
+/// Every row of `src/country.csv` against the generated tables, run `iana countries` after editing it.
+#[test]
+fn test_country_csv() {
+    let split = |line: &str| -> Vec<String> {
+        let mut fields = vec![String::new()];
+        let mut quoted = false;
+        for c in line.chars() {
+            match c {
+                '"' => quoted = !quoted,
+                ',' if !quoted => fields.push(String::new()),
+                c => fields.last_mut().unwrap().push(c),
+            }
+        }
+        fields
+    };
+
+    let content = include_str!("../country.csv");
+    let mut lines = content.lines();
+    let header = split(lines.next().unwrap());
+    let column = |name: &str| header.iter().position(|field| field == name).unwrap();
+
+    let mut count = 0;
+    for line in lines {
+        let fields = split(line);
+        let field = |name: &str| fields[column(name)].as_str();
+
+        let country = Country::from_str(field("alpha-2")).unwrap();
+        assert_eq!(country.to_string(), field("alpha-2"));
+        assert_eq!(country.index().to_string(), field("index"));
+        assert_eq!(Country::from_index(country.index()), Ok(country));
+        assert_eq!(country.name(), field("name"));
+        assert_eq!(country.kind().to_string(), field("kind"));
+        assert_eq!(country.common_names().join(";"), field("common-names"));
+        assert_eq!(country.registry().map(|registry| registry.to_string()).unwrap_or_default(), field("registry"));
+        assert_eq!(Country::parse_lenient(field("name")), Ok(country));
+        for common_name in country.common_names() {
+            assert_eq!(Country::parse_lenient(common_name), Ok(country), "{}", common_name);
+        }
+        assert_eq!(country.alpha3().unwrap_or(""), field("alpha-3"));
+        assert_eq!(country.numeric().map(|numeric| format!("{:03}", numeric)).unwrap_or_default(), field("country-code"));
+
+        let code = |region: Option<Region>| region.map(|region| format!("{:03}", region.code())).unwrap_or_default();
+        assert_eq!(code(country.region()), field("region-code"), "{}", line);
+        assert_eq!(code(country.sub_region()), field("sub-region-code"), "{}", line);
+        assert_eq!(code(country.intermediate_region()), field("intermediate-region-code"), "{}", line);
+        count += 1;
+    }
+
+    assert_eq!(count, Country::all().count());
+    assert_eq!((0..=255).filter(|&index| Country::from_index(index).is_ok()).count(), count);
+    assert!(Country::all().zip(Country::all().skip(1)).all(|(a, b)| a < b && a.index() < b.index()));
+}
@@ -0,0 +1,3410 @@
+// Generated by `iana countries` from `src/country.csv`, edit the CSV instead.
+
+
+/// Country Code List: ISO 3166-1993 (E)
+///
+/// Download from: https://github.com/lukes/ISO-3166-Countries-with-Regional-Codes
+///
+/// Followed by the codes the RIRs use outside ISO 3166-1, see `Country::kind`.
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Country {
+    AF = 0u8,
+    AX = 1u8,
+    AL = 2u8,
+    DZ = 3u8,
+    AS = 4u8,
+    AD = 5u8,
+    AO = 6u8,
+    AI = 7u8,
+    AQ = 8u8,
+    AG = 9u8,
+    AR = 10u8,
+    AM = 11u8,
+    AW = 12u8,
+    AU = 13u8,
+    AT = 14u8,
+    AZ = 15u8,
+    BS = 16u8,
+    BH = 17u8,
+    BD = 18u8,
+    BB = 19u8,
+    BY = 20u8,
+    BE = 21u8,
+    BZ = 22u8,
+    BJ = 23u8,
+    BM = 24u8,
+    BT = 25u8,
+    BO = 26u8,
+    BQ = 27u8,
+    BA = 28u8,
+    BW = 29u8,
+    BV = 30u8,
+    BR = 31u8,
+    IO = 32u8,
+    BN = 33u8,
+    BG = 34u8,
+    BF = 35u8,
+    BI = 36u8,
+    KH = 37u8,
+    CM = 38u8,
+    CA = 39u8,
+    CV = 40u8,
+    KY = 41u8,
+    CF = 42u8,
+    TD = 43u8,
+    CL = 44u8,
+    CN = 45u8,
+    CX = 46u8,
+    CC = 47u8,
+    CO = 48u8,
+    KM = 49u8,
+    CG = 50u8,
+    CD = 51u8,
+    CK = 52u8,
+    CR = 53u8,
+    CI = 54u8,
+    HR = 55u8,
+    CU = 56u8,
+    CW = 57u8,
+    CY = 58u8,
+    CZ = 59u8,
+    DK = 60u8,
+    DJ = 61u8,
+    DM = 62u8,
+    DO = 63u8,
+    EC = 64u8,
+    EG = 65u8,
+    SV = 66u8,
+    GQ = 67u8,
+    ER = 68u8,
+    EE = 69u8,
+    ET = 70u8,
+    FK = 71u8,
+    FO = 72u8,
+    FJ = 73u8,
+    FI = 74u8,
+    FR = 75u8,
+    GF = 76u8,
+    PF = 77u8,
+    TF = 78u8,
+    GA = 79u8,
+    GM = 80u8,
+    GE = 81u8,
+    DE = 82u8,
+    GH = 83u8,
+    GI = 84u8,
+    GR = 85u8,
+    GL = 86u8,
+    GD = 87u8,
+    GP = 88u8,
+    GU = 89u8,
+    GT = 90u8,
+    GG = 91u8,
+    GN = 92u8,
+    GW = 93u8,
+    GY = 94u8,
+    HT = 95u8,
+    HM = 96u8,
+    VA = 97u8,
+    HN = 98u8,
+    HK = 99u8,
+    HU = 100u8,
+    IS = 101u8,
+    IN = 102u8,
+    ID = 103u8,
+    IR = 104u8,
+    IQ = 105u8,
+    IE = 106u8,
+    IM = 107u8,
+    IL = 108u8,
+    IT = 109u8,
+    JM = 110u8,
+    JP = 111u8,
+    JE = 112u8,
+    JO = 113u8,
+    KZ = 114u8,
+    KE = 115u8,
+    KI = 116u8,
+    KP = 117u8,
+    KR = 118u8,
+    KW = 119u8,
+    KG = 120u8,
+    LA = 121u8,
+    LV = 122u8,
+    LB = 123u8,
+    LS = 124u8,
+    LR = 125u8,
+    LY = 126u8,
+    LI = 127u8,
+    LT = 128u8,
+    LU = 129u8,
+    MO = 130u8,
+    MK = 131u8,
+    MG = 132u8,
+    MW = 133u8,
+    MY = 134u8,
+    MV = 135u8,
+    ML = 136u8,
+    MT = 137u8,
+    MH = 138u8,
+    MQ = 139u8,
+    MR = 140u8,
+    MU = 141u8,
+    YT = 142u8,
+    MX = 143u8,
+    FM = 144u8,
+    MD = 145u8,
+    MC = 146u8,
+    MN = 147u8,
+    ME = 148u8,
+    MS = 149u8,
+    MA = 150u8,
+    MZ = 151u8,
+    MM = 152u8,
+    NA = 153u8,
+    NR = 154u8,
+    NP = 155u8,
+    NL = 156u8,
+    NC = 157u8,
+    NZ = 158u8,
+    NI = 159u8,
+    NE = 160u8,
+    NG = 161u8,
+    NU = 162u8,
+    NF = 163u8,
+    MP = 164u8,
+    NO = 165u8,
+    OM = 166u8,
+    PK = 167u8,
+    PW = 168u8,
+    PS = 169u8,
+    PA = 170u8,
+    PG = 171u8,
+    PY = 172u8,
+    PE = 173u8,
+    PH = 174u8,
+    PN = 175u8,
+    PL = 176u8,
+    PT = 177u8,
+    PR = 178u8,
+    QA = 179u8,
+    RE = 180u8,
+    RO = 181u8,
+    RU = 182u8,
+    RW = 183u8,
+    BL = 184u8,
+    SH = 185u8,
+    KN = 186u8,
+    LC = 187u8,
+    MF = 188u8,
+    PM = 189u8,
+    VC = 190u8,
+    WS = 191u8,
+    SM = 192u8,
+    ST = 193u8,
+    SA = 194u8,
+    SN = 195u8,
+    RS = 196u8,
+    SC = 197u8,
+    SL = 198u8,
+    SG = 199u8,
+    SX = 200u8,
+    SK = 201u8,
+    SI = 202u8,
+    SB = 203u8,
+    SO = 204u8,
+    ZA = 205u8,
+    GS = 206u8,
+    SS = 207u8,
+    ES = 208u8,
+    LK = 209u8,
+    SD = 210u8,
+    SR = 211u8,
+    SJ = 212u8,
+    SZ = 213u8,
+    SE = 214u8,
+    CH = 215u8,
+    SY = 216u8,
+    TW = 217u8,
+    TJ = 218u8,
+    TZ = 219u8,
+    TH = 220u8,
+    TL = 221u8,
+    TG = 222u8,
+    TK = 223u8,
+    TO = 224u8,
+    TT = 225u8,
+    TN = 226u8,
+    TR = 227u8,
+    TM = 228u8,
+    TC = 229u8,
+    TV = 230u8,
+    UG = 231u8,
+    UA = 232u8,
+    AE = 233u8,
+    GB = 234u8,
+    US = 235u8,
+    UM = 236u8,
+    UY = 237u8,
+    UZ = 238u8,
+    VU = 239u8,
+    VE = 240u8,
+    VN = 241u8,
+    VG = 242u8,
+    VI = 243u8,
+    WF = 244u8,
+    EH = 245u8,
+    YE = 246u8,
+    ZM = 247u8,
+    ZW = 248u8,
+    EU = 249u8,
+    ZZ = 250u8,
+    AP = 251u8,
+}
+
+static COUNTRIES: [Country; 252] = [
+    Country::AF,
+    Country::AX,
+    Country::AL,
+    Country::DZ,
+    Country::AS,
+    Country::AD,
+    Country::AO,
+    Country::AI,
+    Country::AQ,
+    Country::AG,
+    Country::AR,
+    Country::AM,
+    Country::AW,
+    Country::AU,
+    Country::AT,
+    Country::AZ,
+    Country::BS,
+    Country::BH,
+    Country::BD,
+    Country::BB,
+    Country::BY,
+    Country::BE,
+    Country::BZ,
+    Country::BJ,
+    Country::BM,
+    Country::BT,
+    Country::BO,
+    Country::BQ,
+    Country::BA,
+    Country::BW,
+    Country::BV,
+    Country::BR,
+    Country::IO,
+    Country::BN,
+    Country::BG,
+    Country::BF,
+    Country::BI,
+    Country::KH,
+    Country::CM,
+    Country::CA,
+    Country::CV,
+    Country::KY,
+    Country::CF,
+    Country::TD,
+    Country::CL,
+    Country::CN,
+    Country::CX,
+    Country::CC,
+    Country::CO,
+    Country::KM,
+    Country::CG,
+    Country::CD,
+    Country::CK,
+    Country::CR,
+    Country::CI,
+    Country::HR,
+    Country::CU,
+    Country::CW,
+    Country::CY,
+    Country::CZ,
+    Country::DK,
+    Country::DJ,
+    Country::DM,
+    Country::DO,
+    Country::EC,
+    Country::EG,
+    Country::SV,
+    Country::GQ,
+    Country::ER,
+    Country::EE,
+    Country::ET,
+    Country::FK,
+    Country::FO,
+    Country::FJ,
+    Country::FI,
+    Country::FR,
+    Country::GF,
+    Country::PF,
+    Country::TF,
+    Country::GA,
+    Country::GM,
+    Country::GE,
+    Country::DE,
+    Country::GH,
+    Country::GI,
+    Country::GR,
+    Country::GL,
+    Country::GD,
+    Country::GP,
+    Country::GU,
+    Country::GT,
+    Country::GG,
+    Country::GN,
+    Country::GW,
+    Country::GY,
+    Country::HT,
+    Country::HM,
+    Country::VA,
+    Country::HN,
+    Country::HK,
+    Country::HU,
+    Country::IS,
+    Country::IN,
+    Country::ID,
+    Country::IR,
+    Country::IQ,
+    Country::IE,
+    Country::IM,
+    Country::IL,
+    Country::IT,
+    Country::JM,
+    Country::JP,
+    Country::JE,
+    Country::JO,
+    Country::KZ,
+    Country::KE,
+    Country::KI,
+    Country::KP,
+    Country::KR,
+    Country::KW,
+    Country::KG,
+    Country::LA,
+    Country::LV,
+    Country::LB,
+    Country::LS,
+    Country::LR,
+    Country::LY,
+    Country::LI,
+    Country::LT,
+    Country::LU,
+    Country::MO,
+    Country::MK,
+    Country::MG,
+    Country::MW,
+    Country::MY,
+    Country::MV,
+    Country::ML,
+    Country::MT,
+    Country::MH,
+    Country::MQ,
+    Country::MR,
+    Country::MU,
+    Country::YT,
+    Country::MX,
+    Country::FM,
+    Country::MD,
+    Country::MC,
+    Country::MN,
+    Country::ME,
+    Country::MS,
+    Country::MA,
+    Country::MZ,
+    Country::MM,
+    Country::NA,
+    Country::NR,
+    Country::NP,
+    Country::NL,
+    Country::NC,
+    Country::NZ,
+    Country::NI,
+    Country::NE,
+    Country::NG,
+    Country::NU,
+    Country::NF,
+    Country::MP,
+    Country::NO,
+    Country::OM,
+    Country::PK,
+    Country::PW,
+    Country::PS,
+    Country::PA,
+    Country::PG,
+    Country::PY,
+    Country::PE,
+    Country::PH,
+    Country::PN,
+    Country::PL,
+    Country::PT,
+    Country::PR,
+    Country::QA,
+    Country::RE,
+    Country::RO,
+    Country::RU,
+    Country::RW,
+    Country::BL,
+    Country::SH,
+    Country::KN,
+    Country::LC,
+    Country::MF,
+    Country::PM,
+    Country::VC,
+    Country::WS,
+    Country::SM,
+    Country::ST,
+    Country::SA,
+    Country::SN,
+    Country::RS,
+    Country::SC,
+    Country::SL,
+    Country::SG,
+    Country::SX,
+    Country::SK,
+    Country::SI,
+    Country::SB,
+    Country::SO,
+    Country::ZA,
+    Country::GS,
+    Country::SS,
+    Country::ES,
+    Country::LK,
+    Country::SD,
+    Country::SR,
+    Country::SJ,
+    Country::SZ,
+    Country::SE,
+    Country::CH,
+    Country::SY,
+    Country::TW,
+    Country::TJ,
+    Country::TZ,
+    Country::TH,
+    Country::TL,
+    Country::TG,
+    Country::TK,
+    Country::TO,
+    Country::TT,
+    Country::TN,
+    Country::TR,
+    Country::TM,
+    Country::TC,
+    Country::TV,
+    Country::UG,
+    Country::UA,
+    Country::AE,
+    Country::GB,
+    Country::US,
+    Country::UM,
+    Country::UY,
+    Country::UZ,
+    Country::VU,
+    Country::VE,
+    Country::VN,
+    Country::VG,
+    Country::VI,
+    Country::WF,
+    Country::EH,
+    Country::YE,
+    Country::ZM,
+    Country::ZW,
+    Country::EU,
+    Country::ZZ,
+    Country::AP,
+];
+
+
+impl Country {
+
+    /// `Region` for the RIR codes covering several countries, `EU` and `AP`,
+    /// `Unspecified` for `ZZ`, which also stands for an empty code.
+    pub fn kind(&self) -> CountryKind {
+        match *self {
+            Country::EU | Country::AP => CountryKind::Region,
+            Country::ZZ => CountryKind::Unspecified,
+            _ => CountryKind::Iso,
+        }
+    }
+
+    pub fn from_index(index: u8) -> Result<Self, Error> {
+        match index {
+            0 => Ok(Country::AF),
+            1 => Ok(Country::AX),
+            2 => Ok(Country::AL),
+            3 => Ok(Country::DZ),
+            4 => Ok(Country::AS),
+            5 => Ok(Country::AD),
+            6 => Ok(Country::AO),
+            7 => Ok(Country::AI),
+            8 => Ok(Country::AQ),
+            9 => Ok(Country::AG),
+            10 => Ok(Country::AR),
+            11 => Ok(Country::AM),
+            12 => Ok(Country::AW),
+            13 => Ok(Country::AU),
+            14 => Ok(Country::AT),
+            15 => Ok(Country::AZ),
+            16 => Ok(Country::BS),
+            17 => Ok(Country::BH),
+            18 => Ok(Country::BD),
+            19 => Ok(Country::BB),
+            20 => Ok(Country::BY),
+            21 => Ok(Country::BE),
+            22 => Ok(Country::BZ),
+            23 => Ok(Country::BJ),
+            24 => Ok(Country::BM),
+            25 => Ok(Country::BT),
+            26 => Ok(Country::BO),
+            27 => Ok(Country::BQ),
+            28 => Ok(Country::BA),
+            29 => Ok(Country::BW),
+            30 => Ok(Country::BV),
+            31 => Ok(Country::BR),
+            32 => Ok(Country::IO),
+            33 => Ok(Country::BN),
+            34 => Ok(Country::BG),
+            35 => Ok(Country::BF),
+            36 => Ok(Country::BI),
+            37 => Ok(Country::KH),
+            38 => Ok(Country::CM),
+            39 => Ok(Country::CA),
+            40 => Ok(Country::CV),
+            41 => Ok(Country::KY),
+            42 => Ok(Country::CF),
+            43 => Ok(Country::TD),
+            44 => Ok(Country::CL),
+            45 => Ok(Country::CN),
+            46 => Ok(Country::CX),
+            47 => Ok(Country::CC),
+            48 => Ok(Country::CO),
+            49 => Ok(Country::KM),
+            50 => Ok(Country::CG),
+            51 => Ok(Country::CD),
+            52 => Ok(Country::CK),
+            53 => Ok(Country::CR),
+            54 => Ok(Country::CI),
+            55 => Ok(Country::HR),
+            56 => Ok(Country::CU),
+            57 => Ok(Country::CW),
+            58 => Ok(Country::CY),
+            59 => Ok(Country::CZ),
+            60 => Ok(Country::DK),
+            61 => Ok(Country::DJ),
+            62 => Ok(Country::DM),
+            63 => Ok(Country::DO),
+            64 => Ok(Country::EC),
+            65 => Ok(Country::EG),
+            66 => Ok(Country::SV),
+            67 => Ok(Country::GQ),
+            68 => Ok(Country::ER),
+            69 => Ok(Country::EE),
+            70 => Ok(Country::ET),
+            71 => Ok(Country::FK),
+            72 => Ok(Country::FO),
+            73 => Ok(Country::FJ),
+            74 => Ok(Country::FI),
+            75 => Ok(Country::FR),
+            76 => Ok(Country::GF),
+            77 => Ok(Country::PF),
+            78 => Ok(Country::TF),
+            79 => Ok(Country::GA),
+            80 => Ok(Country::GM),
+            81 => Ok(Country::GE),
+            82 => Ok(Country::DE),
+            83 => Ok(Country::GH),
+            84 => Ok(Country::GI),
+            85 => Ok(Country::GR),
+            86 => Ok(Country::GL),
+            87 => Ok(Country::GD),
+            88 => Ok(Country::GP),
+            89 => Ok(Country::GU),
+            90 => Ok(Country::GT),
+            91 => Ok(Country::GG),
+            92 => Ok(Country::GN),
+            93 => Ok(Country::GW),
+            94 => Ok(Country::GY),
+            95 => Ok(Country::HT),
+            96 => Ok(Country::HM),
+            97 => Ok(Country::VA),
+            98 => Ok(Country::HN),
+            99 => Ok(Country::HK),
+            100 => Ok(Country::HU),
+            101 => Ok(Country::IS),
+            102 => Ok(Country::IN),
+            103 => Ok(Country::ID),
+            104 => Ok(Country::IR),
+            105 => Ok(Country::IQ),
+            106 => Ok(Country::IE),
+            107 => Ok(Country::IM),
+            108 => Ok(Country::IL),
+            109 => Ok(Country::IT),
+            110 => Ok(Country::JM),
+            111 => Ok(Country::JP),
+            112 => Ok(Country::JE),
+            113 => Ok(Country::JO),
+            114 => Ok(Country::KZ),
+            115 => Ok(Country::KE),
+            116 => Ok(Country::KI),
+            117 => Ok(Country::KP),
+            118 => Ok(Country::KR),
+            119 => Ok(Country::KW),
+            120 => Ok(Country::KG),
+            121 => Ok(Country::LA),
+            122 => Ok(Country::LV),
+            123 => Ok(Country::LB),
+            124 => Ok(Country::LS),
+            125 => Ok(Country::LR),
+            126 => Ok(Country::LY),
+            127 => Ok(Country::LI),
+            128 => Ok(Country::LT),
+            129 => Ok(Country::LU),
+            130 => Ok(Country::MO),
+            131 => Ok(Country::MK),
+            132 => Ok(Country::MG),
+            133 => Ok(Country::MW),
+            134 => Ok(Country::MY),
+            135 => Ok(Country::MV),
+            136 => Ok(Country::ML),
+            137 => Ok(Country::MT),
+            138 => Ok(Country::MH),
+            139 => Ok(Country::MQ),
+            140 => Ok(Country::MR),
+            141 => Ok(Country::MU),
+            142 => Ok(Country::YT),
+            143 => Ok(Country::MX),
+            144 => Ok(Country::FM),
+            145 => Ok(Country::MD),
+            146 => Ok(Country::MC),
+            147 => Ok(Country::MN),
+            148 => Ok(Country::ME),
+            149 => Ok(Country::MS),
+            150 => Ok(Country::MA),
+            151 => Ok(Country::MZ),
+            152 => Ok(Country::MM),
+            153 => Ok(Country::NA),
+            154 => Ok(Country::NR),
+            155 => Ok(Country::NP),
+            156 => Ok(Country::NL),
+            157 => Ok(Country::NC),
+            158 => Ok(Country::NZ),
+            159 => Ok(Country::NI),
+            160 => Ok(Country::NE),
+            161 => Ok(Country::NG),
+            162 => Ok(Country::NU),
+            163 => Ok(Country::NF),
+            164 => Ok(Country::MP),
+            165 => Ok(Country::NO),
+            166 => Ok(Country::OM),
+            167 => Ok(Country::PK),
+            168 => Ok(Country::PW),
+            169 => Ok(Country::PS),
+            170 => Ok(Country::PA),
+            171 => Ok(Country::PG),
+            172 => Ok(Country::PY),
+            173 => Ok(Country::PE),
+            174 => Ok(Country::PH),
+            175 => Ok(Country::PN),
+            176 => Ok(Country::PL),
+            177 => Ok(Country::PT),
+            178 => Ok(Country::PR),
+            179 => Ok(Country::QA),
+            180 => Ok(Country::RE),
+            181 => Ok(Country::RO),
+            182 => Ok(Country::RU),
+            183 => Ok(Country::RW),
+            184 => Ok(Country::BL),
+            185 => Ok(Country::SH),
+            186 => Ok(Country::KN),
+            187 => Ok(Country::LC),
+            188 => Ok(Country::MF),
+            189 => Ok(Country::PM),
+            190 => Ok(Country::VC),
+            191 => Ok(Country::WS),
+            192 => Ok(Country::SM),
+            193 => Ok(Country::ST),
+            194 => Ok(Country::SA),
+            195 => Ok(Country::SN),
+            196 => Ok(Country::RS),
+            197 => Ok(Country::SC),
+            198 => Ok(Country::SL),
+            199 => Ok(Country::SG),
+            200 => Ok(Country::SX),
+            201 => Ok(Country::SK),
+            202 => Ok(Country::SI),
+            203 => Ok(Country::SB),
+            204 => Ok(Country::SO),
+            205 => Ok(Country::ZA),
+            206 => Ok(Country::GS),
+            207 => Ok(Country::SS),
+            208 => Ok(Country::ES),
+            209 => Ok(Country::LK),
+            210 => Ok(Country::SD),
+            211 => Ok(Country::SR),
+            212 => Ok(Country::SJ),
+            213 => Ok(Country::SZ),
+            214 => Ok(Country::SE),
+            215 => Ok(Country::CH),
+            216 => Ok(Country::SY),
+            217 => Ok(Country::TW),
+            218 => Ok(Country::TJ),
+            219 => Ok(Country::TZ),
+            220 => Ok(Country::TH),
+            221 => Ok(Country::TL),
+            222 => Ok(Country::TG),
+            223 => Ok(Country::TK),
+            224 => Ok(Country::TO),
+            225 => Ok(Country::TT),
+            226 => Ok(Country::TN),
+            227 => Ok(Country::TR),
+            228 => Ok(Country::TM),
+            229 => Ok(Country::TC),
+            230 => Ok(Country::TV),
+            231 => Ok(Country::UG),
+            232 => Ok(Country::UA),
+            233 => Ok(Country::AE),
+            234 => Ok(Country::GB),
+            235 => Ok(Country::US),
+            236 => Ok(Country::UM),
+            237 => Ok(Country::UY),
+            238 => Ok(Country::UZ),
+            239 => Ok(Country::VU),
+            240 => Ok(Country::VE),
+            241 => Ok(Country::VN),
+            242 => Ok(Country::VG),
+            243 => Ok(Country::VI),
+            244 => Ok(Country::WF),
+            245 => Ok(Country::EH),
+            246 => Ok(Country::YE),
+            247 => Ok(Country::ZM),
+            248 => Ok(Country::ZW),
+            249 => Ok(Country::EU),
+            250 => Ok(Country::ZZ),
+            251 => Ok(Country::AP),
+            e => Err(Error::ParseCountryError(format!("Unknow Country Index ({})", e))),
+        }
+    }
+
+    pub fn index(&self) -> u8 {
+        match *self {
+            Country::AF => 0,
+            Country::AX => 1,
+            Country::AL => 2,
+            Country::DZ => 3,
+            Country::AS => 4,
+            Country::AD => 5,
+            Country::AO => 6,
+            Country::AI => 7,
+            Country::AQ => 8,
+            Country::AG => 9,
+            Country::AR => 10,
+            Country::AM => 11,
+            Country::AW => 12,
+            Country::AU => 13,
+            Country::AT => 14,
+            Country::AZ => 15,
+            Country::BS => 16,
+            Country::BH => 17,
+            Country::BD => 18,
+            Country::BB => 19,
+            Country::BY => 20,
+            Country::BE => 21,
+            Country::BZ => 22,
+            Country::BJ => 23,
+            Country::BM => 24,
+            Country::BT => 25,
+            Country::BO => 26,
+            Country::BQ => 27,
+            Country::BA => 28,
+            Country::BW => 29,
+            Country::BV => 30,
+            Country::BR => 31,
+            Country::IO => 32,
+            Country::BN => 33,
+            Country::BG => 34,
+            Country::BF => 35,
+            Country::BI => 36,
+            Country::KH => 37,
+            Country::CM => 38,
+            Country::CA => 39,
+            Country::CV => 40,
+            Country::KY => 41,
+            Country::CF => 42,
+            Country::TD => 43,
+            Country::CL => 44,
+            Country::CN => 45,
+            Country::CX => 46,
+            Country::CC => 47,
+            Country::CO => 48,
+            Country::KM => 49,
+            Country::CG => 50,
+            Country::CD => 51,
+            Country::CK => 52,
+            Country::CR => 53,
+            Country::CI => 54,
+            Country::HR => 55,
+            Country::CU => 56,
+            Country::CW => 57,
+            Country::CY => 58,
+            Country::CZ => 59,
+            Country::DK => 60,
+            Country::DJ => 61,
+            Country::DM => 62,
+            Country::DO => 63,
+            Country::EC => 64,
+            Country::EG => 65,
+            Country::SV => 66,
+            Country::GQ => 67,
+            Country::ER => 68,
+            Country::EE => 69,
+            Country::ET => 70,
+            Country::FK => 71,
+            Country::FO => 72,
+            Country::FJ => 73,
+            Country::FI => 74,
+            Country::FR => 75,
+            Country::GF => 76,
+            Country::PF => 77,
+            Country::TF => 78,
+            Country::GA => 79,
+            Country::GM => 80,
+            Country::GE => 81,
+            Country::DE => 82,
+            Country::GH => 83,
+            Country::GI => 84,
+            Country::GR => 85,
+            Country::GL => 86,
+            Country::GD => 87,
+            Country::GP => 88,
+            Country::GU => 89,
+            Country::GT => 90,
+            Country::GG => 91,
+            Country::GN => 92,
+            Country::GW => 93,
+            Country::GY => 94,
+            Country::HT => 95,
+            Country::HM => 96,
+            Country::VA => 97,
+            Country::HN => 98,
+            Country::HK => 99,
+            Country::HU => 100,
+            Country::IS => 101,
+            Country::IN => 102,
+            Country::ID => 103,
+            Country::IR => 104,
+            Country::IQ => 105,
+            Country::IE => 106,
+            Country::IM => 107,
+            Country::IL => 108,
+            Country::IT => 109,
+            Country::JM => 110,
+            Country::JP => 111,
+            Country::JE => 112,
+            Country::JO => 113,
+            Country::KZ => 114,
+            Country::KE => 115,
+            Country::KI => 116,
+            Country::KP => 117,
+            Country::KR => 118,
+            Country::KW => 119,
+            Country::KG => 120,
+            Country::LA => 121,
+            Country::LV => 122,
+            Country::LB => 123,
+            Country::LS => 124,
+            Country::LR => 125,
+            Country::LY => 126,
+            Country::LI => 127,
+            Country::LT => 128,
+            Country::LU => 129,
+            Country::MO => 130,
+            Country::MK => 131,
+            Country::MG => 132,
+            Country::MW => 133,
+            Country::MY => 134,
+            Country::MV => 135,
+            Country::ML => 136,
+            Country::MT => 137,
+            Country::MH => 138,
+            Country::MQ => 139,
+            Country::MR => 140,
+            Country::MU => 141,
+            Country::YT => 142,
+            Country::MX => 143,
+            Country::FM => 144,
+            Country::MD => 145,
+            Country::MC => 146,
+            Country::MN => 147,
+            Country::ME => 148,
+            Country::MS => 149,
+            Country::MA => 150,
+            Country::MZ => 151,
+            Country::MM => 152,
+            Country::NA => 153,
+            Country::NR => 154,
+            Country::NP => 155,
+            Country::NL => 156,
+            Country::NC => 157,
+            Country::NZ => 158,
+            Country::NI => 159,
+            Country::NE => 160,
+            Country::NG => 161,
+            Country::NU => 162,
+            Country::NF => 163,
+            Country::MP => 164,
+            Country::NO => 165,
+            Country::OM => 166,
+            Country::PK => 167,
+            Country::PW => 168,
+            Country::PS => 169,
+            Country::PA => 170,
+            Country::PG => 171,
+            Country::PY => 172,
+            Country::PE => 173,
+            Country::PH => 174,
+            Country::PN => 175,
+            Country::PL => 176,
+            Country::PT => 177,
+            Country::PR => 178,
+            Country::QA => 179,
+            Country::RE => 180,
+            Country::RO => 181,
+            Country::RU => 182,
+            Country::RW => 183,
+            Country::BL => 184,
+            Country::SH => 185,
+            Country::KN => 186,
+            Country::LC => 187,
+            Country::MF => 188,
+            Country::PM => 189,
+            Country::VC => 190,
+            Country::WS => 191,
+            Country::SM => 192,
+            Country::ST => 193,
+            Country::SA => 194,
+            Country::SN => 195,
+            Country::RS => 196,
+            Country::SC => 197,
+            Country::SL => 198,
+            Country::SG => 199,
+            Country::SX => 200,
+            Country::SK => 201,
+            Country::SI => 202,
+            Country::SB => 203,
+            Country::SO => 204,
+            Country::ZA => 205,
+            Country::GS => 206,
+            Country::SS => 207,
+            Country::ES => 208,
+            Country::LK => 209,
+            Country::SD => 210,
+            Country::SR => 211,
+            Country::SJ => 212,
+            Country::SZ => 213,
+            Country::SE => 214,
+            Country::CH => 215,
+            Country::SY => 216,
+            Country::TW => 217,
+            Country::TJ => 218,
+            Country::TZ => 219,
+            Country::TH => 220,
+            Country::TL => 221,
+            Country::TG => 222,
+            Country::TK => 223,
+            Country::TO => 224,
+            Country::TT => 225,
+            Country::TN => 226,
+            Country::TR => 227,
+            Country::TM => 228,
+            Country::TC => 229,
+            Country::TV => 230,
+            Country::UG => 231,
+            Country::UA => 232,
+            Country::AE => 233,
+            Country::GB => 234,
+            Country::US => 235,
+            Country::UM => 236,
+            Country::UY => 237,
+            Country::UZ => 238,
+            Country::VU => 239,
+            Country::VE => 240,
+            Country::VN => 241,
+            Country::VG => 242,
+            Country::VI => 243,
+            Country::WF => 244,
+            Country::EH => 245,
+            Country::YE => 246,
+            Country::ZM => 247,
+            Country::ZW => 248,
+            Country::EU => 249,
+            Country::ZZ => 250,
+            Country::AP => 251,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Country::AF => "Afghanistan",
+            Country::AX => "Åland Islands",
+            Country::AL => "Albania",
+            Country::DZ => "Algeria",
+            Country::AS => "American Samoa",
+            Country::AD => "Andorra",
+            Country::AO => "Angola",
+            Country::AI => "Anguilla",
+            Country::AQ => "Antarctica",
+            Country::AG => "Antigua and Barbuda",
+            Country::AR => "Argentina",
+            Country::AM => "Armenia",
+            Country::AW => "Aruba",
+            Country::AU => "Australia",
+            Country::AT => "Austria",
+            Country::AZ => "Azerbaijan",
+            Country::BS => "Bahamas",
+            Country::BH => "Bahrain",
+            Country::BD => "Bangladesh",
+            Country::BB => "Barbados",
+            Country::BY => "Belarus",
+            Country::BE => "Belgium",
+            Country::BZ => "Belize",
+            Country::BJ => "Benin",
+            Country::BM => "Bermuda",
+            Country::BT => "Bhutan",
+            Country::BO => "Bolivia (Plurinational State of)",
+            Country::BQ => "Bonaire, Sint Eustatius and Saba",
+            Country::BA => "Bosnia and Herzegovina",
+            Country::BW => "Botswana",
+            Country::BV => "Bouvet Island",
+            Country::BR => "Brazil",
+            Country::IO => "British Indian Ocean Territory",
+            Country::BN => "Brunei Darussalam",
+            Country::BG => "Bulgaria",
+            Country::BF => "Burkina Faso",
+            Country::BI => "Burundi",
+            Country::KH => "Cambodia",
+            Country::CM => "Cameroon",
+            Country::CA => "Canada",
+            Country::CV => "Cabo Verde",
+            Country::KY => "Cayman Islands",
+            Country::CF => "Central African Republic",
+            Country::TD => "Chad",
+            Country::CL => "Chile",
+            Country::CN => "China",
+            Country::CX => "Christmas Island",
+            Country::CC => "Cocos (Keeling) Islands",
+            Country::CO => "Colombia",
+            Country::KM => "Comoros",
+            Country::CG => "Congo",
+            Country::CD => "Congo (Democratic Republic of the)",
+            Country::CK => "Cook Islands",
+            Country::CR => "Costa Rica",
+            Country::CI => "Côte d'Ivoire",
+            Country::HR => "Croatia",
+            Country::CU => "Cuba",
+            Country::CW => "Curaçao",
+            Country::CY => "Cyprus",
+            Country::CZ => "Czech Republic",
+            Country::DK => "Denmark",
+            Country::DJ => "Djibouti",
+            Country::DM => "Dominica",
+            Country::DO => "Dominican Republic",
+            Country::EC => "Ecuador",
+            Country::EG => "Egypt",
+            Country::SV => "El Salvador",
+            Country::GQ => "Equatorial Guinea",
+            Country::ER => "Eritrea",
+            Country::EE => "Estonia",
+            Country::ET => "Ethiopia",
+            Country::FK => "Falkland Islands (Malvinas)",
+            Country::FO => "Faroe Islands",
+            Country::FJ => "Fiji",
+            Country::FI => "Finland",
+            Country::FR => "France",
+            Country::GF => "French Guiana",
+            Country::PF => "French Polynesia",
+            Country::TF => "French Southern Territories",
+            Country::GA => "Gabon",
+            Country::GM => "Gambia",
+            Country::GE => "Georgia",
+            Country::DE => "Germany",
+            Country::GH => "Ghana",
+            Country::GI => "Gibraltar",
+            Country::GR => "Greece",
+            Country::GL => "Greenland",
+            Country::GD => "Grenada",
+            Country::GP => "Guadeloupe",
+            Country::GU => "Guam",
+            Country::GT => "Guatemala",
+            Country::GG => "Guernsey",
+            Country::GN => "Guinea",
+            Country::GW => "Guinea-Bissau",
+            Country::GY => "Guyana",
+            Country::HT => "Haiti",
+            Country::HM => "Heard Island and McDonald Islands",
+            Country::VA => "Holy See",
+            Country::HN => "Honduras",
+            Country::HK => "Hong Kong",
+            Country::HU => "Hungary",
+            Country::IS => "Iceland",
+            Country::IN => "India",
+            Country::ID => "Indonesia",
+            Country::IR => "Iran (Islamic Republic of)",
+            Country::IQ => "Iraq",
+            Country::IE => "Ireland",
+            Country::IM => "Isle of Man",
+            Country::IL => "Israel",
+            Country::IT => "Italy",
+            Country::JM => "Jamaica",
+            Country::JP => "Japan",
+            Country::JE => "Jersey",
+            Country::JO => "Jordan",
+            Country::KZ => "Kazakhstan",
+            Country::KE => "Kenya",
+            Country::KI => "Kiribati",
+            Country::KP => "Korea (Democratic People's Republic of)",
+            Country::KR => "Korea (Republic of)",
+            Country::KW => "Kuwait",
+            Country::KG => "Kyrgyzstan",
+            Country::LA => "Lao People's Democratic Republic",
+            Country::LV => "Latvia",
+            Country::LB => "Lebanon",
+            Country::LS => "Lesotho",
+            Country::LR => "Liberia",
+            Country::LY => "Libya",
+            Country::LI => "Liechtenstein",
+            Country::LT => "Lithuania",
+            Country::LU => "Luxembourg",
+            Country::MO => "Macao",
+            Country::MK => "Macedonia (the former Yugoslav Republic of)",
+            Country::MG => "Madagascar",
+            Country::MW => "Malawi",
+            Country::MY => "Malaysia",
+            Country::MV => "Maldives",
+            Country::ML => "Mali",
+            Country::MT => "Malta",
+            Country::MH => "Marshall Islands",
+            Country::MQ => "Martinique",
+            Country::MR => "Mauritania",
+            Country::MU => "Mauritius",
+            Country::YT => "Mayotte",
+            Country::MX => "Mexico",
+            Country::FM => "Micronesia (Federated States of)",
+            Country::MD => "Moldova (Republic of)",
+            Country::MC => "Monaco",
+            Country::MN => "Mongolia",
+            Country::ME => "Montenegro",
+            Country::MS => "Montserrat",
+            Country::MA => "Morocco",
+            Country::MZ => "Mozambique",
+            Country::MM => "Myanmar",
+            Country::NA => "Namibia",
+            Country::NR => "Nauru",
+            Country::NP => "Nepal",
+            Country::NL => "Netherlands",
+            Country::NC => "New Caledonia",
+            Country::NZ => "New Zealand",
+            Country::NI => "Nicaragua",
+            Country::NE => "Niger",
+            Country::NG => "Nigeria",
+            Country::NU => "Niue",
+            Country::NF => "Norfolk Island",
+            Country::MP => "Northern Mariana Islands",
+            Country::NO => "Norway",
+            Country::OM => "Oman",
+            Country::PK => "Pakistan",
+            Country::PW => "Palau",
+            Country::PS => "Palestine, State of",
+            Country::PA => "Panama",
+            Country::PG => "Papua New Guinea",
+            Country::PY => "Paraguay",
+            Country::PE => "Peru",
+            Country::PH => "Philippines",
+            Country::PN => "Pitcairn",
+            Country::PL => "Poland",
+            Country::PT => "Portugal",
+            Country::PR => "Puerto Rico",
+            Country::QA => "Qatar",
+            Country::RE => "Réunion",
+            Country::RO => "Romania",
+            Country::RU => "Russian Federation",
+            Country::RW => "Rwanda",
+            Country::BL => "Saint Barthélemy",
+            Country::SH => "Saint Helena, Ascension and Tristan da Cunha",
+            Country::KN => "Saint Kitts and Nevis",
+            Country::LC => "Saint Lucia",
+            Country::MF => "Saint Martin (French part)",
+            Country::PM => "Saint Pierre and Miquelon",
+            Country::VC => "Saint Vincent and the Grenadines",
+            Country::WS => "Samoa",
+            Country::SM => "San Marino",
+            Country::ST => "Sao Tome and Principe",
+            Country::SA => "Saudi Arabia",
+            Country::SN => "Senegal",
+            Country::RS => "Serbia",
+            Country::SC => "Seychelles",
+            Country::SL => "Sierra Leone",
+            Country::SG => "Singapore",
+            Country::SX => "Sint Maarten (Dutch part)",
+            Country::SK => "Slovakia",
+            Country::SI => "Slovenia",
+            Country::SB => "Solomon Islands",
+            Country::SO => "Somalia",
+            Country::ZA => "South Africa",
+            Country::GS => "South Georgia and the South Sandwich Islands",
+            Country::SS => "South Sudan",
+            Country::ES => "Spain",
+            Country::LK => "Sri Lanka",
+            Country::SD => "Sudan",
+            Country::SR => "Suriname",
+            Country::SJ => "Svalbard and Jan Mayen",
+            Country::SZ => "Swaziland",
+            Country::SE => "Sweden",
+            Country::CH => "Switzerland",
+            Country::SY => "Syrian Arab Republic",
+            Country::TW => "Taiwan, Province of China",
+            Country::TJ => "Tajikistan",
+            Country::TZ => "Tanzania, United Republic of",
+            Country::TH => "Thailand",
+            Country::TL => "Timor-Leste",
+            Country::TG => "Togo",
+            Country::TK => "Tokelau",
+            Country::TO => "Tonga",
+            Country::TT => "Trinidad and Tobago",
+            Country::TN => "Tunisia",
+            Country::TR => "Turkey",
+            Country::TM => "Turkmenistan",
+            Country::TC => "Turks and Caicos Islands",
+            Country::TV => "Tuvalu",
+            Country::UG => "Uganda",
+            Country::UA => "Ukraine",
+            Country::AE => "United Arab Emirates",
+            Country::GB => "United Kingdom of Great Britain and Northern Ireland",
+            Country::US => "United States of America",
+            Country::UM => "United States Minor Outlying Islands",
+            Country::UY => "Uruguay",
+            Country::UZ => "Uzbekistan",
+            Country::VU => "Vanuatu",
+            Country::VE => "Venezuela (Bolivarian Republic of)",
+            Country::VN => "Viet Nam",
+            Country::VG => "Virgin Islands (British)",
+            Country::VI => "Virgin Islands (U.S.)",
+            Country::WF => "Wallis and Futuna",
+            Country::EH => "Western Sahara",
+            Country::YE => "Yemen",
+            Country::ZM => "Zambia",
+            Country::ZW => "Zimbabwe",
+            Country::EU => "Europe",
+            Country::ZZ => "Unknown or unspecified country",
+            Country::AP => "Asia Pacific",
+        }
+    }
+
+    /// Other names in use, e.g. `Ivory Coast` for `CI`, see `Country::parse_lenient`.
+    pub fn common_names(&self) -> &'static [&'static str] {
+        match *self {
+            Country::BO => &["Bolivia"],
+            Country::BQ => &["Caribbean Netherlands"],
+            Country::BN => &["Brunei"],
+            Country::CV => &["Cape Verde"],
+            Country::CC => &["Cocos Islands", "Keeling Islands"],
+            Country::CG => &["Republic of the Congo", "Congo-Brazzaville"],
+            Country::CD => &["Democratic Republic of the Congo", "DR Congo", "Congo-Kinshasa"],
+            Country::CI => &["Ivory Coast"],
+            Country::CZ => &["Czechia"],
+            Country::FK => &["Falkland Islands"],
+            Country::VA => &["Vatican", "Vatican City"],
+            Country::IR => &["Iran"],
+            Country::KP => &["North Korea"],
+            Country::KR => &["South Korea"],
+            Country::LA => &["Laos"],
+            Country::MO => &["Macau"],
+            Country::MK => &["North Macedonia", "Macedonia"],
+            Country::FM => &["Micronesia"],
+            Country::MD => &["Moldova"],
+            Country::MM => &["Burma"],
+            Country::NL => &["Holland"],
+            Country::PS => &["Palestine"],
+            Country::RU => &["Russia"],
+            Country::SH => &["Saint Helena"],
+            Country::MF => &["Saint Martin"],
+            Country::ST => &["São Tomé and Príncipe"],
+            Country::SX => &["Sint Maarten"],
+            Country::SZ => &["Eswatini"],
+            Country::SY => &["Syria"],
+            Country::TW => &["Taiwan"],
+            Country::TZ => &["Tanzania"],
+            Country::TL => &["East Timor"],
+            Country::TR => &["Türkiye"],
+            Country::GB => &["United Kingdom", "UK", "Great Britain", "Britain"],
+            Country::US => &["United States", "America"],
+            Country::VE => &["Venezuela"],
+            Country::VN => &["Vietnam"],
+            Country::VG => &["British Virgin Islands"],
+            Country::VI => &["US Virgin Islands", "United States Virgin Islands"],
+            Country::EU => &["European Union"],
+            _ => &[],
+        }
+    }
+
+    /// ISO 3166-1 alpha-3 code, e.g. `USA`, `None` for the RIR codes outside ISO 3166-1.
+    pub fn alpha3(&self) -> Option<&'static str> {
+        match *self {
+            Country::AF => Some("AFG"),
+            Country::AX => Some("ALA"),
+            Country::AL => Some("ALB"),
+            Country::DZ => Some("DZA"),
+            Country::AS => Some("ASM"),
+            Country::AD => Some("AND"),
+            Country::AO => Some("AGO"),
+            Country::AI => Some("AIA"),
+            Country::AQ => Some("ATA"),
+            Country::AG => Some("ATG"),
+            Country::AR => Some("ARG"),
+            Country::AM => Some("ARM"),
+            Country::AW => Some("ABW"),
+            Country::AU => Some("AUS"),
+            Country::AT => Some("AUT"),
+            Country::AZ => Some("AZE"),
+            Country::BS => Some("BHS"),
+            Country::BH => Some("BHR"),
+            Country::BD => Some("BGD"),
+            Country::BB => Some("BRB"),
+            Country::BY => Some("BLR"),
+            Country::BE => Some("BEL"),
+            Country::BZ => Some("BLZ"),
+            Country::BJ => Some("BEN"),
+            Country::BM => Some("BMU"),
+            Country::BT => Some("BTN"),
+            Country::BO => Some("BOL"),
+            Country::BQ => Some("BES"),
+            Country::BA => Some("BIH"),
+            Country::BW => Some("BWA"),
+            Country::BV => Some("BVT"),
+            Country::BR => Some("BRA"),
+            Country::IO => Some("IOT"),
+            Country::BN => Some("BRN"),
+            Country::BG => Some("BGR"),
+            Country::BF => Some("BFA"),
+            Country::BI => Some("BDI"),
+            Country::KH => Some("KHM"),
+            Country::CM => Some("CMR"),
+            Country::CA => Some("CAN"),
+            Country::CV => Some("CPV"),
+            Country::KY => Some("CYM"),
+            Country::CF => Some("CAF"),
+            Country::TD => Some("TCD"),
+            Country::CL => Some("CHL"),
+            Country::CN => Some("CHN"),
+            Country::CX => Some("CXR"),
+            Country::CC => Some("CCK"),
+            Country::CO => Some("COL"),
+            Country::KM => Some("COM"),
+            Country::CG => Some("COG"),
+            Country::CD => Some("COD"),
+            Country::CK => Some("COK"),
+            Country::CR => Some("CRI"),
+            Country::CI => Some("CIV"),
+            Country::HR => Some("HRV"),
+            Country::CU => Some("CUB"),
+            Country::CW => Some("CUW"),
+            Country::CY => Some("CYP"),
+            Country::CZ => Some("CZE"),
+            Country::DK => Some("DNK"),
+            Country::DJ => Some("DJI"),
+            Country::DM => Some("DMA"),
+            Country::DO => Some("DOM"),
+            Country::EC => Some("ECU"),
+            Country::EG => Some("EGY"),
+            Country::SV => Some("SLV"),
+            Country::GQ => Some("GNQ"),
+            Country::ER => Some("ERI"),
+            Country::EE => Some("EST"),
+            Country::ET => Some("ETH"),
+            Country::FK => Some("FLK"),
+            Country::FO => Some("FRO"),
+            Country::FJ => Some("FJI"),
+            Country::FI => Some("FIN"),
+            Country::FR => Some("FRA"),
+            Country::GF => Some("GUF"),
+            Country::PF => Some("PYF"),
+            Country::TF => Some("ATF"),
+            Country::GA => Some("GAB"),
+            Country::GM => Some("GMB"),
+            Country::GE => Some("GEO"),
+            Country::DE => Some("DEU"),
+            Country::GH => Some("GHA"),
+            Country::GI => Some("GIB"),
+            Country::GR => Some("GRC"),
+            Country::GL => Some("GRL"),
+            Country::GD => Some("GRD"),
+            Country::GP => Some("GLP"),
+            Country::GU => Some("GUM"),
+            Country::GT => Some("GTM"),
+            Country::GG => Some("GGY"),
+            Country::GN => Some("GIN"),
+            Country::GW => Some("GNB"),
+            Country::GY => Some("GUY"),
+            Country::HT => Some("HTI"),
+            Country::HM => Some("HMD"),
+            Country::VA => Some("VAT"),
+            Country::HN => Some("HND"),
+            Country::HK => Some("HKG"),
+            Country::HU => Some("HUN"),
+            Country::IS => Some("ISL"),
+            Country::IN => Some("IND"),
+            Country::ID => Some("IDN"),
+            Country::IR => Some("IRN"),
+            Country::IQ => Some("IRQ"),
+            Country::IE => Some("IRL"),
+            Country::IM => Some("IMN"),
+            Country::IL => Some("ISR"),
+            Country::IT => Some("ITA"),
+            Country::JM => Some("JAM"),
+            Country::JP => Some("JPN"),
+            Country::JE => Some("JEY"),
+            Country::JO => Some("JOR"),
+            Country::KZ => Some("KAZ"),
+            Country::KE => Some("KEN"),
+            Country::KI => Some("KIR"),
+            Country::KP => Some("PRK"),
+            Country::KR => Some("KOR"),
+            Country::KW => Some("KWT"),
+            Country::KG => Some("KGZ"),
+            Country::LA => Some("LAO"),
+            Country::LV => Some("LVA"),
+            Country::LB => Some("LBN"),
+            Country::LS => Some("LSO"),
+            Country::LR => Some("LBR"),
+            Country::LY => Some("LBY"),
+            Country::LI => Some("LIE"),
+            Country::LT => Some("LTU"),
+            Country::LU => Some("LUX"),
+            Country::MO => Some("MAC"),
+            Country::MK => Some("MKD"),
+            Country::MG => Some("MDG"),
+            Country::MW => Some("MWI"),
+            Country::MY => Some("MYS"),
+            Country::MV => Some("MDV"),
+            Country::ML => Some("MLI"),
+            Country::MT => Some("MLT"),
+            Country::MH => Some("MHL"),
+            Country::MQ => Some("MTQ"),
+            Country::MR => Some("MRT"),
+            Country::MU => Some("MUS"),
+            Country::YT => Some("MYT"),
+            Country::MX => Some("MEX"),
+            Country::FM => Some("FSM"),
+            Country::MD => Some("MDA"),
+            Country::MC => Some("MCO"),
+            Country::MN => Some("MNG"),
+            Country::ME => Some("MNE"),
+            Country::MS => Some("MSR"),
+            Country::MA => Some("MAR"),
+            Country::MZ => Some("MOZ"),
+            Country::MM => Some("MMR"),
+            Country::NA => Some("NAM"),
+            Country::NR => Some("NRU"),
+            Country::NP => Some("NPL"),
+            Country::NL => Some("NLD"),
+            Country::NC => Some("NCL"),
+            Country::NZ => Some("NZL"),
+            Country::NI => Some("NIC"),
+            Country::NE => Some("NER"),
+            Country::NG => Some("NGA"),
+            Country::NU => Some("NIU"),
+            Country::NF => Some("NFK"),
+            Country::MP => Some("MNP"),
+            Country::NO => Some("NOR"),
+            Country::OM => Some("OMN"),
+            Country::PK => Some("PAK"),
+            Country::PW => Some("PLW"),
+            Country::PS => Some("PSE"),
+            Country::PA => Some("PAN"),
+            Country::PG => Some("PNG"),
+            Country::PY => Some("PRY"),
+            Country::PE => Some("PER"),
+            Country::PH => Some("PHL"),
+            Country::PN => Some("PCN"),
+            Country::PL => Some("POL"),
+            Country::PT => Some("PRT"),
+            Country::PR => Some("PRI"),
+            Country::QA => Some("QAT"),
+            Country::RE => Some("REU"),
+            Country::RO => Some("ROU"),
+            Country::RU => Some("RUS"),
+            Country::RW => Some("RWA"),
+            Country::BL => Some("BLM"),
+            Country::SH => Some("SHN"),
+            Country::KN => Some("KNA"),
+            Country::LC => Some("LCA"),
+            Country::MF => Some("MAF"),
+            Country::PM => Some("SPM"),
+            Country::VC => Some("VCT"),
+            Country::WS => Some("WSM"),
+            Country::SM => Some("SMR"),
+            Country::ST => Some("STP"),
+            Country::SA => Some("SAU"),
+            Country::SN => Some("SEN"),
+            Country::RS => Some("SRB"),
+            Country::SC => Some("SYC"),
+            Country::SL => Some("SLE"),
+            Country::SG => Some("SGP"),
+            Country::SX => Some("SXM"),
+            Country::SK => Some("SVK"),
+            Country::SI => Some("SVN"),
+            Country::SB => Some("SLB"),
+            Country::SO => Some("SOM"),
+            Country::ZA => Some("ZAF"),
+            Country::GS => Some("SGS"),
+            Country::SS => Some("SSD"),
+            Country::ES => Some("ESP"),
+            Country::LK => Some("LKA"),
+            Country::SD => Some("SDN"),
+            Country::SR => Some("SUR"),
+            Country::SJ => Some("SJM"),
+            Country::SZ => Some("SWZ"),
+            Country::SE => Some("SWE"),
+            Country::CH => Some("CHE"),
+            Country::SY => Some("SYR"),
+            Country::TW => Some("TWN"),
+            Country::TJ => Some("TJK"),
+            Country::TZ => Some("TZA"),
+            Country::TH => Some("THA"),
+            Country::TL => Some("TLS"),
+            Country::TG => Some("TGO"),
+            Country::TK => Some("TKL"),
+            Country::TO => Some("TON"),
+            Country::TT => Some("TTO"),
+            Country::TN => Some("TUN"),
+            Country::TR => Some("TUR"),
+            Country::TM => Some("TKM"),
+            Country::TC => Some("TCA"),
+            Country::TV => Some("TUV"),
+            Country::UG => Some("UGA"),
+            Country::UA => Some("UKR"),
+            Country::AE => Some("ARE"),
+            Country::GB => Some("GBR"),
+            Country::US => Some("USA"),
+            Country::UM => Some("UMI"),
+            Country::UY => Some("URY"),
+            Country::UZ => Some("UZB"),
+            Country::VU => Some("VUT"),
+            Country::VE => Some("VEN"),
+            Country::VN => Some("VNM"),
+            Country::VG => Some("VGB"),
+            Country::VI => Some("VIR"),
+            Country::WF => Some("WLF"),
+            Country::EH => Some("ESH"),
+            Country::YE => Some("YEM"),
+            Country::ZM => Some("ZMB"),
+            Country::ZW => Some("ZWE"),
+            Country::EU | Country::ZZ | Country::AP => None,
+        }
+    }
+
+    /// ISO 3166-1 numeric code, e.g. `840`, `None` for the RIR codes outside ISO 3166-1.
+    pub fn numeric(&self) -> Option<u16> {
+        match *self {
+            Country::AF => Some(4),
+            Country::AX => Some(248),
+            Country::AL => Some(8),
+            Country::DZ => Some(12),
+            Country::AS => Some(16),
+            Country::AD => Some(20),
+            Country::AO => Some(24),
+            Country::AI => Some(660),
+            Country::AQ => Some(10),
+            Country::AG => Some(28),
+            Country::AR => Some(32),
+            Country::AM => Some(51),
+            Country::AW => Some(533),
+            Country::AU => Some(36),
+            Country::AT => Some(40),
+            Country::AZ => Some(31),
+            Country::BS => Some(44),
+            Country::BH => Some(48),
+            Country::BD => Some(50),
+            Country::BB => Some(52),
+            Country::BY => Some(112),
+            Country::BE => Some(56),
+            Country::BZ => Some(84),
+            Country::BJ => Some(204),
+            Country::BM => Some(60),
+            Country::BT => Some(64),
+            Country::BO => Some(68),
+            Country::BQ => Some(535),
+            Country::BA => Some(70),
+            Country::BW => Some(72),
+            Country::BV => Some(74),
+            Country::BR => Some(76),
+            Country::IO => Some(86),
+            Country::BN => Some(96),
+            Country::BG => Some(100),
+            Country::BF => Some(854),
+            Country::BI => Some(108),
+            Country::KH => Some(116),
+            Country::CM => Some(120),
+            Country::CA => Some(124),
+            Country::CV => Some(132),
+            Country::KY => Some(136),
+            Country::CF => Some(140),
+            Country::TD => Some(148),
+            Country::CL => Some(152),
+            Country::CN => Some(156),
+            Country::CX => Some(162),
+            Country::CC => Some(166),
+            Country::CO => Some(170),
+            Country::KM => Some(174),
+            Country::CG => Some(178),
+            Country::CD => Some(180),
+            Country::CK => Some(184),
+            Country::CR => Some(188),
+            Country::CI => Some(384),
+            Country::HR => Some(191),
+            Country::CU => Some(192),
+            Country::CW => Some(531),
+            Country::CY => Some(196),
+            Country::CZ => Some(203),
+            Country::DK => Some(208),
+            Country::DJ => Some(262),
+            Country::DM => Some(212),
+            Country::DO => Some(214),
+            Country::EC => Some(218),
+            Country::EG => Some(818),
+            Country::SV => Some(222),
+            Country::GQ => Some(226),
+            Country::ER => Some(232),
+            Country::EE => Some(233),
+            Country::ET => Some(231),
+            Country::FK => Some(238),
+            Country::FO => Some(234),
+            Country::FJ => Some(242),
+            Country::FI => Some(246),
+            Country::FR => Some(250),
+            Country::GF => Some(254),
+            Country::PF => Some(258),
+            Country::TF => Some(260),
+            Country::GA => Some(266),
+            Country::GM => Some(270),
+            Country::GE => Some(268),
+            Country::DE => Some(276),
+            Country::GH => Some(288),
+            Country::GI => Some(292),
+            Country::GR => Some(300),
+            Country::GL => Some(304),
+            Country::GD => Some(308),
+            Country::GP => Some(312),
+            Country::GU => Some(316),
+            Country::GT => Some(320),
+            Country::GG => Some(831),
+            Country::GN => Some(324),
+            Country::GW => Some(624),
+            Country::GY => Some(328),
+            Country::HT => Some(332),
+            Country::HM => Some(334),
+            Country::VA => Some(336),
+            Country::HN => Some(340),
+            Country::HK => Some(344),
+            Country::HU => Some(348),
+            Country::IS => Some(352),
+            Country::IN => Some(356),
+            Country::ID => Some(360),
+            Country::IR => Some(364),
+            Country::IQ => Some(368),
+            Country::IE => Some(372),
+            Country::IM => Some(833),
+            Country::IL => Some(376),
+            Country::IT => Some(380),
+            Country::JM => Some(388),
+            Country::JP => Some(392),
+            Country::JE => Some(832),
+            Country::JO => Some(400),
+            Country::KZ => Some(398),
+            Country::KE => Some(404),
+            Country::KI => Some(296),
+            Country::KP => Some(408),
+            Country::KR => Some(410),
+            Country::KW => Some(414),
+            Country::KG => Some(417),
+            Country::LA => Some(418),
+            Country::LV => Some(428),
+            Country::LB => Some(422),
+            Country::LS => Some(426),
+            Country::LR => Some(430),
+            Country::LY => Some(434),
+            Country::LI => Some(438),
+            Country::LT => Some(440),
+            Country::LU => Some(442),
+            Country::MO => Some(446),
+            Country::MK => Some(807),
+            Country::MG => Some(450),
+            Country::MW => Some(454),
+            Country::MY => Some(458),
+            Country::MV => Some(462),
+            Country::ML => Some(466),
+            Country::MT => Some(470),
+            Country::MH => Some(584),
+            Country::MQ => Some(474),
+            Country::MR => Some(478),
+            Country::MU => Some(480),
+            Country::YT => Some(175),
+            Country::MX => Some(484),
+            Country::FM => Some(583),
+            Country::MD => Some(498),
+            Country::MC => Some(492),
+            Country::MN => Some(496),
+            Country::ME => Some(499),
+            Country::MS => Some(500),
+            Country::MA => Some(504),
+            Country::MZ => Some(508),
+            Country::MM => Some(104),
+            Country::NA => Some(516),
+            Country::NR => Some(520),
+            Country::NP => Some(524),
+            Country::NL => Some(528),
+            Country::NC => Some(540),
+            Country::NZ => Some(554),
+            Country::NI => Some(558),
+            Country::NE => Some(562),
+            Country::NG => Some(566),
+            Country::NU => Some(570),
+            Country::NF => Some(574),
+            Country::MP => Some(580),
+            Country::NO => Some(578),
+            Country::OM => Some(512),
+            Country::PK => Some(586),
+            Country::PW => Some(585),
+            Country::PS => Some(275),
+            Country::PA => Some(591),
+            Country::PG => Some(598),
+            Country::PY => Some(600),
+            Country::PE => Some(604),
+            Country::PH => Some(608),
+            Country::PN => Some(612),
+            Country::PL => Some(616),
+            Country::PT => Some(620),
+            Country::PR => Some(630),
+            Country::QA => Some(634),
+            Country::RE => Some(638),
+            Country::RO => Some(642),
+            Country::RU => Some(643),
+            Country::RW => Some(646),
+            Country::BL => Some(652),
+            Country::SH => Some(654),
+            Country::KN => Some(659),
+            Country::LC => Some(662),
+            Country::MF => Some(663),
+            Country::PM => Some(666),
+            Country::VC => Some(670),
+            Country::WS => Some(882),
+            Country::SM => Some(674),
+            Country::ST => Some(678),
+            Country::SA => Some(682),
+            Country::SN => Some(686),
+            Country::RS => Some(688),
+            Country::SC => Some(690),
+            Country::SL => Some(694),
+            Country::SG => Some(702),
+            Country::SX => Some(534),
+            Country::SK => Some(703),
+            Country::SI => Some(705),
+            Country::SB => Some(90),
+            Country::SO => Some(706),
+            Country::ZA => Some(710),
+            Country::GS => Some(239),
+            Country::SS => Some(728),
+            Country::ES => Some(724),
+            Country::LK => Some(144),
+            Country::SD => Some(729),
+            Country::SR => Some(740),
+            Country::SJ => Some(744),
+            Country::SZ => Some(748),
+            Country::SE => Some(752),
+            Country::CH => Some(756),
+            Country::SY => Some(760),
+            Country::TW => Some(158),
+            Country::TJ => Some(762),
+            Country::TZ => Some(834),
+            Country::TH => Some(764),
+            Country::TL => Some(626),
+            Country::TG => Some(768),
+            Country::TK => Some(772),
+            Country::TO => Some(776),
+            Country::TT => Some(780),
+            Country::TN => Some(788),
+            Country::TR => Some(792),
+            Country::TM => Some(795),
+            Country::TC => Some(796),
+            Country::TV => Some(798),
+            Country::UG => Some(800),
+            Country::UA => Some(804),
+            Country::AE => Some(784),
+            Country::GB => Some(826),
+            Country::US => Some(840),
+            Country::UM => Some(581),
+            Country::UY => Some(858),
+            Country::UZ => Some(860),
+            Country::VU => Some(548),
+            Country::VE => Some(862),
+            Country::VN => Some(704),
+            Country::VG => Some(92),
+            Country::VI => Some(850),
+            Country::WF => Some(876),
+            Country::EH => Some(732),
+            Country::YE => Some(887),
+            Country::ZM => Some(894),
+            Country::ZW => Some(716),
+            Country::EU | Country::ZZ | Country::AP => None,
+        }
+    }
+
+    /// The smallest UN M49 area containing the country, see `Country::region`.
+    pub(crate) fn area(&self) -> Option<Region> {
+        match *self {
+            Country::AF => Some(Region::SouthernAsia),
+            Country::AX => Some(Region::NorthernEurope),
+            Country::AL => Some(Region::SouthernEurope),
+            Country::DZ => Some(Region::NorthernAfrica),
+            Country::AS => Some(Region::Polynesia),
+            Country::AD => Some(Region::SouthernEurope),
+            Country::AO => Some(Region::MiddleAfrica),
+            Country::AI => Some(Region::Caribbean),
+            Country::AG => Some(Region::Caribbean),
+            Country::AR => Some(Region::SouthAmerica),
+            Country::AM => Some(Region::WesternAsia),
+            Country::AW => Some(Region::Caribbean),
+            Country::AU => Some(Region::AustraliaAndNewZealand),
+            Country::AT => Some(Region::WesternEurope),
+            Country::AZ => Some(Region::WesternAsia),
+            Country::BS => Some(Region::Caribbean),
+            Country::BH => Some(Region::WesternAsia),
+            Country::BD => Some(Region::SouthernAsia),
+            Country::BB => Some(Region::Caribbean),
+            Country::BY => Some(Region::EasternEurope),
+            Country::BE => Some(Region::WesternEurope),
+            Country::BZ => Some(Region::CentralAmerica),
+            Country::BJ => Some(Region::WesternAfrica),
+            Country::BM => Some(Region::NorthernAmerica),
+            Country::BT => Some(Region::SouthernAsia),
+            Country::BO => Some(Region::SouthAmerica),
+            Country::BQ => Some(Region::Caribbean),
+            Country::BA => Some(Region::SouthernEurope),
+            Country::BW => Some(Region::SouthernAfrica),
+            Country::BV => Some(Region::SouthAmerica),
+            Country::BR => Some(Region::SouthAmerica),
+            Country::IO => Some(Region::EasternAfrica),
+            Country::BN => Some(Region::SouthEasternAsia),
+            Country::BG => Some(Region::EasternEurope),
+            Country::BF => Some(Region::WesternAfrica),
+            Country::BI => Some(Region::EasternAfrica),
+            Country::KH => Some(Region::SouthEasternAsia),
+            Country::CM => Some(Region::MiddleAfrica),
+            Country::CA => Some(Region::NorthernAmerica),
+            Country::CV => Some(Region::WesternAfrica),
+            Country::KY => Some(Region::Caribbean),
+            Country::CF => Some(Region::MiddleAfrica),
+            Country::TD => Some(Region::MiddleAfrica),
+            Country::CL => Some(Region::SouthAmerica),
+            Country::CN => Some(Region::EasternAsia),
+            Country::CX => Some(Region::AustraliaAndNewZealand),
+            Country::CC => Some(Region::AustraliaAndNewZealand),
+            Country::CO => Some(Region::SouthAmerica),
+            Country::KM => Some(Region::EasternAfrica),
+            Country::CG => Some(Region::MiddleAfrica),
+            Country::CD => Some(Region::MiddleAfrica),
+            Country::CK => Some(Region::Polynesia),
+            Country::CR => Some(Region::CentralAmerica),
+            Country::CI => Some(Region::WesternAfrica),
+            Country::HR => Some(Region::SouthernEurope),
+            Country::CU => Some(Region::Caribbean),
+            Country::CW => Some(Region::Caribbean),
+            Country::CY => Some(Region::WesternAsia),
+            Country::CZ => Some(Region::EasternEurope),
+            Country::DK => Some(Region::NorthernEurope),
+            Country::DJ => Some(Region::EasternAfrica),
+            Country::DM => Some(Region::Caribbean),
+            Country::DO => Some(Region::Caribbean),
+            Country::EC => Some(Region::SouthAmerica),
+            Country::EG => Some(Region::NorthernAfrica),
+            Country::SV => Some(Region::CentralAmerica),
+            Country::GQ => Some(Region::MiddleAfrica),
+            Country::ER => Some(Region::EasternAfrica),
+            Country::EE => Some(Region::NorthernEurope),
+            Country::ET => Some(Region::EasternAfrica),
+            Country::FK => Some(Region::SouthAmerica),
+            Country::FO => Some(Region::NorthernEurope),
+            Country::FJ => Some(Region::Melanesia),
+            Country::FI => Some(Region::NorthernEurope),
+            Country::FR => Some(Region::WesternEurope),
+            Country::GF => Some(Region::SouthAmerica),
+            Country::PF => Some(Region::Polynesia),
+            Country::TF => Some(Region::EasternAfrica),
+            Country::GA => Some(Region::MiddleAfrica),
+            Country::GM => Some(Region::WesternAfrica),
+            Country::GE => Some(Region::WesternAsia),
+            Country::DE => Some(Region::WesternEurope),
+            Country::GH => Some(Region::WesternAfrica),
+            Country::GI => Some(Region::SouthernEurope),
+            Country::GR => Some(Region::SouthernEurope),
+            Country::GL => Some(Region::NorthernAmerica),
+            Country::GD => Some(Region::Caribbean),
+            Country::GP => Some(Region::Caribbean),
+            Country::GU => Some(Region::Micronesia),
+            Country::GT => Some(Region::CentralAmerica),
+            Country::GG => Some(Region::ChannelIslands),
+            Country::GN => Some(Region::WesternAfrica),
+            Country::GW => Some(Region::WesternAfrica),
+            Country::GY => Some(Region::SouthAmerica),
+            Country::HT => Some(Region::Caribbean),
+            Country::HM => Some(Region::AustraliaAndNewZealand),
+            Country::VA => Some(Region::SouthernEurope),
+            Country::HN => Some(Region::CentralAmerica),
+            Country::HK => Some(Region::EasternAsia),
+            Country::HU => Some(Region::EasternEurope),
+            Country::IS => Some(Region::NorthernEurope),
+            Country::IN => Some(Region::SouthernAsia),
+            Country::ID => Some(Region::SouthEasternAsia),
+            Country::IR => Some(Region::SouthernAsia),
+            Country::IQ => Some(Region::WesternAsia),
+            Country::IE => Some(Region::NorthernEurope),
+            Country::IM => Some(Region::NorthernEurope),
+            Country::IL => Some(Region::WesternAsia),
+            Country::IT => Some(Region::SouthernEurope),
+            Country::JM => Some(Region::Caribbean),
+            Country::JP => Some(Region::EasternAsia),
+            Country::JE => Some(Region::ChannelIslands),
+            Country::JO => Some(Region::WesternAsia),
+            Country::KZ => Some(Region::CentralAsia),
+            Country::KE => Some(Region::EasternAfrica),
+            Country::KI => Some(Region::Micronesia),
+            Country::KP => Some(Region::EasternAsia),
+            Country::KR => Some(Region::EasternAsia),
+            Country::KW => Some(Region::WesternAsia),
+            Country::KG => Some(Region::CentralAsia),
+            Country::LA => Some(Region::SouthEasternAsia),
+            Country::LV => Some(Region::NorthernEurope),
+            Country::LB => Some(Region::WesternAsia),
+            Country::LS => Some(Region::SouthernAfrica),
+            Country::LR => Some(Region::WesternAfrica),
+            Country::LY => Some(Region::NorthernAfrica),
+            Country::LI => Some(Region::WesternEurope),
+            Country::LT => Some(Region::NorthernEurope),
+            Country::LU => Some(Region::WesternEurope),
+            Country::MO => Some(Region::EasternAsia),
+            Country::MK => Some(Region::SouthernEurope),
+            Country::MG => Some(Region::EasternAfrica),
+            Country::MW => Some(Region::EasternAfrica),
+            Country::MY => Some(Region::SouthEasternAsia),
+            Country::MV => Some(Region::SouthernAsia),
+            Country::ML => Some(Region::WesternAfrica),
+            Country::MT => Some(Region::SouthernEurope),
+            Country::MH => Some(Region::Micronesia),
+            Country::MQ => Some(Region::Caribbean),
+            Country::MR => Some(Region::WesternAfrica),
+            Country::MU => Some(Region::EasternAfrica),
+            Country::YT => Some(Region::EasternAfrica),
+            Country::MX => Some(Region::CentralAmerica),
+            Country::FM => Some(Region::Micronesia),
+            Country::MD => Some(Region::EasternEurope),
+            Country::MC => Some(Region::WesternEurope),
+            Country::MN => Some(Region::EasternAsia),
+            Country::ME => Some(Region::SouthernEurope),
+            Country::MS => Some(Region::Caribbean),
+            Country::MA => Some(Region::NorthernAfrica),
+            Country::MZ => Some(Region::EasternAfrica),
+            Country::MM => Some(Region::SouthEasternAsia),
+            Country::NA => Some(Region::SouthernAfrica),
+            Country::NR => Some(Region::Micronesia),
+            Country::NP => Some(Region::SouthernAsia),
+            Country::NL => Some(Region::WesternEurope),
+            Country::NC => Some(Region::Melanesia),
+            Country::NZ => Some(Region::AustraliaAndNewZealand),
+            Country::NI => Some(Region::CentralAmerica),
+            Country::NE => Some(Region::WesternAfrica),
+            Country::NG => Some(Region::WesternAfrica),
+            Country::NU => Some(Region::Polynesia),
+            Country::NF => Some(Region::AustraliaAndNewZealand),
+            Country::MP => Some(Region::Micronesia),
+            Country::NO => Some(Region::NorthernEurope),
+            Country::OM => Some(Region::WesternAsia),
+            Country::PK => Some(Region::SouthernAsia),
+            Country::PW => Some(Region::Micronesia),
+            Country::PS => Some(Region::WesternAsia),
+            Country::PA => Some(Region::CentralAmerica),
+            Country::PG => Some(Region::Melanesia),
+            Country::PY => Some(Region::SouthAmerica),
+            Country::PE => Some(Region::SouthAmerica),
+            Country::PH => Some(Region::SouthEasternAsia),
+            Country::PN => Some(Region::Polynesia),
+            Country::PL => Some(Region::EasternEurope),
+            Country::PT => Some(Region::SouthernEurope),
+            Country::PR => Some(Region::Caribbean),
+            Country::QA => Some(Region::WesternAsia),
+            Country::RE => Some(Region::EasternAfrica),
+            Country::RO => Some(Region::EasternEurope),
+            Country::RU => Some(Region::EasternEurope),
+            Country::RW => Some(Region::EasternAfrica),
+            Country::BL => Some(Region::Caribbean),
+            Country::SH => Some(Region::WesternAfrica),
+            Country::KN => Some(Region::Caribbean),
+            Country::LC => Some(Region::Caribbean),
+            Country::MF => Some(Region::Caribbean),
+            Country::PM => Some(Region::NorthernAmerica),
+            Country::VC => Some(Region::Caribbean),
+            Country::WS => Some(Region::Polynesia),
+            Country::SM => Some(Region::SouthernEurope),
+            Country::ST => Some(Region::MiddleAfrica),
+            Country::SA => Some(Region::WesternAsia),
+            Country::SN => Some(Region::WesternAfrica),
+            Country::RS => Some(Region::SouthernEurope),
+            Country::SC => Some(Region::EasternAfrica),
+            Country::SL => Some(Region::WesternAfrica),
+            Country::SG => Some(Region::SouthEasternAsia),
+            Country::SX => Some(Region::Caribbean),
+            Country::SK => Some(Region::EasternEurope),
+            Country::SI => Some(Region::SouthernEurope),
+            Country::SB => Some(Region::Melanesia),
+            Country::SO => Some(Region::EasternAfrica),
+            Country::ZA => Some(Region::SouthernAfrica),
+            Country::GS => Some(Region::SouthAmerica),
+            Country::SS => Some(Region::EasternAfrica),
+            Country::ES => Some(Region::SouthernEurope),
+            Country::LK => Some(Region::SouthernAsia),
+            Country::SD => Some(Region::NorthernAfrica),
+            Country::SR => Some(Region::SouthAmerica),
+            Country::SJ => Some(Region::NorthernEurope),
+            Country::SZ => Some(Region::SouthernAfrica),
+            Country::SE => Some(Region::NorthernEurope),
+            Country::CH => Some(Region::WesternEurope),
+            Country::SY => Some(Region::WesternAsia),
+            Country::TW => Some(Region::EasternAsia),
+            Country::TJ => Some(Region::CentralAsia),
+            Country::TZ => Some(Region::EasternAfrica),
+            Country::TH => Some(Region::SouthEasternAsia),
+            Country::TL => Some(Region::SouthEasternAsia),
+            Country::TG => Some(Region::WesternAfrica),
+            Country::TK => Some(Region::Polynesia),
+            Country::TO => Some(Region::Polynesia),
+            Country::TT => Some(Region::Caribbean),
+            Country::TN => Some(Region::NorthernAfrica),
+            Country::TR => Some(Region::WesternAsia),
+            Country::TM => Some(Region::CentralAsia),
+            Country::TC => Some(Region::Caribbean),
+            Country::TV => Some(Region::Polynesia),
+            Country::UG => Some(Region::EasternAfrica),
+            Country::UA => Some(Region::EasternEurope),
+            Country::AE => Some(Region::WesternAsia),
+            Country::GB => Some(Region::NorthernEurope),
+            Country::US => Some(Region::NorthernAmerica),
+            Country::UM => Some(Region::Micronesia),
+            Country::UY => Some(Region::SouthAmerica),
+            Country::UZ => Some(Region::CentralAsia),
+            Country::VU => Some(Region::Melanesia),
+            Country::VE => Some(Region::SouthAmerica),
+            Country::VN => Some(Region::SouthEasternAsia),
+            Country::VG => Some(Region::Caribbean),
+            Country::VI => Some(Region::Caribbean),
+            Country::WF => Some(Region::Polynesia),
+            Country::EH => Some(Region::NorthernAfrica),
+            Country::YE => Some(Region::WesternAsia),
+            Country::ZM => Some(Region::EasternAfrica),
+            Country::ZW => Some(Region::EasternAfrica),
+            Country::EU => Some(Region::Europe),
+            Country::AQ | Country::ZZ | Country::AP => None,
+        }
+    }
+
+    /// The RIR whose service region covers the country, `None` for `ZZ`.
+    pub fn registry(&self) -> Option<Registry> {
+        match *self {
+            Country::AF => Some(Registry::Apnic),
+            Country::AX => Some(Registry::Ripencc),
+            Country::AL => Some(Registry::Ripencc),
+            Country::DZ => Some(Registry::Afrinic),
+            Country::AS => Some(Registry::Apnic),
+            Country::AD => Some(Registry::Ripencc),
+            Country::AO => Some(Registry::Afrinic),
+            Country::AI => Some(Registry::Arin),
+            Country::AQ => Some(Registry::Arin),
+            Country::AG => Some(Registry::Arin),
+            Country::AR => Some(Registry::Lacnic),
+            Country::AM => Some(Registry::Ripencc),
+            Country::AW => Some(Registry::Lacnic),
+            Country::AU => Some(Registry::Apnic),
+            Country::AT => Some(Registry::Ripencc),
+            Country::AZ => Some(Registry::Ripencc),
+            Country::BS => Some(Registry::Arin),
+            Country::BH => Some(Registry::Ripencc),
+            Country::BD => Some(Registry::Apnic),
+            Country::BB => Some(Registry::Arin),
+            Country::BY => Some(Registry::Ripencc),
+            Country::BE => Some(Registry::Ripencc),
+            Country::BZ => Some(Registry::Lacnic),
+            Country::BJ => Some(Registry::Afrinic),
+            Country::BM => Some(Registry::Arin),
+            Country::BT => Some(Registry::Apnic),
+            Country::BO => Some(Registry::Lacnic),
+            Country::BQ => Some(Registry::Lacnic),
+            Country::BA => Some(Registry::Ripencc),
+            Country::BW => Some(Registry::Afrinic),
+            Country::BV => Some(Registry::Arin),
+            Country::BR => Some(Registry::Lacnic),
+            Country::IO => Some(Registry::Apnic),
+            Country::BN => Some(Registry::Apnic),
+            Country::BG => Some(Registry::Ripencc),
+            Country::BF => Some(Registry::Afrinic),
+            Country::BI => Some(Registry::Afrinic),
+            Country::KH => Some(Registry::Apnic),
+            Country::CM => Some(Registry::Afrinic),
+            Country::CA => Some(Registry::Arin),
+            Country::CV => Some(Registry::Afrinic),
+            Country::KY => Some(Registry::Arin),
+            Country::CF => Some(Registry::Afrinic),
+            Country::TD => Some(Registry::Afrinic),
+            Country::CL => Some(Registry::Lacnic),
+            Country::CN => Some(Registry::Apnic),
+            Country::CX => Some(Registry::Apnic),
+            Country::CC => Some(Registry::Apnic),
+            Country::CO => Some(Registry::Lacnic),
+            Country::KM => Some(Registry::Afrinic),
+            Country::CG => Some(Registry::Afrinic),
+            Country::CD => Some(Registry::Afrinic),
+            Country::CK => Some(Registry::Apnic),
+            Country::CR => Some(Registry::Lacnic),
+            Country::CI => Some(Registry::Afrinic),
+            Country::HR => Some(Registry::Ripencc),
+            Country::CU => Some(Registry::Lacnic),
+            Country::CW => Some(Registry::Lacnic),
+            Country::CY => Some(Registry::Ripencc),
+            Country::CZ => Some(Registry::Ripencc),
+            Country::DK => Some(Registry::Ripencc),
+            Country::DJ => Some(Registry::Afrinic),
+            Country::DM => Some(Registry::Arin),
+            Country::DO => Some(Registry::Lacnic),
+            Country::EC => Some(Registry::Lacnic),
+            Country::EG => Some(Registry::Afrinic),
+            Country::SV => Some(Registry::Lacnic),
+            Country::GQ => Some(Registry::Afrinic),
+            Country::ER => Some(Registry::Afrinic),
+            Country::EE => Some(Registry::Ripencc),
+            Country::ET => Some(Registry::Afrinic),
+            Country::FK => Some(Registry::Lacnic),
+            Country::FO => Some(Registry::Ripencc),
+            Country::FJ => Some(Registry::Apnic),
+            Country::FI => Some(Registry::Ripencc),
+            Country::FR => Some(Registry::Ripencc),
+            Country::GF => Some(Registry::Lacnic),
+            Country::PF => Some(Registry::Apnic),
+            Country::TF => Some(Registry::Apnic),
+            Country::GA => Some(Registry::Afrinic),
+            Country::GM => Some(Registry::Afrinic),
+            Country::GE => Some(Registry::Ripencc),
+            Country::DE => Some(Registry::Ripencc),
+            Country::GH => Some(Registry::Afrinic),
+            Country::GI => Some(Registry::Ripencc),
+            Country::GR => Some(Registry::Ripencc),
+            Country::GL => Some(Registry::Ripencc),
+            Country::GD => Some(Registry::Arin),
+            Country::GP => Some(Registry::Arin),
+            Country::GU => Some(Registry::Apnic),
+            Country::GT => Some(Registry::Lacnic),
+            Country::GG => Some(Registry::Ripencc),
+            Country::GN => Some(Registry::Afrinic),
+            Country::GW => Some(Registry::Afrinic),
+            Country::GY => Some(Registry::Lacnic),
+            Country::HT => Some(Registry::Lacnic),
+            Country::HM => Some(Registry::Arin),
+            Country::VA => Some(Registry::Ripencc),
+            Country::HN => Some(Registry::Lacnic),
+            Country::HK => Some(Registry::Apnic),
+            Country::HU => Some(Registry::Ripencc),
+            Country::IS => Some(Registry::Ripencc),
+            Country::IN => Some(Registry::Apnic),
+            Country::ID => Some(Registry::Apnic),
+            Country::IR => Some(Registry::Ripencc),
+            Country::IQ => Some(Registry::Ripencc),
+            Country::IE => Some(Registry::Ripencc),
+            Country::IM => Some(Registry::Ripencc),
+            Country::IL => Some(Registry::Ripencc),
+            Country::IT => Some(Registry::Ripencc),
+            Country::JM => Some(Registry::Arin),
+            Country::JP => Some(Registry::Apnic),
+            Country::JE => Some(Registry::Ripencc),
+            Country::JO => Some(Registry::Ripencc),
+            Country::KZ => Some(Registry::Ripencc),
+            Country::KE => Some(Registry::Afrinic),
+            Country::KI => Some(Registry::Apnic),
+            Country::KP => Some(Registry::Apnic),
+            Country::KR => Some(Registry::Apnic),
+            Country::KW => Some(Registry::Ripencc),
+            Country::KG => Some(Registry::Ripencc),
+            Country::LA => Some(Registry::Apnic),
+            Country::LV => Some(Registry::Ripencc),
+            Country::LB => Some(Registry::Ripencc),
+            Country::LS => Some(Registry::Afrinic),
+            Country::LR => Some(Registry::Afrinic),
+            Country::LY => Some(Registry::Afrinic),
+            Country::LI => Some(Registry::Ripencc),
+            Country::LT => Some(Registry::Ripencc),
+            Country::LU => Some(Registry::Ripencc),
+            Country::MO => Some(Registry::Apnic),
+            Country::MK => Some(Registry::Ripencc),
+            Country::MG => Some(Registry::Afrinic),
+            Country::MW => Some(Registry::Afrinic),
+            Country::MY => Some(Registry::Apnic),
+            Country::MV => Some(Registry::Apnic),
+            Country::ML => Some(Registry::Afrinic),
+            Country::MT => Some(Registry::Ripencc),
+            Country::MH => Some(Registry::Apnic),
+            Country::MQ => Some(Registry::Arin),
+            Country::MR => Some(Registry::Afrinic),
+            Country::MU => Some(Registry::Afrinic),
+            Country::YT => Some(Registry::Afrinic),
+            Country::MX => Some(Registry::Lacnic),
+            Country::FM => Some(Registry::Apnic),
+            Country::MD => Some(Registry::Ripencc),
+            Country::MC => Some(Registry::Ripencc),
+            Country::MN => Some(Registry::Apnic),
+            Country::ME => Some(Registry::Ripencc),
+            Country::MS => Some(Registry::Arin),
+            Country::MA => Some(Registry::Afrinic),
+            Country::MZ => Some(Registry::Afrinic),
+            Country::MM => Some(Registry::Apnic),
+            Country::NA => Some(Registry::Afrinic),
+            Country::NR => Some(Registry::Apnic),
+            Country::NP => Some(Registry::Apnic),
+            Country::NL => Some(Registry::Ripencc),
+            Country::NC => Some(Registry::Apnic),
+            Country::NZ => Some(Registry::Apnic),
+            Country::NI => Some(Registry::Lacnic),
+            Country::NE => Some(Registry::Afrinic),
+            Country::NG => Some(Registry::Afrinic),
+            Country::NU => Some(Registry::Apnic),
+            Country::NF => Some(Registry::Apnic),
+            Country::MP => Some(Registry::Apnic),
+            Country::NO => Some(Registry::Ripencc),
+            Country::OM => Some(Registry::Ripencc),
+            Country::PK => Some(Registry::Apnic),
+            Country::PW => Some(Registry::Apnic),
+            Country::PS => Some(Registry::Ripencc),
+            Country::PA => Some(Registry::Lacnic),
+            Country::PG => Some(Registry::Apnic),
+            Country::PY => Some(Registry::Lacnic),
+            Country::PE => Some(Registry::Lacnic),
+            Country::PH => Some(Registry::Apnic),
+            Country::PN => Some(Registry::Apnic),
+            Country::PL => Some(Registry::Ripencc),
+            Country::PT => Some(Registry::Ripencc),
+            Country::PR => Some(Registry::Arin),
+            Country::QA => Some(Registry::Ripencc),
+            Country::RE => Some(Registry::Afrinic),
+            Country::RO => Some(Registry::Ripencc),
+            Country::RU => Some(Registry::Ripencc),
+            Country::RW => Some(Registry::Afrinic),
+            Country::BL => Some(Registry::Arin),
+            Country::SH => Some(Registry::Arin),
+            Country::KN => Some(Registry::Arin),
+            Country::LC => Some(Registry::Arin),
+            Country::MF => Some(Registry::Arin),
+            Country::PM => Some(Registry::Arin),
+            Country::VC => Some(Registry::Arin),
+            Country::WS => Some(Registry::Apnic),
+            Country::SM => Some(Registry::Ripencc),
+            Country::ST => Some(Registry::Afrinic),
+            Country::SA => Some(Registry::Ripencc),
+            Country::SN => Some(Registry::Afrinic),
+            Country::RS => Some(Registry::Ripencc),
+            Country::SC => Some(Registry::Afrinic),
+            Country::SL => Some(Registry::Afrinic),
+            Country::SG => Some(Registry::Apnic),
+            Country::SX => Some(Registry::Lacnic),
+            Country::SK => Some(Registry::Ripencc),
+            Country::SI => Some(Registry::Ripencc),
+            Country::SB => Some(Registry::Apnic),
+            Country::SO => Some(Registry::Afrinic),
+            Country::ZA => Some(Registry::Afrinic),
+            Country::GS => Some(Registry::Lacnic),
+            Country::SS => Some(Registry::Afrinic),
+            Country::ES => Some(Registry::Ripencc),
+            Country::LK => Some(Registry::Apnic),
+            Country::SD => Some(Registry::Afrinic),
+            Country::SR => Some(Registry::Lacnic),
+            Country::SJ => Some(Registry::Ripencc),
+            Country::SZ => Some(Registry::Afrinic),
+            Country::SE => Some(Registry::Ripencc),
+            Country::CH => Some(Registry::Ripencc),
+            Country::SY => Some(Registry::Ripencc),
+            Country::TW => Some(Registry::Apnic),
+            Country::TJ => Some(Registry::Ripencc),
+            Country::TZ => Some(Registry::Afrinic),
+            Country::TH => Some(Registry::Apnic),
+            Country::TL => Some(Registry::Apnic),
+            Country::TG => Some(Registry::Afrinic),
+            Country::TK => Some(Registry::Apnic),
+            Country::TO => Some(Registry::Apnic),
+            Country::TT => Some(Registry::Lacnic),
+            Country::TN => Some(Registry::Afrinic),
+            Country::TR => Some(Registry::Ripencc),
+            Country::TM => Some(Registry::Ripencc),
+            Country::TC => Some(Registry::Arin),
+            Country::TV => Some(Registry::Apnic),
+            Country::UG => Some(Registry::Afrinic),
+            Country::UA => Some(Registry::Ripencc),
+            Country::AE => Some(Registry::Ripencc),
+            Country::GB => Some(Registry::Ripencc),
+            Country::US => Some(Registry::Arin),
+            Country::UM => Some(Registry::Arin),
+            Country::UY => Some(Registry::Lacnic),
+            Country::UZ => Some(Registry::Ripencc),
+            Country::VU => Some(Registry::Apnic),
+            Country::VE => Some(Registry::Lacnic),
+            Country::VN => Some(Registry::Apnic),
+            Country::VG => Some(Registry::Arin),
+            Country::VI => Some(Registry::Arin),
+            Country::WF => Some(Registry::Apnic),
+            Country::EH => Some(Registry::Afrinic),
+            Country::YE => Some(Registry::Ripencc),
+            Country::ZM => Some(Registry::Afrinic),
+            Country::ZW => Some(Registry::Afrinic),
+            Country::EU => Some(Registry::Ripencc),
+            Country::AP => Some(Registry::Apnic),
+            Country::ZZ => None,
+        }
+    }
+
+    pub fn from_alpha3(code: &str) -> Result<Self, Error> {
+        match code {
+            "AFG" => Ok(Country::AF),
+            "ALA" => Ok(Country::AX),
+            "ALB" => Ok(Country::AL),
+            "DZA" => Ok(Country::DZ),
+            "ASM" => Ok(Country::AS),
+            "AND" => Ok(Country::AD),
+            "AGO" => Ok(Country::AO),
+            "AIA" => Ok(Country::AI),
+            "ATA" => Ok(Country::AQ),
+            "ATG" => Ok(Country::AG),
+            "ARG" => Ok(Country::AR),
+            "ARM" => Ok(Country::AM),
+            "ABW" => Ok(Country::AW),
+            "AUS" => Ok(Country::AU),
+            "AUT" => Ok(Country::AT),
+            "AZE" => Ok(Country::AZ),
+            "BHS" => Ok(Country::BS),
+            "BHR" => Ok(Country::BH),
+            "BGD" => Ok(Country::BD),
+            "BRB" => Ok(Country::BB),
+            "BLR" => Ok(Country::BY),
+            "BEL" => Ok(Country::BE),
+            "BLZ" => Ok(Country::BZ),
+            "BEN" => Ok(Country::BJ),
+            "BMU" => Ok(Country::BM),
+            "BTN" => Ok(Country::BT),
+            "BOL" => Ok(Country::BO),
+            "BES" => Ok(Country::BQ),
+            "BIH" => Ok(Country::BA),
+            "BWA" => Ok(Country::BW),
+            "BVT" => Ok(Country::BV),
+            "BRA" => Ok(Country::BR),
+            "IOT" => Ok(Country::IO),
+            "BRN" => Ok(Country::BN),
+            "BGR" => Ok(Country::BG),
+            "BFA" => Ok(Country::BF),
+            "BDI" => Ok(Country::BI),
+            "KHM" => Ok(Country::KH),
+            "CMR" => Ok(Country::CM),
+            "CAN" => Ok(Country::CA),
+            "CPV" => Ok(Country::CV),
+            "CYM" => Ok(Country::KY),
+            "CAF" => Ok(Country::CF),
+            "TCD" => Ok(Country::TD),
+            "CHL" => Ok(Country::CL),
+            "CHN" => Ok(Country::CN),
+            "CXR" => Ok(Country::CX),
+            "CCK" => Ok(Country::CC),
+            "COL" => Ok(Country::CO),
+            "COM" => Ok(Country::KM),
+            "COG" => Ok(Country::CG),
+            "COD" => Ok(Country::CD),
+            "COK" => Ok(Country::CK),
+            "CRI" => Ok(Country::CR),
+            "CIV" => Ok(Country::CI),
+            "HRV" => Ok(Country::HR),
+            "CUB" => Ok(Country::CU),
+            "CUW" => Ok(Country::CW),
+            "CYP" => Ok(Country::CY),
+            "CZE" => Ok(Country::CZ),
+            "DNK" => Ok(Country::DK),
+            "DJI" => Ok(Country::DJ),
+            "DMA" => Ok(Country::DM),
+            "DOM" => Ok(Country::DO),
+            "ECU" => Ok(Country::EC),
+            "EGY" => Ok(Country::EG),
+            "SLV" => Ok(Country::SV),
+            "GNQ" => Ok(Country::GQ),
+            "ERI" => Ok(Country::ER),
+            "EST" => Ok(Country::EE),
+            "ETH" => Ok(Country::ET),
+            "FLK" => Ok(Country::FK),
+            "FRO" => Ok(Country::FO),
+            "FJI" => Ok(Country::FJ),
+            "FIN" => Ok(Country::FI),
+            "FRA" => Ok(Country::FR),
+            "GUF" => Ok(Country::GF),
+            "PYF" => Ok(Country::PF),
+            "ATF" => Ok(Country::TF),
+            "GAB" => Ok(Country::GA),
+            "GMB" => Ok(Country::GM),
+            "GEO" => Ok(Country::GE),
+            "DEU" => Ok(Country::DE),
+            "GHA" => Ok(Country::GH),
+            "GIB" => Ok(Country::GI),
+            "GRC" => Ok(Country::GR),
+            "GRL" => Ok(Country::GL),
+            "GRD" => Ok(Country::GD),
+            "GLP" => Ok(Country::GP),
+            "GUM" => Ok(Country::GU),
+            "GTM" => Ok(Country::GT),
+            "GGY" => Ok(Country::GG),
+            "GIN" => Ok(Country::GN),
+            "GNB" => Ok(Country::GW),
+            "GUY" => Ok(Country::GY),
+            "HTI" => Ok(Country::HT),
+            "HMD" => Ok(Country::HM),
+            "VAT" => Ok(Country::VA),
+            "HND" => Ok(Country::HN),
+            "HKG" => Ok(Country::HK),
+            "HUN" => Ok(Country::HU),
+            "ISL" => Ok(Country::IS),
+            "IND" => Ok(Country::IN),
+            "IDN" => Ok(Country::ID),
+            "IRN" => Ok(Country::IR),
+            "IRQ" => Ok(Country::IQ),
+            "IRL" => Ok(Country::IE),
+            "IMN" => Ok(Country::IM),
+            "ISR" => Ok(Country::IL),
+            "ITA" => Ok(Country::IT),
+            "JAM" => Ok(Country::JM),
+            "JPN" => Ok(Country::JP),
+            "JEY" => Ok(Country::JE),
+            "JOR" => Ok(Country::JO),
+            "KAZ" => Ok(Country::KZ),
+            "KEN" => Ok(Country::KE),
+            "KIR" => Ok(Country::KI),
+            "PRK" => Ok(Country::KP),
+            "KOR" => Ok(Country::KR),
+            "KWT" => Ok(Country::KW),
+            "KGZ" => Ok(Country::KG),
+            "LAO" => Ok(Country::LA),
+            "LVA" => Ok(Country::LV),
+            "LBN" => Ok(Country::LB),
+            "LSO" => Ok(Country::LS),
+            "LBR" => Ok(Country::LR),
+            "LBY" => Ok(Country::LY),
+            "LIE" => Ok(Country::LI),
+            "LTU" => Ok(Country::LT),
+            "LUX" => Ok(Country::LU),
+            "MAC" => Ok(Country::MO),
+            "MKD" => Ok(Country::MK),
+            "MDG" => Ok(Country::MG),
+            "MWI" => Ok(Country::MW),
+            "MYS" => Ok(Country::MY),
+            "MDV" => Ok(Country::MV),
+            "MLI" => Ok(Country::ML),
+            "MLT" => Ok(Country::MT),
+            "MHL" => Ok(Country::MH),
+            "MTQ" => Ok(Country::MQ),
+            "MRT" => Ok(Country::MR),
+            "MUS" => Ok(Country::MU),
+            "MYT" => Ok(Country::YT),
+            "MEX" => Ok(Country::MX),
+            "FSM" => Ok(Country::FM),
+            "MDA" => Ok(Country::MD),
+            "MCO" => Ok(Country::MC),
+            "MNG" => Ok(Country::MN),
+            "MNE" => Ok(Country::ME),
+            "MSR" => Ok(Country::MS),
+            "MAR" => Ok(Country::MA),
+            "MOZ" => Ok(Country::MZ),
+            "MMR" => Ok(Country::MM),
+            "NAM" => Ok(Country::NA),
+            "NRU" => Ok(Country::NR),
+            "NPL" => Ok(Country::NP),
+            "NLD" => Ok(Country::NL),
+            "NCL" => Ok(Country::NC),
+            "NZL" => Ok(Country::NZ),
+            "NIC" => Ok(Country::NI),
+            "NER" => Ok(Country::NE),
+            "NGA" => Ok(Country::NG),
+            "NIU" => Ok(Country::NU),
+            "NFK" => Ok(Country::NF),
+            "MNP" => Ok(Country::MP),
+            "NOR" => Ok(Country::NO),
+            "OMN" => Ok(Country::OM),
+            "PAK" => Ok(Country::PK),
+            "PLW" => Ok(Country::PW),
+            "PSE" => Ok(Country::PS),
+            "PAN" => Ok(Country::PA),
+            "PNG" => Ok(Country::PG),
+            "PRY" => Ok(Country::PY),
+            "PER" => Ok(Country::PE),
+            "PHL" => Ok(Country::PH),
+            "PCN" => Ok(Country::PN),
+            "POL" => Ok(Country::PL),
+            "PRT" => Ok(Country::PT),
+            "PRI" => Ok(Country::PR),
+            "QAT" => Ok(Country::QA),
+            "REU" => Ok(Country::RE),
+            "ROU" => Ok(Country::RO),
+            "RUS" => Ok(Country::RU),
+            "RWA" => Ok(Country::RW),
+            "BLM" => Ok(Country::BL),
+            "SHN" => Ok(Country::SH),
+            "KNA" => Ok(Country::KN),
+            "LCA" => Ok(Country::LC),
+            "MAF" => Ok(Country::MF),
+            "SPM" => Ok(Country::PM),
+            "VCT" => Ok(Country::VC),
+            "WSM" => Ok(Country::WS),
+            "SMR" => Ok(Country::SM),
+            "STP" => Ok(Country::ST),
+            "SAU" => Ok(Country::SA),
+            "SEN" => Ok(Country::SN),
+            "SRB" => Ok(Country::RS),
+            "SYC" => Ok(Country::SC),
+            "SLE" => Ok(Country::SL),
+            "SGP" => Ok(Country::SG),
+            "SXM" => Ok(Country::SX),
+            "SVK" => Ok(Country::SK),
+            "SVN" => Ok(Country::SI),
+            "SLB" => Ok(Country::SB),
+            "SOM" => Ok(Country::SO),
+            "ZAF" => Ok(Country::ZA),
+            "SGS" => Ok(Country::GS),
+            "SSD" => Ok(Country::SS),
+            "ESP" => Ok(Country::ES),
+            "LKA" => Ok(Country::LK),
+            "SDN" => Ok(Country::SD),
+            "SUR" => Ok(Country::SR),
+            "SJM" => Ok(Country::SJ),
+            "SWZ" => Ok(Country::SZ),
+            "SWE" => Ok(Country::SE),
+            "CHE" => Ok(Country::CH),
+            "SYR" => Ok(Country::SY),
+            "TWN" => Ok(Country::TW),
+            "TJK" => Ok(Country::TJ),
+            "TZA" => Ok(Country::TZ),
+            "THA" => Ok(Country::TH),
+            "TLS" => Ok(Country::TL),
+            "TGO" => Ok(Country::TG),
+            "TKL" => Ok(Country::TK),
+            "TON" => Ok(Country::TO),
+            "TTO" => Ok(Country::TT),
+            "TUN" => Ok(Country::TN),
+            "TUR" => Ok(Country::TR),
+            "TKM" => Ok(Country::TM),
+            "TCA" => Ok(Country::TC),
+            "TUV" => Ok(Country::TV),
+            "UGA" => Ok(Country::UG),
+            "UKR" => Ok(Country::UA),
+            "ARE" => Ok(Country::AE),
+            "GBR" => Ok(Country::GB),
+            "USA" => Ok(Country::US),
+            "UMI" => Ok(Country::UM),
+            "URY" => Ok(Country::UY),
+            "UZB" => Ok(Country::UZ),
+            "VUT" => Ok(Country::VU),
+            "VEN" => Ok(Country::VE),
+            "VNM" => Ok(Country::VN),
+            "VGB" => Ok(Country::VG),
+            "VIR" => Ok(Country::VI),
+            "WLF" => Ok(Country::WF),
+            "ESH" => Ok(Country::EH),
+            "YEM" => Ok(Country::YE),
+            "ZMB" => Ok(Country::ZM),
+            "ZWE" => Ok(Country::ZW),
+            e => Err(Error::ParseCountryError(format!("Unknow Country Alpha-3 Code ({})", e))),
+        }
+    }
+
+    pub fn from_numeric(code: u16) -> Result<Self, Error> {
+        match code {
+            4 => Ok(Country::AF),
+            8 => Ok(Country::AL),
+            10 => Ok(Country::AQ),
+            12 => Ok(Country::DZ),
+            16 => Ok(Country::AS),
+            20 => Ok(Country::AD),
+            24 => Ok(Country::AO),
+            28 => Ok(Country::AG),
+            31 => Ok(Country::AZ),
+            32 => Ok(Country::AR),
+            36 => Ok(Country::AU),
+            40 => Ok(Country::AT),
+            44 => Ok(Country::BS),
+            48 => Ok(Country::BH),
+            50 => Ok(Country::BD),
+            51 => Ok(Country::AM),
+            52 => Ok(Country::BB),
+            56 => Ok(Country::BE),
+            60 => Ok(Country::BM),
+            64 => Ok(Country::BT),
+            68 => Ok(Country::BO),
+            70 => Ok(Country::BA),
+            72 => Ok(Country::BW),
+            74 => Ok(Country::BV),
+            76 => Ok(Country::BR),
+            84 => Ok(Country::BZ),
+            86 => Ok(Country::IO),
+            90 => Ok(Country::SB),
+            92 => Ok(Country::VG),
+            96 => Ok(Country::BN),
+            100 => Ok(Country::BG),
+            104 => Ok(Country::MM),
+            108 => Ok(Country::BI),
+            112 => Ok(Country::BY),
+            116 => Ok(Country::KH),
+            120 => Ok(Country::CM),
+            124 => Ok(Country::CA),
+            132 => Ok(Country::CV),
+            136 => Ok(Country::KY),
+            140 => Ok(Country::CF),
+            144 => Ok(Country::LK),
+            148 => Ok(Country::TD),
+            152 => Ok(Country::CL),
+            156 => Ok(Country::CN),
+            158 => Ok(Country::TW),
+            162 => Ok(Country::CX),
+            166 => Ok(Country::CC),
+            170 => Ok(Country::CO),
+            174 => Ok(Country::KM),
+            175 => Ok(Country::YT),
+            178 => Ok(Country::CG),
+            180 => Ok(Country::CD),
+            184 => Ok(Country::CK),
+            188 => Ok(Country::CR),
+            191 => Ok(Country::HR),
+            192 => Ok(Country::CU),
+            196 => Ok(Country::CY),
+            203 => Ok(Country::CZ),
+            204 => Ok(Country::BJ),
+            208 => Ok(Country::DK),
+            212 => Ok(Country::DM),
+            214 => Ok(Country::DO),
+            218 => Ok(Country::EC),
+            222 => Ok(Country::SV),
+            226 => Ok(Country::GQ),
+            231 => Ok(Country::ET),
+            232 => Ok(Country::ER),
+            233 => Ok(Country::EE),
+            234 => Ok(Country::FO),
+            238 => Ok(Country::FK),
+            239 => Ok(Country::GS),
+            242 => Ok(Country::FJ),
+            246 => Ok(Country::FI),
+            248 => Ok(Country::AX),
+            250 => Ok(Country::FR),
+            254 => Ok(Country::GF),
+            258 => Ok(Country::PF),
+            260 => Ok(Country::TF),
+            262 => Ok(Country::DJ),
+            266 => Ok(Country::GA),
+            268 => Ok(Country::GE),
+            270 => Ok(Country::GM),
+            275 => Ok(Country::PS),
+            276 => Ok(Country::DE),
+            288 => Ok(Country::GH),
+            292 => Ok(Country::GI),
+            296 => Ok(Country::KI),
+            300 => Ok(Country::GR),
+            304 => Ok(Country::GL),
+            308 => Ok(Country::GD),
+            312 => Ok(Country::GP),
+            316 => Ok(Country::GU),
+            320 => Ok(Country::GT),
+            324 => Ok(Country::GN),
+            328 => Ok(Country::GY),
+            332 => Ok(Country::HT),
+            334 => Ok(Country::HM),
+            336 => Ok(Country::VA),
+            340 => Ok(Country::HN),
+            344 => Ok(Country::HK),
+            348 => Ok(Country::HU),
+            352 => Ok(Country::IS),
+            356 => Ok(Country::IN),
+            360 => Ok(Country::ID),
+            364 => Ok(Country::IR),
+            368 => Ok(Country::IQ),
+            372 => Ok(Country::IE),
+            376 => Ok(Country::IL),
+            380 => Ok(Country::IT),
+            384 => Ok(Country::CI),
+            388 => Ok(Country::JM),
+            392 => Ok(Country::JP),
+            398 => Ok(Country::KZ),
+            400 => Ok(Country::JO),
+            404 => Ok(Country::KE),
+            408 => Ok(Country::KP),
+            410 => Ok(Country::KR),
+            414 => Ok(Country::KW),
+            417 => Ok(Country::KG),
+            418 => Ok(Country::LA),
+            422 => Ok(Country::LB),
+            426 => Ok(Country::LS),
+            428 => Ok(Country::LV),
+            430 => Ok(Country::LR),
+            434 => Ok(Country::LY),
+            438 => Ok(Country::LI),
+            440 => Ok(Country::LT),
+            442 => Ok(Country::LU),
+            446 => Ok(Country::MO),
+            450 => Ok(Country::MG),
+            454 => Ok(Country::MW),
+            458 => Ok(Country::MY),
+            462 => Ok(Country::MV),
+            466 => Ok(Country::ML),
+            470 => Ok(Country::MT),
+            474 => Ok(Country::MQ),
+            478 => Ok(Country::MR),
+            480 => Ok(Country::MU),
+            484 => Ok(Country::MX),
+            492 => Ok(Country::MC),
+            496 => Ok(Country::MN),
+            498 => Ok(Country::MD),
+            499 => Ok(Country::ME),
+            500 => Ok(Country::MS),
+            504 => Ok(Country::MA),
+            508 => Ok(Country::MZ),
+            512 => Ok(Country::OM),
+            516 => Ok(Country::NA),
+            520 => Ok(Country::NR),
+            524 => Ok(Country::NP),
+            528 => Ok(Country::NL),
+            531 => Ok(Country::CW),
+            533 => Ok(Country::AW),
+            534 => Ok(Country::SX),
+            535 => Ok(Country::BQ),
+            540 => Ok(Country::NC),
+            548 => Ok(Country::VU),
+            554 => Ok(Country::NZ),
+            558 => Ok(Country::NI),
+            562 => Ok(Country::NE),
+            566 => Ok(Country::NG),
+            570 => Ok(Country::NU),
+            574 => Ok(Country::NF),
+            578 => Ok(Country::NO),
+            580 => Ok(Country::MP),
+            581 => Ok(Country::UM),
+            583 => Ok(Country::FM),
+            584 => Ok(Country::MH),
+            585 => Ok(Country::PW),
+            586 => Ok(Country::PK),
+            591 => Ok(Country::PA),
+            598 => Ok(Country::PG),
+            600 => Ok(Country::PY),
+            604 => Ok(Country::PE),
+            608 => Ok(Country::PH),
+            612 => Ok(Country::PN),
+            616 => Ok(Country::PL),
+            620 => Ok(Country::PT),
+            624 => Ok(Country::GW),
+            626 => Ok(Country::TL),
+            630 => Ok(Country::PR),
+            634 => Ok(Country::QA),
+            638 => Ok(Country::RE),
+            642 => Ok(Country::RO),
+            643 => Ok(Country::RU),
+            646 => Ok(Country::RW),
+            652 => Ok(Country::BL),
+            654 => Ok(Country::SH),
+            659 => Ok(Country::KN),
+            660 => Ok(Country::AI),
+            662 => Ok(Country::LC),
+            663 => Ok(Country::MF),
+            666 => Ok(Country::PM),
+            670 => Ok(Country::VC),
+            674 => Ok(Country::SM),
+            678 => Ok(Country::ST),
+            682 => Ok(Country::SA),
+            686 => Ok(Country::SN),
+            688 => Ok(Country::RS),
+            690 => Ok(Country::SC),
+            694 => Ok(Country::SL),
+            702 => Ok(Country::SG),
+            703 => Ok(Country::SK),
+            704 => Ok(Country::VN),
+            705 => Ok(Country::SI),
+            706 => Ok(Country::SO),
+            710 => Ok(Country::ZA),
+            716 => Ok(Country::ZW),
+            724 => Ok(Country::ES),
+            728 => Ok(Country::SS),
+            729 => Ok(Country::SD),
+            732 => Ok(Country::EH),
+            740 => Ok(Country::SR),
+            744 => Ok(Country::SJ),
+            748 => Ok(Country::SZ),
+            752 => Ok(Country::SE),
+            756 => Ok(Country::CH),
+            760 => Ok(Country::SY),
+            762 => Ok(Country::TJ),
+            764 => Ok(Country::TH),
+            768 => Ok(Country::TG),
+            772 => Ok(Country::TK),
+            776 => Ok(Country::TO),
+            780 => Ok(Country::TT),
+            784 => Ok(Country::AE),
+            788 => Ok(Country::TN),
+            792 => Ok(Country::TR),
+            795 => Ok(Country::TM),
+            796 => Ok(Country::TC),
+            798 => Ok(Country::TV),
+            800 => Ok(Country::UG),
+            804 => Ok(Country::UA),
+            807 => Ok(Country::MK),
+            818 => Ok(Country::EG),
+            826 => Ok(Country::GB),
+            831 => Ok(Country::GG),
+            832 => Ok(Country::JE),
+            833 => Ok(Country::IM),
+            834 => Ok(Country::TZ),
+            840 => Ok(Country::US),
+            850 => Ok(Country::VI),
+            854 => Ok(Country::BF),
+            858 => Ok(Country::UY),
+            860 => Ok(Country::UZ),
+            862 => Ok(Country::VE),
+            876 => Ok(Country::WF),
+            882 => Ok(Country::WS),
+            887 => Ok(Country::YE),
+            894 => Ok(Country::ZM),
+            e => Err(Error::ParseCountryError(format!("Unknow Country Numeric Code ({})", e))),
+        }
+    }
+}
+
+
+impl FromStr for Country {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "AF" => Ok(Country::AF),
+            "AX" => Ok(Country::AX),
+            "AL" => Ok(Country::AL),
+            "DZ" => Ok(Country::DZ),
+            "AS" => Ok(Country::AS),
+            "AD" => Ok(Country::AD),
+            "AO" => Ok(Country::AO),
+            "AI" => Ok(Country::AI),
+            "AQ" => Ok(Country::AQ),
+            "AG" => Ok(Country::AG),
+            "AR" => Ok(Country::AR),
+            "AM" => Ok(Country::AM),
+            "AW" => Ok(Country::AW),
+            "AU" => Ok(Country::AU),
+            "AT" => Ok(Country::AT),
+            "AZ" => Ok(Country::AZ),
+            "BS" => Ok(Country::BS),
+            "BH" => Ok(Country::BH),
+            "BD" => Ok(Country::BD),
+            "BB" => Ok(Country::BB),
+            "BY" => Ok(Country::BY),
+            "BE" => Ok(Country::BE),
+            "BZ" => Ok(Country::BZ),
+            "BJ" => Ok(Country::BJ),
+            "BM" => Ok(Country::BM),
+            "BT" => Ok(Country::BT),
+            "BO" => Ok(Country::BO),
+            "BQ" => Ok(Country::BQ),
+            "BA" => Ok(Country::BA),
+            "BW" => Ok(Country::BW),
+            "BV" => Ok(Country::BV),
+            "BR" => Ok(Country::BR),
+            "IO" => Ok(Country::IO),
+            "BN" => Ok(Country::BN),
+            "BG" => Ok(Country::BG),
+            "BF" => Ok(Country::BF),
+            "BI" => Ok(Country::BI),
+            "KH" => Ok(Country::KH),
+            "CM" => Ok(Country::CM),
+            "CA" => Ok(Country::CA),
+            "CV" => Ok(Country::CV),
+            "KY" => Ok(Country::KY),
+            "CF" => Ok(Country::CF),
+            "TD" => Ok(Country::TD),
+            "CL" => Ok(Country::CL),
+            "CN" => Ok(Country::CN),
+            "CX" => Ok(Country::CX),
+            "CC" => Ok(Country::CC),
+            "CO" => Ok(Country::CO),
+            "KM" => Ok(Country::KM),
+            "CG" => Ok(Country::CG),
+            "CD" => Ok(Country::CD),
+            "CK" => Ok(Country::CK),
+            "CR" => Ok(Country::CR),
+            "CI" => Ok(Country::CI),
+            "HR" => Ok(Country::HR),
+            "CU" => Ok(Country::CU),
+            "CW" => Ok(Country::CW),
+            "CY" => Ok(Country::CY),
+            "CZ" => Ok(Country::CZ),
+            "DK" => Ok(Country::DK),
+            "DJ" => Ok(Country::DJ),
+            "DM" => Ok(Country::DM),
+            "DO" => Ok(Country::DO),
+            "EC" => Ok(Country::EC),
+            "EG" => Ok(Country::EG),
+            "SV" => Ok(Country::SV),
+            "GQ" => Ok(Country::GQ),
+            "ER" => Ok(Country::ER),
+            "EE" => Ok(Country::EE),
+            "ET" => Ok(Country::ET),
+            "FK" => Ok(Country::FK),
+            "FO" => Ok(Country::FO),
+            "FJ" => Ok(Country::FJ),
+            "FI" => Ok(Country::FI),
+            "FR" => Ok(Country::FR),
+            "GF" => Ok(Country::GF),
+            "PF" => Ok(Country::PF),
+            "TF" => Ok(Country::TF),
+            "GA" => Ok(Country::GA),
+            "GM" => Ok(Country::GM),
+            "GE" => Ok(Country::GE),
+            "DE" => Ok(Country::DE),
+            "GH" => Ok(Country::GH),
+            "GI" => Ok(Country::GI),
+            "GR" => Ok(Country::GR),
+            "GL" => Ok(Country::GL),
+            "GD" => Ok(Country::GD),
+            "GP" => Ok(Country::GP),
+            "GU" => Ok(Country::GU),
+            "GT" => Ok(Country::GT),
+            "GG" => Ok(Country::GG),
+            "GN" => Ok(Country::GN),
+            "GW" => Ok(Country::GW),
+            "GY" => Ok(Country::GY),
+            "HT" => Ok(Country::HT),
+            "HM" => Ok(Country::HM),
+            "VA" => Ok(Country::VA),
+            "HN" => Ok(Country::HN),
+            "HK" => Ok(Country::HK),
+            "HU" => Ok(Country::HU),
+            "IS" => Ok(Country::IS),
+            "IN" => Ok(Country::IN),
+            "ID" => Ok(Country::ID),
+            "IR" => Ok(Country::IR),
+            "IQ" => Ok(Country::IQ),
+            "IE" => Ok(Country::IE),
+            "IM" => Ok(Country::IM),
+            "IL" => Ok(Country::IL),
+            "IT" => Ok(Country::IT),
+            "JM" => Ok(Country::JM),
+            "JP" => Ok(Country::JP),
+            "JE" => Ok(Country::JE),
+            "JO" => Ok(Country::JO),
+            "KZ" => Ok(Country::KZ),
+            "KE" => Ok(Country::KE),
+            "KI" => Ok(Country::KI),
+            "KP" => Ok(Country::KP),
+            "KR" => Ok(Country::KR),
+            "KW" => Ok(Country::KW),
+            "KG" => Ok(Country::KG),
+            "LA" => Ok(Country::LA),
+            "LV" => Ok(Country::LV),
+            "LB" => Ok(Country::LB),
+            "LS" => Ok(Country::LS),
+            "LR" => Ok(Country::LR),
+            "LY" => Ok(Country::LY),
+            "LI" => Ok(Country::LI),
+            "LT" => Ok(Country::LT),
+            "LU" => Ok(Country::LU),
+            "MO" => Ok(Country::MO),
+            "MK" => Ok(Country::MK),
+            "MG" => Ok(Country::MG),
+            "MW" => Ok(Country::MW),
+            "MY" => Ok(Country::MY),
+            "MV" => Ok(Country::MV),
+            "ML" => Ok(Country::ML),
+            "MT" => Ok(Country::MT),
+            "MH" => Ok(Country::MH),
+            "MQ" => Ok(Country::MQ),
+            "MR" => Ok(Country::MR),
+            "MU" => Ok(Country::MU),
+            "YT" => Ok(Country::YT),
+            "MX" => Ok(Country::MX),
+            "FM" => Ok(Country::FM),
+            "MD" => Ok(Country::MD),
+            "MC" => Ok(Country::MC),
+            "MN" => Ok(Country::MN),
+            "ME" => Ok(Country::ME),
+            "MS" => Ok(Country::MS),
+            "MA" => Ok(Country::MA),
+            "MZ" => Ok(Country::MZ),
+            "MM" => Ok(Country::MM),
+            "NA" => Ok(Country::NA),
+            "NR" => Ok(Country::NR),
+            "NP" => Ok(Country::NP),
+            "NL" => Ok(Country::NL),
+            "NC" => Ok(Country::NC),
+            "NZ" => Ok(Country::NZ),
+            "NI" => Ok(Country::NI),
+            "NE" => Ok(Country::NE),
+            "NG" => Ok(Country::NG),
+            "NU" => Ok(Country::NU),
+            "NF" => Ok(Country::NF),
+            "MP" => Ok(Country::MP),
+            "NO" => Ok(Country::NO),
+            "OM" => Ok(Country::OM),
+            "PK" => Ok(Country::PK),
+            "PW" => Ok(Country::PW),
+            "PS" => Ok(Country::PS),
+            "PA" => Ok(Country::PA),
+            "PG" => Ok(Country::PG),
+            "PY" => Ok(Country::PY),
+            "PE" => Ok(Country::PE),
+            "PH" => Ok(Country::PH),
+            "PN" => Ok(Country::PN),
+            "PL" => Ok(Country::PL),
+            "PT" => Ok(Country::PT),
+            "PR" => Ok(Country::PR),
+            "QA" => Ok(Country::QA),
+            "RE" => Ok(Country::RE),
+            "RO" => Ok(Country::RO),
+            "RU" => Ok(Country::RU),
+            "RW" => Ok(Country::RW),
+            "BL" => Ok(Country::BL),
+            "SH" => Ok(Country::SH),
+            "KN" => Ok(Country::KN),
+            "LC" => Ok(Country::LC),
+            "MF" => Ok(Country::MF),
+            "PM" => Ok(Country::PM),
+            "VC" => Ok(Country::VC),
+            "WS" => Ok(Country::WS),
+            "SM" => Ok(Country::SM),
+            "ST" => Ok(Country::ST),
+            "SA" => Ok(Country::SA),
+            "SN" => Ok(Country::SN),
+            "RS" => Ok(Country::RS),
+            "SC" => Ok(Country::SC),
+            "SL" => Ok(Country::SL),
+            "SG" => Ok(Country::SG),
+            "SX" => Ok(Country::SX),
+            "SK" => Ok(Country::SK),
+            "SI" => Ok(Country::SI),
+            "SB" => Ok(Country::SB),
+            "SO" => Ok(Country::SO),
+            "ZA" => Ok(Country::ZA),
+            "GS" => Ok(Country::GS),
+            "SS" => Ok(Country::SS),
+            "ES" => Ok(Country::ES),
+            "LK" => Ok(Country::LK),
+            "SD" => Ok(Country::SD),
+            "SR" => Ok(Country::SR),
+            "SJ" => Ok(Country::SJ),
+            "SZ" => Ok(Country::SZ),
+            "SE" => Ok(Country::SE),
+            "CH" => Ok(Country::CH),
+            "SY" => Ok(Country::SY),
+            "TW" => Ok(Country::TW),
+            "TJ" => Ok(Country::TJ),
+            "TZ" => Ok(Country::TZ),
+            "TH" => Ok(Country::TH),
+            "TL" => Ok(Country::TL),
+            "TG" => Ok(Country::TG),
+            "TK" => Ok(Country::TK),
+            "TO" => Ok(Country::TO),
+            "TT" => Ok(Country::TT),
+            "TN" => Ok(Country::TN),
+            "TR" => Ok(Country::TR),
+            "TM" => Ok(Country::TM),
+            "TC" => Ok(Country::TC),
+            "TV" => Ok(Country::TV),
+            "UG" => Ok(Country::UG),
+            "UA" => Ok(Country::UA),
+            "AE" => Ok(Country::AE),
+            "GB" => Ok(Country::GB),
+            "US" => Ok(Country::US),
+            "UM" => Ok(Country::UM),
+            "UY" => Ok(Country::UY),
+            "UZ" => Ok(Country::UZ),
+            "VU" => Ok(Country::VU),
+            "VE" => Ok(Country::VE),
+            "VN" => Ok(Country::VN),
+            "VG" => Ok(Country::VG),
+            "VI" => Ok(Country::VI),
+            "WF" => Ok(Country::WF),
+            "EH" => Ok(Country::EH),
+            "YE" => Ok(Country::YE),
+            "ZM" => Ok(Country::ZM),
+            "ZW" => Ok(Country::ZW),
+            "EU" => Ok(Country::EU),
+            "ZZ" => Ok(Country::ZZ),
+            "AP" => Ok(Country::AP),
+            e => Err(Error::ParseCountryError(format!("Unknow Country Code ({})", e))),
+        }
+    }
+}
+
+
+impl fmt::Display for Country {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Country::AF => write!(f, "AF"),
+            Country::AX => write!(f, "AX"),
+            Country::AL => write!(f, "AL"),
+            Country::DZ => write!(f, "DZ"),
+            Country::AS => write!(f, "AS"),
+            Country::AD => write!(f, "AD"),
+            Country::AO => write!(f, "AO"),
+            Country::AI => write!(f, "AI"),
+            Country::AQ => write!(f, "AQ"),
+            Country::AG => write!(f, "AG"),
+            Country::AR => write!(f, "AR"),
+            Country::AM => write!(f, "AM"),
+            Country::AW => write!(f, "AW"),
+            Country::AU => write!(f, "AU"),
+            Country::AT => write!(f, "AT"),
+            Country::AZ => write!(f, "AZ"),
+            Country::BS => write!(f, "BS"),
+            Country::BH => write!(f, "BH"),
+            Country::BD => write!(f, "BD"),
+            Country::BB => write!(f, "BB"),
+            Country::BY => write!(f, "BY"),
+            Country::BE => write!(f, "BE"),
+            Country::BZ => write!(f, "BZ"),
+            Country::BJ => write!(f, "BJ"),
+            Country::BM => write!(f, "BM"),
+            Country::BT => write!(f, "BT"),
+            Country::BO => write!(f, "BO"),
+            Country::BQ => write!(f, "BQ"),
+            Country::BA => write!(f, "BA"),
+            Country::BW => write!(f, "BW"),
+            Country::BV => write!(f, "BV"),
+            Country::BR => write!(f, "BR"),
+            Country::IO => write!(f, "IO"),
+            Country::BN => write!(f, "BN"),
+            Country::BG => write!(f, "BG"),
+            Country::BF => write!(f, "BF"),
+            Country::BI => write!(f, "BI"),
+            Country::KH => write!(f, "KH"),
+            Country::CM => write!(f, "CM"),
+            Country::CA => write!(f, "CA"),
+            Country::CV => write!(f, "CV"),
+            Country::KY => write!(f, "KY"),
+            Country::CF => write!(f, "CF"),
+            Country::TD => write!(f, "TD"),
+            Country::CL => write!(f, "CL"),
+            Country::CN => write!(f, "CN"),
+            Country::CX => write!(f, "CX"),
+            Country::CC => write!(f, "CC"),
+            Country::CO => write!(f, "CO"),
+            Country::KM => write!(f, "KM"),
+            Country::CG => write!(f, "CG"),
+            Country::CD => write!(f, "CD"),
+            Country::CK => write!(f, "CK"),
+            Country::CR => write!(f, "CR"),
+            Country::CI => write!(f, "CI"),
+            Country::HR => write!(f, "HR"),
+            Country::CU => write!(f, "CU"),
+            Country::CW => write!(f, "CW"),
+            Country::CY => write!(f, "CY"),
+            Country::CZ => write!(f, "CZ"),
+            Country::DK => write!(f, "DK"),
+            Country::DJ => write!(f, "DJ"),
+            Country::DM => write!(f, "DM"),
+            Country::DO => write!(f, "DO"),
+            Country::EC => write!(f, "EC"),
+            Country::EG => write!(f, "EG"),
+            Country::SV => write!(f, "SV"),
+            Country::GQ => write!(f, "GQ"),
+            Country::ER => write!(f, "ER"),
+            Country::EE => write!(f, "EE"),
+            Country::ET => write!(f, "ET"),
+            Country::FK => write!(f, "FK"),
+            Country::FO => write!(f, "FO"),
+            Country::FJ => write!(f, "FJ"),
+            Country::FI => write!(f, "FI"),
+            Country::FR => write!(f, "FR"),
+            Country::GF => write!(f, "GF"),
+            Country::PF => write!(f, "PF"),
+            Country::TF => write!(f, "TF"),
+            Country::GA => write!(f, "GA"),
+            Country::GM => write!(f, "GM"),
+            Country::GE => write!(f, "GE"),
+            Country::DE => write!(f, "DE"),
+            Country::GH => write!(f, "GH"),
+            Country::GI => write!(f, "GI"),
+            Country::GR => write!(f, "GR"),
+            Country::GL => write!(f, "GL"),
+            Country::GD => write!(f, "GD"),
+            Country::GP => write!(f, "GP"),
+            Country::GU => write!(f, "GU"),
+            Country::GT => write!(f, "GT"),
+            Country::GG => write!(f, "GG"),
+            Country::GN => write!(f, "GN"),
+            Country::GW => write!(f, "GW"),
+            Country::GY => write!(f, "GY"),
+            Country::HT => write!(f, "HT"),
+            Country::HM => write!(f, "HM"),
+            Country::VA => write!(f, "VA"),
+            Country::HN => write!(f, "HN"),
+            Country::HK => write!(f, "HK"),
+            Country::HU => write!(f, "HU"),
+            Country::IS => write!(f, "IS"),
+            Country::IN => write!(f, "IN"),
+            Country::ID => write!(f, "ID"),
+            Country::IR => write!(f, "IR"),
+            Country::IQ => write!(f, "IQ"),
+            Country::IE => write!(f, "IE"),
+            Country::IM => write!(f, "IM"),
+            Country::IL => write!(f, "IL"),
+            Country::IT => write!(f, "IT"),
+            Country::JM => write!(f, "JM"),
+            Country::JP => write!(f, "JP"),
+            Country::JE => write!(f, "JE"),
+            Country::JO => write!(f, "JO"),
+            Country::KZ => write!(f, "KZ"),
+            Country::KE => write!(f, "KE"),
+            Country::KI => write!(f, "KI"),
+            Country::KP => write!(f, "KP"),
+            Country::KR => write!(f, "KR"),
+            Country::KW => write!(f, "KW"),
+            Country::KG => write!(f, "KG"),
+            Country::LA => write!(f, "LA"),
+            Country::LV => write!(f, "LV"),
+            Country::LB => write!(f, "LB"),
+            Country::LS => write!(f, "LS"),
+            Country::LR => write!(f, "LR"),
+            Country::LY => write!(f, "LY"),
+            Country::LI => write!(f, "LI"),
+            Country::LT => write!(f, "LT"),
+            Country::LU => write!(f, "LU"),
+            Country::MO => write!(f, "MO"),
+            Country::MK => write!(f, "MK"),
+            Country::MG => write!(f, "MG"),
+            Country::MW => write!(f, "MW"),
+            Country::MY => write!(f, "MY"),
+            Country::MV => write!(f, "MV"),
+            Country::ML => write!(f, "ML"),
+            Country::MT => write!(f, "MT"),
+            Country::MH => write!(f, "MH"),
+            Country::MQ => write!(f, "MQ"),
+            Country::MR => write!(f, "MR"),
+            Country::MU => write!(f, "MU"),
+            Country::YT => write!(f, "YT"),
+            Country::MX => write!(f, "MX"),
+            Country::FM => write!(f, "FM"),
+            Country::MD => write!(f, "MD"),
+            Country::MC => write!(f, "MC"),
+            Country::MN => write!(f, "MN"),
+            Country::ME => write!(f, "ME"),
+            Country::MS => write!(f, "MS"),
+            Country::MA => write!(f, "MA"),
+            Country::MZ => write!(f, "MZ"),
+            Country::MM => write!(f, "MM"),
+            Country::NA => write!(f, "NA"),
+            Country::NR => write!(f, "NR"),
+            Country::NP => write!(f, "NP"),
+            Country::NL => write!(f, "NL"),
+            Country::NC => write!(f, "NC"),
+            Country::NZ => write!(f, "NZ"),
+            Country::NI => write!(f, "NI"),
+            Country::NE => write!(f, "NE"),
+            Country::NG => write!(f, "NG"),
+            Country::NU => write!(f, "NU"),
+            Country::NF => write!(f, "NF"),
+            Country::MP => write!(f, "MP"),
+            Country::NO => write!(f, "NO"),
+            Country::OM => write!(f, "OM"),
+            Country::PK => write!(f, "PK"),
+            Country::PW => write!(f, "PW"),
+            Country::PS => write!(f, "PS"),
+            Country::PA => write!(f, "PA"),
+            Country::PG => write!(f, "PG"),
+            Country::PY => write!(f, "PY"),
+            Country::PE => write!(f, "PE"),
+            Country::PH => write!(f, "PH"),
+            Country::PN => write!(f, "PN"),
+            Country::PL => write!(f, "PL"),
+            Country::PT => write!(f, "PT"),
+            Country::PR => write!(f, "PR"),
+            Country::QA => write!(f, "QA"),
+            Country::RE => write!(f, "RE"),
+            Country::RO => write!(f, "RO"),
+            Country::RU => write!(f, "RU"),
+            Country::RW => write!(f, "RW"),
+            Country::BL => write!(f, "BL"),
+            Country::SH => write!(f, "SH"),
+            Country::KN => write!(f, "KN"),
+            Country::LC => write!(f, "LC"),
+            Country::MF => write!(f, "MF"),
+            Country::PM => write!(f, "PM"),
+            Country::VC => write!(f, "VC"),
+            Country::WS => write!(f, "WS"),
+            Country::SM => write!(f, "SM"),
+            Country::ST => write!(f, "ST"),
+            Country::SA => write!(f, "SA"),
+            Country::SN => write!(f, "SN"),
+            Country::RS => write!(f, "RS"),
+            Country::SC => write!(f, "SC"),
+            Country::SL => write!(f, "SL"),
+            Country::SG => write!(f, "SG"),
+            Country::SX => write!(f, "SX"),
+            Country::SK => write!(f, "SK"),
+            Country::SI => write!(f, "SI"),
+            Country::SB => write!(f, "SB"),
+            Country::SO => write!(f, "SO"),
+            Country::ZA => write!(f, "ZA"),
+            Country::GS => write!(f, "GS"),
+            Country::SS => write!(f, "SS"),
+            Country::ES => write!(f, "ES"),
+            Country::LK => write!(f, "LK"),
+            Country::SD => write!(f, "SD"),
+            Country::SR => write!(f, "SR"),
+            Country::SJ => write!(f, "SJ"),
+            Country::SZ => write!(f, "SZ"),
+            Country::SE => write!(f, "SE"),
+            Country::CH => write!(f, "CH"),
+            Country::SY => write!(f, "SY"),
+            Country::TW => write!(f, "TW"),
+            Country::TJ => write!(f, "TJ"),
+            Country::TZ => write!(f, "TZ"),
+            Country::TH => write!(f, "TH"),
+            Country::TL => write!(f, "TL"),
+            Country::TG => write!(f, "TG"),
+            Country::TK => write!(f, "TK"),
+            Country::TO => write!(f, "TO"),
+            Country::TT => write!(f, "TT"),
+            Country::TN => write!(f, "TN"),
+            Country::TR => write!(f, "TR"),
+            Country::TM => write!(f, "TM"),
+            Country::TC => write!(f, "TC"),
+            Country::TV => write!(f, "TV"),
+            Country::UG => write!(f, "UG"),
+            Country::UA => write!(f, "UA"),
+            Country::AE => write!(f, "AE"),
+            Country::GB => write!(f, "GB"),
+            Country::US => write!(f, "US"),
+            Country::UM => write!(f, "UM"),
+            Country::UY => write!(f, "UY"),
+            Country::UZ => write!(f, "UZ"),
+            Country::VU => write!(f, "VU"),
+            Country::VE => write!(f, "VE"),
+            Country::VN => write!(f, "VN"),
+            Country::VG => write!(f, "VG"),
+            Country::VI => write!(f, "VI"),
+            Country::WF => write!(f, "WF"),
+            Country::EH => write!(f, "EH"),
+            Country::YE => write!(f, "YE"),
+            Country::ZM => write!(f, "ZM"),
+            Country::ZW => write!(f, "ZW"),
+            Country::EU => write!(f, "EU"),
+            Country::ZZ => write!(f, "ZZ"),
+            Country::AP => write!(f, "AP"),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    ParseStatusError(String),
+    ParseCountryError(String),
+    ParseRegistryError(String),
+    ParseRecordError(String),
+    ParseCidrError(String),
+    ParseOriginError(String),
+    ParseRegionError(String),
+    ParseContinentError(String),
+    ParseLocaleError(String),
+    /// An RDAP bootstrap file.
+    ParseRdapError(String),
+    /// A row of an overlay CSV, with its line number.
+    ParseOverlayError(String),
+    /// A `ChangeKind` of a diff.
+    ParseChangeKindError(String),
+    /// The remote file does not exist (HTTP 404, FTP 550, missing local file).
+    NotFoundError(String),
+    /// Transport failure: connection, timeout, unexpected status code or a truncated body.
+    FetchError(String),
+    /// The downloaded file does not match its published MD5 checksum.
+    ChecksumError(String),
+    IoError(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Error::ParseStatusError(ref e) | Error::ParseCountryError(ref e) | Error::ParseRegistryError(ref e)
+            | Error::ParseRecordError(ref e) | Error::ParseCidrError(ref e) | Error::ParseOriginError(ref e)
+            | Error::ParseRegionError(ref e) | Error::ParseContinentError(ref e) | Error::ParseLocaleError(ref e)
+            | Error::ParseRdapError(ref e) | Error::ParseOverlayError(ref e) | Error::ParseChangeKindError(ref e)
+            | Error::NotFoundError(ref e)
+            | Error::FetchError(ref e) | Error::ChecksumError(ref e) | Error::IoError(ref e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,452 @@
+use crate::rir::record::{Record, IpBlock};
+use crate::rir::cidr::{Ipv4Cidr, Ipv6Cidr};
+
+use std::cmp;
+use std::ops;
+use std::iter::FromIterator;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+
+const IPV4_MAX: u128 = u32::MAX as u128;
+const IPV6_MAX: u128 = u128::MAX;
+
+/// The blocks of the IANA IPv4 Special-Purpose Address Registry (RFC 6890) that are not
+/// globally reachable, plus multicast.
+const IPV4_SPECIAL_PURPOSE: [(Ipv4Addr, u8); 15] = [
+    (Ipv4Addr::new(0, 0, 0, 0), 8),
+    (Ipv4Addr::new(10, 0, 0, 0), 8),
+    (Ipv4Addr::new(100, 64, 0, 0), 10),
+    (Ipv4Addr::new(127, 0, 0, 0), 8),
+    (Ipv4Addr::new(169, 254, 0, 0), 16),
+    (Ipv4Addr::new(172, 16, 0, 0), 12),
+    (Ipv4Addr::new(192, 0, 0, 0), 24),
+    (Ipv4Addr::new(192, 0, 2, 0), 24),
+    (Ipv4Addr::new(192, 168, 0, 0), 16),
+    (Ipv4Addr::new(198, 18, 0, 0), 15),
+    (Ipv4Addr::new(198, 51, 100, 0), 24),
+    (Ipv4Addr::new(203, 0, 113, 0), 24),
+    (Ipv4Addr::new(224, 0, 0, 0), 4),
+    (Ipv4Addr::new(240, 0, 0, 0), 4),
+    (Ipv4Addr::new(255, 255, 255, 255), 32),
+];
+
+/// Globally reachable entries within `IPV4_SPECIAL_PURPOSE`, the PCP and TURN anycast addresses.
+const IPV4_GLOBALLY_REACHABLE: [(Ipv4Addr, u8); 2] = [
+    (Ipv4Addr::new(192, 0, 0, 9), 32),
+    (Ipv4Addr::new(192, 0, 0, 10), 32),
+];
+
+/// The blocks of the IANA IPv6 Special-Purpose Address Registry (RFC 6890) that are not
+/// globally reachable, plus multicast.
+const IPV6_SPECIAL_PURPOSE: [(Ipv6Addr, u8); 13] = [
+    (Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 0), 128),
+    (Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1), 128),
+    (Ipv6Addr::new(0, 0, 0, 0, 0, 0xffff, 0, 0), 96),
+    (Ipv6Addr::new(0x64, 0xff9b, 1, 0, 0, 0, 0, 0), 48),
+    (Ipv6Addr::new(0x100, 0, 0, 0, 0, 0, 0, 0), 64),
+    (Ipv6Addr::new(0x100, 0, 0, 1, 0, 0, 0, 0), 64),
+    (Ipv6Addr::new(0x2001, 0, 0, 0, 0, 0, 0, 0), 23),
+    (Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32),
+    (Ipv6Addr::new(0x3fff, 0, 0, 0, 0, 0, 0, 0), 20),
+    (Ipv6Addr::new(0x5f00, 0, 0, 0, 0, 0, 0, 0), 16),
+    (Ipv6Addr::new(0xfc00, 0, 0, 0, 0, 0, 0, 0), 7),
+    (Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 0), 10),
+    (Ipv6Addr::new(0xff00, 0, 0, 0, 0, 0, 0, 0), 8),
+];
+
+/// Entries within `IPV6_SPECIAL_PURPOSE` that are globally reachable (AMT, AS112, ORCHIDv2, ...)
+/// or not marked as unreachable (TEREDO).
+const IPV6_GLOBALLY_REACHABLE: [(Ipv6Addr, u8); 8] = [
+    (Ipv6Addr::new(0x2001, 0, 0, 0, 0, 0, 0, 0), 32),
+    (Ipv6Addr::new(0x2001, 1, 0, 0, 0, 0, 0, 1), 128),
+    (Ipv6Addr::new(0x2001, 1, 0, 0, 0, 0, 0, 2), 128),
+    (Ipv6Addr::new(0x2001, 1, 0, 0, 0, 0, 0, 3), 128),
+    (Ipv6Addr::new(0x2001, 3, 0, 0, 0, 0, 0, 0), 32),
+    (Ipv6Addr::new(0x2001, 4, 0x112, 0, 0, 0, 0, 0), 48),
+    (Ipv6Addr::new(0x2001, 0x20, 0, 0, 0, 0, 0, 0), 28),
+    (Ipv6Addr::new(0x2001, 0x30, 0, 0, 0, 0, 0, 0), 28),
+];
+
+/// A set of IPv4 and IPv6 addresses.
+///
+/// Each family is kept as sorted, non-overlapping and non-adjacent inclusive ranges,
+/// so two sets holding the same addresses compare equal.
+///
+/// ```
+/// use iana::{IpSet, Country};
+///
+/// let ranges = IpSet::from_country(Country::DE) | IpSet::from_country(Country::FR);
+/// let everything_else = !&ranges;
+/// for cidr in everything_else.ipv4_cidrs() {
+///     println!("{}", cidr);
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq)]
+pub struct IpSet {
+    v4: Vec<(u128, u128)>,
+    v6: Vec<(u128, u128)>,
+}
+
+impl IpSet {
+    pub fn new() -> Self {
+        IpSet { v4: Vec::new(), v6: Vec::new() }
+    }
+
+    /// From inclusive ranges of numbers, IPv4 ones must fit in 32 bits.
+    pub(crate) fn from_ranges(v4: Vec<(u128, u128)>, v6: Vec<(u128, u128)>) -> Self {
+        IpSet { v4: merge(v4), v6: merge(v6) }
+    }
+
+    /// Every IPv4 and IPv6 address.
+    pub fn all() -> Self {
+        IpSet { v4: vec![(0, IPV4_MAX)], v6: vec![(0, IPV6_MAX)] }
+    }
+
+    /// Private, loopback, link-local, documentation, multicast and the other addresses
+    /// the IANA special-purpose registries mark as not globally reachable.
+    ///
+    /// Globally reachable special-purpose blocks, like the AS112 and AMT anycast ones or
+    /// `64:ff9b::/96`, are left out.
+    pub fn special_purpose() -> Self {
+        let from_cidrs = |v4_table: &[(Ipv4Addr, u8)], v6_table: &[(Ipv6Addr, u8)]| {
+            let mut set = IpSet::new();
+            for &(address, prefix_len) in v4_table.iter() {
+                set.insert_ipv4_cidr(Ipv4Cidr::new(address, prefix_len));
+            }
+            for &(address, prefix_len) in v6_table.iter() {
+                set.insert_ipv6_cidr(Ipv6Cidr::new(address, prefix_len));
+            }
+            set
+        };
+        from_cidrs(&IPV4_SPECIAL_PURPOSE, &IPV6_SPECIAL_PURPOSE)
+            .difference(&from_cidrs(&IPV4_GLOBALLY_REACHABLE, &IPV6_GLOBALLY_REACHABLE))
+    }
+
+    /// Add `first..=last`, both ends must be of the same family.
+    pub fn insert_range(&mut self, first: IpAddr, last: IpAddr) {
+        match (first, last) {
+            (IpAddr::V4(first), IpAddr::V4(last)) => {
+                assert!(first <= last);
+                insert(&mut self.v4, u32::from(first) as u128, u32::from(last) as u128);
+            },
+            (IpAddr::V6(first), IpAddr::V6(last)) => {
+                assert!(first <= last);
+                insert(&mut self.v6, u128::from(first), u128::from(last));
+            },
+            _ => panic!("{} and {} are not of the same address family", first, last),
+        }
+    }
+
+    pub fn insert_ipv4_cidr(&mut self, cidr: Ipv4Cidr) {
+        self.insert_range(IpAddr::V4(cidr.first()), IpAddr::V4(cidr.last()));
+    }
+
+    pub fn insert_ipv6_cidr(&mut self, cidr: Ipv6Cidr) {
+        self.insert_range(IpAddr::V6(cidr.first()), IpAddr::V6(cidr.last()));
+    }
+
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        let (ranges, n) = match *ip {
+            IpAddr::V4(v4_addr) => (&self.v4, u32::from(v4_addr) as u128),
+            IpAddr::V6(v6_addr) => (&self.v6, u128::from(v6_addr)),
+        };
+        // Index of the first range starting after `n`.
+        let pos = ranges.partition_point(|&(first, _last)| first <= n);
+        pos > 0 && n <= ranges[pos - 1].1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.v4.is_empty() && self.v6.is_empty()
+    }
+
+    pub fn union(&self, other: &IpSet) -> IpSet {
+        IpSet {
+            v4: merge(self.v4.iter().chain(other.v4.iter()).cloned().collect()),
+            v6: merge(self.v6.iter().chain(other.v6.iter()).cloned().collect()),
+        }
+    }
+
+    pub fn intersection(&self, other: &IpSet) -> IpSet {
+        IpSet {
+            v4: intersect(&self.v4, &other.v4),
+            v6: intersect(&self.v6, &other.v6),
+        }
+    }
+
+    pub fn difference(&self, other: &IpSet) -> IpSet {
+        IpSet {
+            v4: intersect(&self.v4, &gaps(&other.v4, IPV4_MAX)),
+            v6: intersect(&self.v6, &gaps(&other.v6, IPV6_MAX)),
+        }
+    }
+
+    /// Every address not in the set, for both families.
+    pub fn complement(&self) -> IpSet {
+        IpSet {
+            v4: gaps(&self.v4, IPV4_MAX),
+            v6: gaps(&self.v6, IPV6_MAX),
+        }
+    }
+
+    /// The IPv4 part of the set.
+    pub fn ipv4(&self) -> IpSet {
+        IpSet { v4: self.v4.clone(), v6: Vec::new() }
+    }
+
+    /// The IPv6 part of the set.
+    pub fn ipv6(&self) -> IpSet {
+        IpSet { v4: Vec::new(), v6: self.v6.clone() }
+    }
+
+    pub fn ipv4_ranges(&self) -> impl Iterator<Item = (Ipv4Addr, Ipv4Addr)> + '_ {
+        self.v4.iter().map(|&(first, last)| (Ipv4Addr::from(first as u32), Ipv4Addr::from(last as u32)))
+    }
+
+    pub fn ipv6_ranges(&self) -> impl Iterator<Item = (Ipv6Addr, Ipv6Addr)> + '_ {
+        self.v6.iter().map(|&(first, last)| (Ipv6Addr::from(first), Ipv6Addr::from(last)))
+    }
+
+    /// The fewest CIDRs covering the IPv4 part of the set.
+    pub fn ipv4_cidrs(&self) -> Vec<Ipv4Cidr> {
+        self.v4.iter()
+            .flat_map(|&(first, last)| range_cidrs(first, last, 32))
+            .map(|(address, prefix_len)| Ipv4Cidr::new(Ipv4Addr::from(address as u32), prefix_len))
+            .collect()
+    }
+
+    /// The fewest CIDRs covering the IPv6 part of the set.
+    pub fn ipv6_cidrs(&self) -> Vec<Ipv6Cidr> {
+        self.v6.iter()
+            .flat_map(|&(first, last)| range_cidrs(first, last, 128))
+            .map(|(address, prefix_len)| Ipv6Cidr::new(Ipv6Addr::from(address), prefix_len))
+            .collect()
+    }
+}
+
+impl From<IpBlock> for IpSet {
+    fn from(ip_block: IpBlock) -> Self {
+        let mut set = IpSet::new();
+        set.insert_range(ip_block.first(), ip_block.last());
+        set
+    }
+}
+
+impl<'a> FromIterator<&'a Record> for IpSet {
+    fn from_iter<I: IntoIterator<Item = &'a Record>>(records: I) -> Self {
+        let mut v4 = Vec::new();
+        let mut v6 = Vec::new();
+        for record in records {
+            let ip_block = record.ip_block();
+            match (ip_block.first(), ip_block.last()) {
+                (IpAddr::V4(first), IpAddr::V4(last)) => v4.push((u32::from(first) as u128, u32::from(last) as u128)),
+                (IpAddr::V6(first), IpAddr::V6(last)) => v6.push((u128::from(first), u128::from(last))),
+                _ => unreachable!(),
+            }
+        }
+
+        IpSet { v4: merge(v4), v6: merge(v6) }
+    }
+}
+
+impl<'a> ops::BitOr<&'a IpSet> for &'a IpSet {
+    type Output = IpSet;
+    fn bitor(self, other: &IpSet) -> IpSet {
+        self.union(other)
+    }
+}
+
+impl ops::BitOr for IpSet {
+    type Output = IpSet;
+    fn bitor(self, other: IpSet) -> IpSet {
+        self.union(&other)
+    }
+}
+
+impl<'a> ops::BitAnd<&'a IpSet> for &'a IpSet {
+    type Output = IpSet;
+    fn bitand(self, other: &IpSet) -> IpSet {
+        self.intersection(other)
+    }
+}
+
+impl<'a> ops::Sub<&'a IpSet> for &'a IpSet {
+    type Output = IpSet;
+    fn sub(self, other: &IpSet) -> IpSet {
+        self.difference(other)
+    }
+}
+
+impl ops::Not for &IpSet {
+    type Output = IpSet;
+    fn not(self) -> IpSet {
+        self.complement()
+    }
+}
+
+
+/// Sort, then join overlapping and adjacent ranges.
+fn merge(mut ranges: Vec<(u128, u128)>) -> Vec<(u128, u128)> {
+    ranges.sort_unstable();
+
+    let mut merged: Vec<(u128, u128)> = Vec::with_capacity(ranges.len());
+    for (first, last) in ranges {
+        match merged.last_mut() {
+            Some(prev) if first <= prev.1.saturating_add(1) => prev.1 = prev.1.max(last),
+            _ => merged.push((first, last)),
+        }
+    }
+    merged
+}
+
+/// Add `first..=last` to merged ranges, joining the ranges it overlaps or touches.
+fn insert(ranges: &mut Vec<(u128, u128)>, first: u128, last: u128) {
+    // The ranges from `start` to `end` end at or after `first - 1` and start at or before `last + 1`.
+    let start = ranges.partition_point(|&(_first, prev_last)| prev_last.saturating_add(1) < first);
+    let end = ranges.partition_point(|&(next_first, _last)| next_first <= last.saturating_add(1));
+    let merged = if start < end {
+        (cmp::min(first, ranges[start].0), cmp::max(last, ranges[end - 1].1))
+    } else {
+        (first, last)
+    };
+    ranges.splice(start..end, std::iter::once(merged));
+}
+
+fn intersect(a: &[(u128, u128)], b: &[(u128, u128)]) -> Vec<(u128, u128)> {
+    let mut out = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        let first = cmp::max(a[i].0, b[j].0);
+        let last = cmp::min(a[i].1, b[j].1);
+        if first <= last {
+            out.push((first, last));
+        }
+        if a[i].1 < b[j].1 {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    out
+}
+
+/// The ranges between canonical ranges, within `0..=max`.
+fn gaps(ranges: &[(u128, u128)], max: u128) -> Vec<(u128, u128)> {
+    let mut gaps = Vec::with_capacity(ranges.len() + 1);
+    let mut next = Some(0u128);
+    for &(first, last) in ranges {
+        if let Some(start) = next {
+            if first > start {
+                gaps.push((start, first - 1));
+            }
+        }
+        next = if last >= max { None } else { Some(last + 1) };
+    }
+    if let Some(start) = next {
+        gaps.push((start, max));
+    }
+    gaps
+}
+
+/// The CIDRs covering `first..=last` in an address space of `bits` bits.
+pub(crate) fn range_cidrs(mut first: u128, last: u128, bits: u32) -> Vec<(u128, u8)> {
+    let mut cidrs = Vec::new();
+    loop {
+        // Largest block aligned on `first` that does not run past `last`.
+        let align = cmp::min(first.trailing_zeros(), bits);
+        let span = last - first;
+        let fits = if span == u128::MAX { 128 } else { 127 - (span + 1).leading_zeros() };
+        let size = cmp::min(align, fits);
+
+        cidrs.push((first, (bits - size) as u8));
+        if size == 128 {
+            break;
+        }
+        match first.checked_add(1 << size) {
+            Some(next) if next <= last => first = next,
+            _ => break,
+        }
+    }
+    cidrs
+}
+
+
+#[test]
+fn test_ipset_cidrs() {
+    let mut set = IpSet::new();
+    for cidr in ["10.0.1.0/24", "10.0.0.0/24", "10.0.2.0/23", "10.0.4.0/24", "10.0.3.0/28"].iter() {
+        set.insert_ipv4_cidr(cidr.parse().unwrap());
+    }
+    let cidrs: Vec<String> = set.ipv4_cidrs().iter().map(|cidr| cidr.to_string()).collect();
+    assert_eq!(cidrs, vec!["10.0.0.0/22", "10.0.4.0/24"]);
+    assert!(set.contains(&"10.0.4.255".parse().unwrap()));
+    assert!(!set.contains(&"10.0.5.0".parse().unwrap()));
+    assert!(!set.contains(&"::".parse().unwrap()));
+
+    let all = IpSet::all();
+    assert_eq!(all.ipv4_cidrs(), vec!["0.0.0.0/0".parse().unwrap()]);
+    assert_eq!(all.ipv6_cidrs(), vec!["::/0".parse().unwrap()]);
+
+    let mut set = IpSet::new();
+    for &(first, last) in [("10.0.0.8", "10.0.0.9"), ("10.0.0.0", "10.0.0.3"), ("10.0.0.20", "10.0.0.29"),
+                           ("10.0.0.5", "10.0.0.6"), ("10.0.0.4", "10.0.0.4"), ("10.0.0.7", "10.0.0.25")].iter() {
+        set.insert_range(first.parse().unwrap(), last.parse().unwrap());
+    }
+    assert_eq!(set.ipv4_ranges().collect::<Vec<_>>(), vec![("10.0.0.0".parse().unwrap(), "10.0.0.29".parse().unwrap())]);
+    set.insert_range("10.0.0.31".parse().unwrap(), "10.0.0.31".parse().unwrap());
+    set.insert_range("0.0.0.0".parse().unwrap(), "0.0.0.0".parse().unwrap());
+    assert_eq!(set.ipv4_ranges().count(), 3);
+
+    let mut tail = IpSet::new();
+    tail.insert_ipv6_cidr("ffff:ffff:ffff:ffff:ffff:ffff:ffff:fffe/127".parse().unwrap());
+    assert_eq!(tail.ipv6_cidrs(), vec!["ffff:ffff:ffff:ffff:ffff:ffff:ffff:fffe/127".parse().unwrap()]);
+}
+
+#[test]
+fn test_ipset_algebra() {
+    let set = |cidrs: &[&str]| {
+        let mut set = IpSet::new();
+        for cidr in cidrs {
+            if cidr.contains(':') {
+                set.insert_ipv6_cidr(cidr.parse().unwrap());
+            } else {
+                set.insert_ipv4_cidr(cidr.parse().unwrap());
+            }
+        }
+        set
+    };
+
+    let a = set(&["10.0.0.0/8", "2001:db8::/32"]);
+    let b = set(&["10.1.0.0/16", "192.168.0.0/16"]);
+
+    assert_eq!(&a | &b, set(&["10.0.0.0/8", "192.168.0.0/16", "2001:db8::/32"]));
+    assert_eq!(&a & &b, set(&["10.1.0.0/16"]));
+    let cidrs: Vec<String> = (&a - &b).ipv4_cidrs().iter().map(|cidr| cidr.to_string()).collect();
+    assert_eq!(cidrs, vec!["10.0.0.0/16", "10.2.0.0/15", "10.4.0.0/14", "10.8.0.0/13",
+                           "10.16.0.0/12", "10.32.0.0/11", "10.64.0.0/10", "10.128.0.0/9"]);
+    assert_eq!((&a - &b).ipv6(), set(&["2001:db8::/32"]));
+    assert_eq!(&b - &a, set(&["192.168.0.0/16"]));
+
+    let not_a = !&a;
+    assert!(!not_a.contains(&"10.9.9.9".parse().unwrap()));
+    assert!(not_a.contains(&"11.0.0.0".parse().unwrap()));
+    assert!(not_a.contains(&"2001:db9::".parse().unwrap()));
+    assert_eq!(!&not_a, a);
+    assert_eq!(&a | &not_a, IpSet::all());
+    assert!((&a & &not_a).is_empty());
+    assert_eq!(!&IpSet::all(), IpSet::new());
+}
+
+#[test]
+fn test_ipset_special_purpose() {
+    let special_purpose = IpSet::special_purpose();
+    for ip in ["10.1.2.3", "127.0.0.1", "169.254.1.1", "192.168.1.1", "224.0.0.251", "255.255.255.255",
+               "192.0.0.8", "192.0.0.170", "::1", "fe80::1", "fd00::1", "ff02::1", "2001:db8::1",
+               "2001:2::1", "3fff::1"].iter() {
+        assert!(special_purpose.contains(&ip.parse().unwrap()), "{}", ip);
+    }
+    for ip in ["1.1.1.1", "8.8.8.8", "223.255.255.255", "2400:cb00::1", "2a00:1450::1",
+               "192.0.0.9", "192.31.196.1", "192.52.193.1", "192.88.99.1", "192.175.48.1",
+               "64:ff9b::808:808", "2001::1", "2001:4:112::1", "2002::1", "2620:4f:8000::1"].iter() {
+        assert!(!special_purpose.contains(&ip.parse().unwrap()), "{}", ip);
+    }
+}
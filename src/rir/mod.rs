@@ -0,0 +1,17 @@
+//! The RIR records, their codes and the parser.
+//!
+//! Shared with `build.rs`, which includes this module to parse the RIR files into the lookup
+//! tables: it must not reach outside of `crate::rir`, nor depend on the crate's features.
+
+pub mod country;
+pub mod region;
+pub mod registry;
+pub mod status;
+pub mod origin;
+pub mod error;
+pub mod rir_files;
+pub mod cidr;
+pub mod ipset;
+pub mod record;
+pub mod parse;
+pub mod overlay;
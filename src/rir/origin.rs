@@ -0,0 +1,52 @@
+use std::str::FromStr;
+use std::fmt;
+
+use crate::rir::error::Error;
+
+
+/// Where the country of a record comes from.
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Origin {
+    /// The RIR statistics exchange files.
+    Rir = 0u8,
+    /// A user supplied overlay, see `Overlay`.
+    Overlay,
+}
+
+impl Origin {
+    pub fn from_index(index: u8) -> Result<Self, Error> {
+        match index {
+            0 => Ok(Origin::Rir),
+            1 => Ok(Origin::Overlay),
+            e => Err(Error::ParseOriginError(format!("Unknow Origin Index ({})", e))),
+        }
+    }
+
+    pub fn index(&self) -> u8 {
+        match *self {
+            Origin::Rir => 0,
+            Origin::Overlay => 1,
+        }
+    }
+}
+
+impl FromStr for Origin {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rir" => Ok(Origin::Rir),
+            "overlay" => Ok(Origin::Overlay),
+            e => Err(Error::ParseOriginError(format!("Unknow Origin ({})", e))),
+        }
+    }
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Origin::Rir => write!(f, "rir"),
+            Origin::Overlay => write!(f, "overlay"),
+        }
+    }
+}
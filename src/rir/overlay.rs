@@ -0,0 +1,377 @@
+//! Correct the country of RIR records with user supplied ranges.
+//!
+//! RIR data is the country of registration, not of use. An overlay CSV maps ranges to the
+//! country to report instead:
+//!
+//! ```text
+//! # network,country,priority
+//! 203.0.113.0/24,DE
+//! 198.51.100.0-198.51.100.127,FR,10
+//! "192.0.2.0","192.0.2.255","NL"
+//! ```
+//!
+//! The network is a CIDR, a single address or `first-last`, or it is split over two columns.
+//! Fields may be quoted as in RFC 4180, columns after the priority, e.g. a comment, are ignored.
+//! Where rows overlap the highest priority wins, then the last row. Records taking their
+//! country from an overlay have `Origin::Overlay`. Addresses outside the RIR data are ignored,
+//! there is no registry or status to report for them.
+
+use log::{debug, warn};
+
+use crate::rir::country::Country;
+use crate::rir::registry::Registry;
+use crate::rir::origin::Origin;
+use crate::rir::error::Error;
+use crate::rir::record::{Record, IpBlock, Ipv4Range};
+use crate::rir::cidr::{Ipv4Cidr, Ipv6Cidr};
+use crate::rir::ipset::{IpSet, range_cidrs};
+
+use std::ops::Bound;
+use std::str::FromStr;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::collections::{BTreeMap, HashSet};
+
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Entry {
+    first: u128,
+    last: u128,
+    country: Country,
+    priority: i32,
+}
+
+/// Non-overlapping ranges, keyed by their first address.
+pub(crate) type Painted<T> = BTreeMap<u128, (u128, T)>;
+
+/// Overwrite `first..=last`, trimming the ranges it overlaps.
+pub(crate) fn paint<T: Copy>(painted: &mut Painted<T>, first: u128, last: u128, value: T) {
+    let mut keep = Vec::new();
+
+    let before = painted.range(..first).next_back().map(|(&start, &(end, old))| (start, end, old));
+    if let Some((start, end, old)) = before {
+        if end >= first {
+            painted.remove(&start);
+            keep.push((start, first - 1, old));
+            if end > last {
+                keep.push((last + 1, end, old));
+            }
+        }
+    }
+
+    let starts: Vec<u128> = painted.range(first..=last).map(|(&start, _)| start).collect();
+    for start in starts {
+        let (end, old) = painted.remove(&start).unwrap();
+        if end > last {
+            keep.push((last + 1, end, old));
+        }
+    }
+
+    painted.insert(first, (last, value));
+    for (start, end, old) in keep {
+        painted.insert(start, (end, old));
+    }
+}
+
+/// The painted parts of `first..=last`, clipped to it.
+fn covered<T: Copy>(painted: &Painted<T>, first: u128, last: u128) -> Vec<(u128, u128, T)> {
+    let before = painted.range(..first).next_back()
+        .filter(|&(_, &(end, _))| end >= first);
+    let within = painted.range((Bound::Included(first), Bound::Included(last)));
+
+    before.into_iter().chain(within)
+        .map(|(&start, &(end, value))| (start.max(first), end.min(last), value))
+        .collect()
+}
+
+
+/// Split a CSV row, a quoted field may hold commas and `""` for a quote (RFC 4180).
+/// `None` when a quote is not closed.
+fn split_row(line: &str) -> Option<Vec<String>> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            },
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field).trim().to_string()),
+            c => field.push(c),
+        }
+    }
+    if quoted {
+        return None;
+    }
+    fields.push(field.trim().to_string());
+    Some(fields)
+}
+
+
+/// User supplied country corrections, applied on top of the RIR records.
+#[derive(Debug, Clone, Default)]
+pub struct Overlay {
+    v4: Vec<Entry>,
+    v6: Vec<Entry>,
+}
+
+impl Overlay {
+    pub fn new() -> Self {
+        Overlay { v4: Vec::new(), v6: Vec::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.v4.len() + self.v6.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.v4.is_empty() && self.v6.is_empty()
+    }
+
+    /// Report `country` for `first..=last`, over lower priority rows.
+    pub fn insert(&mut self, first: IpAddr, last: IpAddr, country: Country, priority: i32) -> Result<(), Error> {
+        let invalid = || Error::ParseOverlayError(format!("Invalid range ({} - {})", first, last));
+        match (first, last) {
+            (IpAddr::V4(first), IpAddr::V4(last)) if first <= last => {
+                let (first, last) = (u32::from(first) as u128, u32::from(last) as u128);
+                self.v4.push(Entry { first, last, country, priority });
+            },
+            (IpAddr::V6(first), IpAddr::V6(last)) if first <= last => {
+                let (first, last) = (u128::from(first), u128::from(last));
+                self.v6.push(Entry { first, last, country, priority });
+            },
+            _ => return Err(invalid()),
+        }
+        Ok(())
+    }
+
+    /// Add the rows of an overlay CSV, a header line is skipped.
+    pub fn parse_csv(&mut self, content: &str) -> Result<(), Error> {
+        let mut first_row = true;
+        for (line_idx, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let is_first_row = std::mem::replace(&mut first_row, false);
+
+            let invalid = |descp: &str| Error::ParseOverlayError(format!("{} (line {}: {})", descp, line_idx + 1, line));
+            let row = split_row(line).ok_or_else(|| invalid("Unterminated quote"))?;
+            let fields: Vec<&str> = row.iter().map(|field| field.as_str()).collect();
+
+            // `first,last,country` or `network,country`.
+            let (range, rest) = match (fields.first(), fields.get(1).and_then(|field| IpAddr::from_str(field).ok())) {
+                (Some(first), Some(last)) => (IpAddr::from_str(first).ok().map(|first| (first, last)), &fields[2..]),
+                (Some(network), None) => (parse_network(network), &fields[1..]),
+                (None, _) => unreachable!(),
+            };
+            let (first, last) = match range {
+                Some(range) => range,
+                // A header, `network,country`, its first field looks nothing like an address.
+                None if is_first_row && !fields[0].contains(['.', ':']) => continue,
+                None => return Err(invalid("Invalid network")),
+            };
+
+            let country = rest.first()
+                .ok_or_else(|| invalid("Missing country"))
+                .and_then(|country| Country::from_str(&country.to_uppercase()).map_err(|_| invalid("Invalid country")))?;
+            let priority = match rest.get(1) {
+                Some(priority) if !priority.is_empty() => priority.parse::<i32>().map_err(|_| invalid("Invalid priority"))?,
+                _ => 0,
+            };
+
+            self.insert(first, last, country, priority).map_err(|_| invalid("Invalid range"))?;
+        }
+        Ok(())
+    }
+
+    /// Split the records where the overlay changes their country.
+    ///
+    /// The IANA delegations to the RIRs are left alone.
+    pub fn apply(&self, records: HashSet<Record>) -> HashSet<Record> {
+        let painted_v4 = Overlay::paint_all(&self.v4);
+        let painted_v6 = Overlay::paint_all(&self.v6);
+
+        let painted_set = IpSet::from_ranges(
+            painted_v4.iter().map(|(&start, &(end, _))| (start, end)).collect(),
+            painted_v6.iter().map(|(&start, &(end, _))| (start, end)).collect(),
+        );
+        let rir_set: IpSet = records.iter().filter(|record| record.src_registry() != Registry::Iana).collect();
+        let ignored = painted_set.difference(&rir_set);
+        if !ignored.is_empty() {
+            let cidrs: Vec<String> = ignored.ipv4_cidrs().iter().map(|cidr| cidr.to_string())
+                .chain(ignored.ipv6_cidrs().iter().map(|cidr| cidr.to_string()))
+                .collect();
+            warn!("{} overlay networks outside the RIR data are ignored", cidrs.len());
+            debug!("Ignored overlay networks: {}", cidrs.join(" "));
+        }
+
+        let mut output = HashSet::with_capacity(records.len());
+        for record in records {
+            if record.src_registry() == Registry::Iana {
+                output.insert(record);
+                continue;
+            }
+
+            let ip_block = record.ip_block();
+            let (first, last, painted) = match (ip_block.first(), ip_block.last()) {
+                (IpAddr::V4(first), IpAddr::V4(last)) => (u32::from(first) as u128, u32::from(last) as u128, &painted_v4),
+                (IpAddr::V6(first), IpAddr::V6(last)) => (u128::from(first), u128::from(last), &painted_v6),
+                _ => unreachable!(),
+            };
+
+            let covered = covered(painted, first, last);
+            if covered.is_empty() {
+                output.insert(record);
+                continue;
+            }
+
+            let is_ipv4 = record.is_ipv4();
+            let mut split = |start: u128, end: u128, country: Option<Country>| {
+                for ip_block in blocks(start, end, is_ipv4) {
+                    let mut piece = record.clone();
+                    piece.ip_block = ip_block;
+                    if let Some(country) = country {
+                        piece.country = country;
+                        piece.origin = Origin::Overlay;
+                    }
+                    output.insert(piece);
+                }
+            };
+
+            // Start of the part not handled yet, `None` past the last address.
+            let mut next = Some(first);
+            for (start, end, country) in covered {
+                if let Some(next) = next.filter(|next| start > *next) {
+                    split(next, start - 1, None);
+                }
+                split(start, end, Some(country));
+                next = end.checked_add(1);
+            }
+            if let Some(next) = next.filter(|next| *next <= last) {
+                split(next, last, None);
+            }
+        }
+
+        output
+    }
+
+    fn paint_all(entries: &[Entry]) -> Painted<Country> {
+        let mut entries = entries.to_vec();
+        // Stable, so later rows of the same priority win.
+        entries.sort_by_key(|entry| entry.priority);
+
+        let mut painted = Painted::new();
+        for entry in entries {
+            paint(&mut painted, entry.first, entry.last, entry.country);
+        }
+        painted
+    }
+}
+
+/// `first`, `first-last` or `address/prefix_len`.
+fn parse_network(s: &str) -> Option<(IpAddr, IpAddr)> {
+    if let Some(pos) = s.find('-') {
+        let first = IpAddr::from_str(s[..pos].trim()).ok()?;
+        let last = IpAddr::from_str(s[pos + 1..].trim()).ok()?;
+        return Some((first, last));
+    }
+
+    match IpAddr::from_str(s.split('/').next().unwrap_or("")).ok()? {
+        IpAddr::V4(_) => {
+            let cidr = Ipv4Cidr::from_str(s).ok()?;
+            Some((IpAddr::V4(cidr.first()), IpAddr::V4(cidr.last())))
+        },
+        IpAddr::V6(_) => {
+            let cidr = Ipv6Cidr::from_str(s).ok()?;
+            Some((IpAddr::V6(cidr.first()), IpAddr::V6(cidr.last())))
+        },
+    }
+}
+
+/// Blocks covering `first..=last`, as `Record::from_str` would build them.
+fn blocks(first: u128, last: u128, is_ipv4: bool) -> Vec<IpBlock> {
+    if is_ipv4 {
+        let range = Ipv4Range::new(Ipv4Addr::from(first as u32), Ipv4Addr::from(last as u32));
+        vec![IpBlock::Ipv4Range(range)]
+    } else {
+        range_cidrs(first, last, 128).into_iter()
+            .map(|(address, prefix_len)| IpBlock::Ipv6Cidr(Ipv6Cidr::new(Ipv6Addr::from(address), prefix_len)))
+            .collect()
+    }
+}
+
+
+#[test]
+fn test_parse_csv() {
+    let mut overlay = Overlay::new();
+    overlay.parse_csv("network,country,priority\n\
+                       # comment\n\
+                       203.0.113.0/24,de\n\
+                       198.51.100.0 - 198.51.100.127,FR,10\n\
+                       \"192.0.2.0\",\"192.0.2.255\",\"NL\"\n\
+                       2001:db8::/32,US,-1\n").unwrap();
+    assert_eq!(overlay.len(), 4);
+    assert_eq!(overlay.v4[1], Entry { first: 0xc6336400, last: 0xc633647f, country: Country::FR, priority: 10 });
+    assert_eq!(overlay.v6[0].priority, -1);
+
+    assert!(Overlay::new().parse_csv("203.0.113.0/24,DE\nnot a network,DE\n").is_err());
+    assert!(Overlay::new().parse_csv("203.0.113.0/33,DE\n198.51.100.0/24,FR\n").is_err());
+    assert!(Overlay::new().parse_csv("# header\n2001:db8::/129,DE\n").is_err());
+
+    let mut overlay = Overlay::new();
+    overlay.parse_csv("# Corrections\nfirst,last,country\n192.0.2.0,192.0.2.255,NL\n").unwrap();
+    assert_eq!(overlay.len(), 1);
+    assert!(Overlay::new().parse_csv("203.0.113.0/24,XX\n").is_err());
+    assert!(Overlay::new().parse_csv("203.0.113.9-203.0.113.0,DE\n").is_err());
+
+    let mut overlay = Overlay::new();
+    overlay.parse_csv("network,country,priority,comment\n\
+                       203.0.113.0/24,DE,5,\"moved, see \"\"ticket 42\"\"\"\n\
+                       \"198.51.100.0 - 198.51.100.127\" , FR\n").unwrap();
+    assert_eq!(overlay.len(), 2);
+    assert_eq!(overlay.v4[0].priority, 5);
+    assert_eq!(overlay.v4[1].country, Country::FR);
+    assert!(Overlay::new().parse_csv("203.0.113.0/24,DE,5,\"moved, see\n").is_err());
+}
+
+#[test]
+fn test_split_row() {
+    assert_eq!(split_row("a, \"b,c\" ,\"d \"\"e\"\"\","), Some(vec!["a".to_string(), "b,c".to_string(), "d \"e\"".to_string(), String::new()]));
+    assert_eq!(split_row("\"a,b"), None);
+}
+
+#[test]
+fn test_apply() {
+    let records: HashSet<Record> = crate::rir::parse::parse_str("2|afrinic|20190911|6|00000000|20190911|00000\n\
+                                                     afrinic|ZA|ipv4|41.0.0.0|1024|20071126|allocated\n\
+                                                     afrinic|EG|ipv4|41.0.4.0|1024|20091105|allocated\n\
+                                                     afrinic|ZA|ipv6|2001:4200::|32|20051021|allocated\n")
+        .into_iter().collect();
+
+    let mut overlay = Overlay::new();
+    overlay.parse_csv("41.0.1.0/24,DE\n\
+                       41.0.1.128/25,FR,1\n\
+                       41.0.1.0/26,NL\n\
+                       41.0.4.0/22,MA\n\
+                       41.1.0.0/16,MA\n\
+                       2001:4200:8000::/33,KE\n").unwrap();
+    let records = overlay.apply(records);
+
+    let mut found: Vec<(String, Country, Origin)> = records.iter()
+        .map(|record| (record.ip_block().to_string(), record.country(), record.origin()))
+        .collect();
+    found.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(found, vec![
+        ("2001:4200:8000::/33".to_string(), Country::KE, Origin::Overlay),
+        ("2001:4200::/33".to_string(), Country::ZA, Origin::Rir),
+        ("41.0.0.0 - 41.0.0.255".to_string(), Country::ZA, Origin::Rir),
+        ("41.0.1.0 - 41.0.1.63".to_string(), Country::NL, Origin::Overlay),
+        ("41.0.1.128 - 41.0.1.255".to_string(), Country::FR, Origin::Overlay),
+        ("41.0.1.64 - 41.0.1.127".to_string(), Country::DE, Origin::Overlay),
+        ("41.0.2.0 - 41.0.3.255".to_string(), Country::ZA, Origin::Rir),
+        ("41.0.4.0 - 41.0.7.255".to_string(), Country::MA, Origin::Overlay),
+    ]);
+}
@@ -0,0 +1,150 @@
+use log::{debug, info, trace, error};
+
+use crate::rir::record::{Record, IpBlock, Provenance, NOT_AN_IP_RECORD};
+use crate::rir::registry::Registry;
+use crate::rir::error::Error;
+use crate::rir::rir_files::IANA_RIR_FILES;
+
+use std::fs;
+use std::str::FromStr;
+use std::collections::{HashMap, HashSet};
+use std::collections::hash_map::Entry;
+use std::path::{Path, PathBuf};
+
+
+/// `version|registry|serial|records|startdate|enddate|UTCoffset`
+fn parse_header(line: &str) -> (Option<u64>, Option<u32>) {
+    let fields: Vec<&str> = line.split('|').collect();
+    let serial = fields.get(2).and_then(|serial| serial.parse::<u64>().ok());
+    let date = fields.get(5)
+        .filter(|date| date.len() == 8)
+        .and_then(|date| date.parse::<u32>().ok())
+        .filter(|date| *date != 0);
+
+    (serial, date)
+}
+
+/// Parse the lines of one RIR statistics exchange file, skipping the header,
+/// summary, comment and `asn` lines.
+///
+/// Records carry the serial and date of the header and their line number.
+pub fn parse_str(content: &str) -> Vec<Record> {
+    let mut records = Vec::new();
+    let mut line_idx = 0usize;
+    let mut header = (None, None);
+
+    for (line_number, line) in content.lines().enumerate() {
+        if line.starts_with('#') {
+            trace!("Ignore comment line#{}", line_idx);
+            continue;
+        }
+
+        if line_idx == 0 {
+            header = parse_header(line);
+        }
+
+        if line_idx == 0 || line.ends_with("summary") {
+            trace!("Ignore summary line#{}", line_idx);
+            line_idx += 1;
+            continue;
+        }
+
+        match Record::from_str(line) {
+            Ok(mut record) => {
+                record.provenance = Some(Provenance {
+                    file: None,
+                    serial: header.0,
+                    date: header.1,
+                    line: line_number as u32 + 1,
+                });
+                records.push(record);
+            },
+            Err(Error::ParseRecordError(ref descp)) if descp.as_str() == NOT_AN_IP_RECORD => {
+
+            },
+            Err(e) => {
+                trace!("Parse Line#{} failed.", line_idx);
+                error!("{:?}", e);
+            },
+        }
+
+        line_idx += 1;
+    }
+
+    records
+}
+
+/// Parse every file of `IANA_RIR_FILES` found in `data_path`.
+///
+/// A block listed by both the plain and the extended file of a registry is kept once,
+/// preferring the extended record which carries the opaque id.
+pub fn parse<P: AsRef<Path>>(data_path: P) -> HashSet<Record> {
+    let data_path = data_path.as_ref();
+    if !data_path.exists() {
+        debug!("Path {:?} not exists", data_path);
+        return HashSet::new();
+    }
+
+    parse_files(IANA_RIR_FILES.iter().map(|&(filename, _)| (filename, data_path.join(filename))))
+}
+
+/// Parse `(filename, filepath)` pairs, `filename` of `IANA_RIR_FILES` names the file in the provenance.
+pub(crate) fn parse_files<I: IntoIterator<Item = (&'static str, PathBuf)>>(files: I) -> HashSet<Record> {
+    let mut records: HashMap<(Registry, IpBlock), Record> = HashMap::new();
+
+    for (filename, filepath) in files {
+        info!("Parse {:?} ...", filepath);
+        if !filepath.exists() {
+            error!("FilePath {:?} not exists.", &filepath);
+            continue;
+        }
+
+        if !filepath.is_file() {
+            error!("FilePath {:?} not a file.", &filepath);
+            continue;
+        }
+
+        let file_content = match fs::read_to_string(&filepath) {
+            Ok(content) => content,
+            Err(e) => {
+                error!("FilePath {:?} {}", &filepath, e);
+                continue;
+            }
+        };
+
+        for mut record in parse_str(&file_content) {
+            if let Some(ref mut provenance) = record.provenance {
+                provenance.file = Some(filename);
+            }
+            match records.entry((record.src_registry(), record.ip_block())) {
+                Entry::Occupied(mut entry) => {
+                    if entry.get().opaque_id().is_none() {
+                        entry.insert(record);
+                    }
+                },
+                Entry::Vacant(entry) => {
+                    entry.insert(record);
+                },
+            }
+        }
+    }
+
+    records.into_values().collect()
+}
+
+
+#[test]
+fn test_parse_str() {
+    let content = "2|afrinic|20190911|6|00000000|20190911|00000\n\
+                   afrinic|*|ipv4|*|2|summary\n\
+                   afrinic|ZA|asn|1228|1|19910301|allocated\n\
+                   afrinic|ZA|ipv4|41.0.0.0|2097152|20071126|allocated\n\
+                   afrinic|EG|ipv4|41.32.0.0|1048576|20091105|allocated\n\
+                   afrinic|ZA|ipv6|2001:4200::|32|20051021|allocated\n";
+    let records = parse_str(content);
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[1].country(), crate::rir::country::Country::EG);
+
+    let provenance = records[1].provenance().unwrap();
+    assert_eq!((provenance.serial, provenance.date, provenance.line), (Some(20190911), Some(20190911), 5));
+}
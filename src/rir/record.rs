@@ -0,0 +1,467 @@
+use std::fmt;
+use std::cmp;
+use std::str::FromStr;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::rir::cidr::{Ipv4Cidr, Ipv6Cidr};
+use crate::rir::country::Country;
+use crate::rir::registry::Registry;
+use crate::rir::status::Status;
+use crate::rir::origin::Origin;
+use crate::rir::error::Error;
+
+
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Ipv4Range {
+    pub start_ip: Ipv4Addr,
+    pub end_ip  : Ipv4Addr,
+}
+
+impl fmt::Display for Ipv4Range {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} - {}", self.start_ip, self.end_ip)
+    }
+}
+
+/// `first - last`, as printed by `Display`.
+impl FromStr for Ipv4Range {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::ParseRecordError(format!("Invalid IPv4 Range ({})", s));
+        let mut addrs = s.splitn(2, '-').map(|addr| addr.trim().parse::<Ipv4Addr>());
+        match (addrs.next(), addrs.next()) {
+            (Some(Ok(start_ip)), Some(Ok(end_ip))) if start_ip <= end_ip => Ok(Ipv4Range::new(start_ip, end_ip)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl Ipv4Range {
+    pub fn new(start_ip: Ipv4Addr, end_ip: Ipv4Addr) -> Self {
+        Ipv4Range { start_ip, end_ip }
+    }
+
+    pub fn with_nums(start_ip: Ipv4Addr, nums: u32) -> Self {
+        let end_ip = Ipv4Addr::from(u32::from(start_ip) + (nums - 1));
+
+        Ipv4Range { start_ip, end_ip }
+    }
+
+    pub fn first(&self) -> Ipv4Addr {
+        self.start_ip
+    }
+
+    pub fn last(&self) -> Ipv4Addr {
+        self.end_ip
+    }
+
+    pub fn total(&self) -> u32 {
+        u32::from(self.end_ip) - u32::from(self.start_ip) + 1
+    }
+
+    pub fn addrs(&self) -> Ipv4AddrsIter {
+        Ipv4AddrsIter {
+            offset: u32::from(self.start_ip) as u64,
+            end   : u32::from(self.end_ip) as u64,
+        }
+    }
+
+    pub fn cidrs(&self) -> Ipv4CidrIter {
+        Ipv4CidrIter {
+            start: u32::from(self.start_ip) as u64,
+            end  : u32::from(self.end_ip) as u64,
+        }
+    }
+}
+
+pub struct Ipv4AddrsIter {
+    offset: u64,
+    end: u64,
+}
+
+impl Iterator for Ipv4AddrsIter {
+    type Item = Ipv4Addr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.end >= self.offset {
+            let ip = Ipv4Addr::from(self.offset as u32);
+            self.offset += 1;
+            Some(ip)
+        } else {
+            None
+        }
+    }
+}
+
+pub struct Ipv4CidrIter {
+    start: u64,
+    end  : u64,
+}
+
+impl Iterator for Ipv4CidrIter {
+    type Item = Ipv4Cidr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.end > self.start {
+            let mut shift = cmp::min(32, self.start.trailing_zeros());
+            let num: u64;
+
+            loop {
+                let n = 2u64.pow(shift);
+                if self.start + n > self.end + 1 {
+                    if shift == 0 {
+                        panic!("oops ...")
+                    }
+                    shift -= 1;
+                } else {
+                    num = n;
+                    break;
+                }
+            }
+            let prefix_len = 32 - shift;
+            let cidr = Ipv4Cidr::new(Ipv4Addr::from(self.start as u32), prefix_len as u8);
+            self.start += num;
+            Some(cidr)
+        } else if self.end == self.start {
+            let cidr = Ipv4Cidr::new(Ipv4Addr::from(self.end as u32), 32);
+            self.start += 1;
+            Some(cidr)
+        } else {
+            None
+        }
+    }
+}
+
+
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IpBlock {
+    Ipv4Range(Ipv4Range),
+    Ipv4Cidr(Ipv4Cidr),
+    Ipv6Cidr(Ipv6Cidr),
+}
+
+impl IpBlock {
+    pub fn first(&self) -> IpAddr {
+        match *self {
+            IpBlock::Ipv4Range(v4_range) => IpAddr::V4(v4_range.first()),
+            IpBlock::Ipv4Cidr(v4_cidr) => IpAddr::V4(v4_cidr.first()),
+            IpBlock::Ipv6Cidr(v6_cidr) => IpAddr::V6(v6_cidr.first()),
+        }
+    }
+
+    pub fn last(&self) -> IpAddr {
+        match *self {
+            IpBlock::Ipv4Range(v4_range) => IpAddr::V4(v4_range.last()),
+            IpBlock::Ipv4Cidr(v4_cidr) => IpAddr::V4(v4_cidr.last()),
+            IpBlock::Ipv6Cidr(v6_cidr) => IpAddr::V6(v6_cidr.last()),
+        }
+    }
+
+    pub fn is_ipv4(&self) -> bool {
+        match *self {
+            IpBlock::Ipv4Range(_) | IpBlock::Ipv4Cidr(_) => true,
+            IpBlock::Ipv6Cidr(_) => false,
+        }
+    }
+
+    pub fn is_ipv6(&self) -> bool {
+        match *self {
+            IpBlock::Ipv4Range(_) | IpBlock::Ipv4Cidr(_) => false,
+            IpBlock::Ipv6Cidr(_) => true,
+        }
+    }
+}
+
+
+impl fmt::Display for IpBlock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            IpBlock::Ipv4Range(v4_range) => fmt::Display::fmt(&v4_range, f),
+            IpBlock::Ipv4Cidr(v4_cidr) => fmt::Display::fmt(&v4_cidr, f),
+            IpBlock::Ipv6Cidr(v6_cidr) => fmt::Display::fmt(&v6_cidr, f),
+        }
+    }
+}
+
+/// A CIDR, or `first - last` for IPv4 ranges that are not one.
+impl FromStr for IpBlock {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.contains('/') {
+            Ipv4Range::from_str(s).map(IpBlock::Ipv4Range)
+        } else if s.contains(':') {
+            Ipv6Cidr::from_str(s).map(IpBlock::Ipv6Cidr)
+        } else {
+            Ipv4Cidr::from_str(s).map(IpBlock::Ipv4Cidr)
+        }
+    }
+}
+
+
+/// Where a record was read from.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Provenance {
+    /// One of `IANA_RIR_FILES`, unset for records from `parse_str`.
+    pub file: Option<&'static str>,
+    /// Serial number of the file header.
+    pub serial: Option<u64>,
+    /// End date of the file header, `YYYYMMDD`.
+    pub date: Option<u32>,
+    /// Line number in the file, counted from 1.
+    pub line: u32,
+}
+
+impl fmt::Display for Provenance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.file.unwrap_or("-"), self.line)?;
+        if let Some(serial) = self.serial {
+            write!(f, " serial {}", serial)?;
+        }
+        if let Some(date) = self.date {
+            write!(f, " date {}", date)?;
+        }
+        Ok(())
+    }
+}
+
+
+/// One `ipv4` or `ipv6` line of a RIR statistics exchange file.
+/// 
+/// Records are sorted by their first address.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Record {
+    pub src_registry: Registry,
+    pub country: Country,
+    pub ip_block: IpBlock,
+    pub status: Status,
+    pub dst_registry: Option<Registry>,
+    /// `YYYYMMDD`, unset for blocks that were never delegated.
+    pub date: Option<u32>,
+    /// Identifier of the holder, only in the extended files.
+    pub opaque_id: Option<String>,
+    pub origin: Origin,
+    /// Set by `parse_str` and `parse`.
+    pub provenance: Option<Provenance>,
+}
+
+impl Record {
+    pub fn src_registry(&self) -> Registry {
+        self.src_registry
+    }
+
+    pub fn country(&self) -> Country {
+        self.country
+    }
+
+    pub fn type_(&self) -> String {
+        if self.is_ipv4() {
+            "ipv4".to_string()
+        } else if self.is_ipv6() {
+            "ipv6".to_string()
+        } else {
+            unreachable!()
+        }
+    }
+
+    pub fn ip_version(&self) -> String {
+        self.type_()
+    }
+
+    pub fn ip_block(&self) -> IpBlock {
+        self.ip_block
+    }
+
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    pub fn dst_registry(&self) -> Option<Registry> {
+        self.dst_registry
+    }
+
+    pub fn date(&self) -> Option<u32> {
+        self.date
+    }
+
+    pub fn opaque_id(&self) -> Option<&str> {
+        self.opaque_id.as_deref()
+    }
+
+    pub fn origin(&self) -> Origin {
+        self.origin
+    }
+
+    pub fn provenance(&self) -> Option<Provenance> {
+        self.provenance
+    }
+
+    pub fn is_ipv4(&self) -> bool {
+        self.ip_block.is_ipv4()
+    }
+
+    pub fn is_ipv6(&self) -> bool {
+        self.ip_block.is_ipv6()
+    }
+
+    pub fn codegen(&self) -> String {
+        let ip_to_number_string = |ipaddr| -> String {
+            match ipaddr {
+                IpAddr::V4(v4_addr) => format!("{}", u32::from(v4_addr)),
+                IpAddr::V6(v6_addr) => format!("{}", u128::from(v6_addr)),
+            }
+        };
+
+        format!("({}, {}, {}, {}, {}, {})",
+                ip_to_number_string(self.ip_block.first()),
+                ip_to_number_string(self.ip_block.last()),
+                self.country.index(),
+                self.src_registry.index(),
+                self.status.index(),
+                self.origin.index())
+    }
+}
+
+impl Ord for Record {
+    fn cmp(&self, other: &Record) -> cmp::Ordering {
+        self.ip_block.first().cmp(&other.ip_block.first())
+    }
+}
+
+impl PartialOrd for Record {
+    fn partial_cmp(&self, other: &Record) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {} {} {} {}",
+            self.src_registry,
+            self.country,
+            self.type_(),
+            match self.ip_block {
+                IpBlock::Ipv4Range(v4_range) => format!("{} {}", v4_range.first(), v4_range.total()),
+                IpBlock::Ipv4Cidr(v4_cidr) => format!("{} {}", v4_cidr.address(), v4_cidr.prefix_len()),
+                IpBlock::Ipv6Cidr(v6_cidr) => format!("{} {}", v6_cidr.address(), v6_cidr.prefix_len()),
+            },
+            self.status,
+            match self.dst_registry {
+                Some(reg) => format!("{}", reg),
+                None => "none".to_string()
+            })
+    }
+}
+
+impl FromStr for Record {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split('|').collect();
+
+        if fields.len() < 7 {
+            return Err(Error::ParseRecordError("Less than 7 fields length of this RIR Record".to_string()));
+        }
+
+        let invalid = |descp: &str| Error::ParseRecordError(format!("{} ({})", descp, s));
+
+        let src_registry = Registry::from_str(fields[0])?;
+        let cc = if fields[1].trim() == "" { "ZZ" } else { fields[1] };
+        let country_code = Country::from_str(cc)?;
+        let type_  = fields[2];
+
+        let ip_block = match type_ {
+            "ipv4" => {
+                let start_ip: Ipv4Addr = fields[3].parse().map_err(|_| invalid("Invalid IPv4 start address"))?;
+                let nums: u32 = fields[4].parse().ok()
+                    .filter(|nums| *nums > 0 && u32::from(start_ip).checked_add(nums - 1).is_some())
+                    .ok_or_else(|| invalid("Invalid IPv4 address count"))?;
+
+                IpBlock::Ipv4Range(Ipv4Range::with_nums(start_ip, nums))
+            }
+            "ipv6" => {
+                let start_ip: Ipv6Addr = fields[3].parse().map_err(|_| invalid("Invalid IPv6 start address"))?;
+                let prefix_len: u8 = fields[4].parse().ok()
+                    .filter(|prefix_len| *prefix_len <= 128)
+                    .ok_or_else(|| invalid("Invalid IPv6 prefix length"))?;
+
+                IpBlock::Ipv6Cidr(Ipv6Cidr::new(start_ip, prefix_len))
+            }
+            _ => {
+                return Err(Error::ParseRecordError(NOT_AN_IP_RECORD.to_string()));
+            }
+        };
+
+        // Some registries write `00000000` or nothing for available and reserved blocks.
+        let date = match fields[5].trim() {
+            "" | "00000000" => None,
+            date if date.len() == 8 => Some(date.parse::<u32>().map_err(|_| invalid("Invalid date"))?),
+            _ => return Err(invalid("Invalid date")),
+        };
+
+        // IANA records the registry a block was delegated to in the status field.
+        let status_ = fields[6];
+        let (status, dst_registry) = if src_registry == Registry::Iana {
+            (Status::Assigned, Some(Registry::from_str(status_)?))
+        } else {
+            (Status::from_str(status_)?, None)
+        };
+
+        let opaque_id = fields.get(7)
+            .map(|opaque_id| opaque_id.trim())
+            .filter(|opaque_id| !opaque_id.is_empty() && src_registry != Registry::Iana)
+            .map(|opaque_id| opaque_id.to_string());
+
+        Ok(Record {
+            src_registry,
+            country: country_code,
+            ip_block,
+            status,
+            dst_registry,
+            date,
+            opaque_id,
+            origin: Origin::Rir,
+            provenance: None,
+        })
+    }
+}
+
+pub(crate) const NOT_AN_IP_RECORD: &str = "Not an IPv4 or IPv6 Record Line.";
+
+
+#[test]
+fn test_parse_record() {
+    use crate::rir::country::CountryKind;
+
+    let record: Record = "afrinic|ZA|ipv4|41.0.0.0|2097152|20071126|allocated".parse().unwrap();
+    assert_eq!(record.country(), Country::ZA);
+    assert_eq!(record.ip_block().first(), IpAddr::from(Ipv4Addr::new(41, 0, 0, 0)));
+    assert_eq!(record.ip_block().last(), IpAddr::from(Ipv4Addr::new(41, 31, 255, 255)));
+    assert_eq!(record.to_string(), "afrinic ZA ipv4 41.0.0.0 2097152 allocated none");
+
+    assert_eq!(record.date(), Some(20071126));
+    assert_eq!(record.opaque_id(), None);
+
+    let record: Record = "afrinic|ZA|ipv4|41.0.0.0|2097152|20071126|allocated|F364712F".parse().unwrap();
+    assert_eq!(record.opaque_id(), Some("F364712F"));
+    let record: Record = "ripencc||ipv4|2.56.0.0|1024||available||".parse().unwrap();
+    assert_eq!((record.date(), record.opaque_id()), (None, None));
+
+    let record: Record = "apnic|JP|ipv6|2001:218::|32|20000807|allocated".parse().unwrap();
+    assert_eq!(record.ip_block().last(), "2001:218:ffff:ffff:ffff:ffff:ffff:ffff".parse::<IpAddr>().unwrap());
+
+    let record: Record = "iana|ZZ|ipv4|41.0.0.0|16777216|20050401|afrinic".parse().unwrap();
+    assert_eq!(record.dst_registry(), Some(Registry::Afrinic));
+
+    let record: Record = "apnic|AP|ipv4|182.50.184.0|2048|20100302|allocated".parse().unwrap();
+    assert_eq!((record.country(), record.country().kind()), (Country::AP, CountryKind::Region));
+    let record: Record = "ripencc|EU|ipv6|2001:678::|29|20040218|allocated".parse().unwrap();
+    assert_eq!(record.country().kind(), CountryKind::Region);
+    let record: Record = "lacnic||ipv4|24.152.0.0|32768||reserved".parse().unwrap();
+    assert_eq!((record.country(), record.country().kind()), (Country::ZZ, CountryKind::Unspecified));
+
+    assert_eq!("afrinic|ZA|asn|1228|1|19910301|allocated".parse::<Record>(),
+               Err(Error::ParseRecordError(NOT_AN_IP_RECORD.to_string())));
+    assert!("afrinic|ZA|ipv4|41.0.0.0|0|20071126|allocated".parse::<Record>().is_err());
+    assert!("afrinic|ZA|ipv4|41.0.0.0|256|2007-11-26|allocated".parse::<Record>().is_err());
+}
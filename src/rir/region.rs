@@ -0,0 +1,342 @@
+use std::str::FromStr;
+use std::fmt;
+
+use crate::rir::country::Country;
+use crate::rir::error::Error;
+
+
+/// UN M49 geographic areas: regions, sub-regions and intermediate regions.
+///
+/// Download from: https://unstats.un.org/unsd/methodology/m49/
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd)]
+pub enum Region {
+    Africa,
+    Americas,
+    Asia,
+    Europe,
+    Oceania,
+
+    NorthernAfrica,
+    SubSaharanAfrica,
+    LatinAmericaAndTheCaribbean,
+    NorthernAmerica,
+    CentralAsia,
+    EasternAsia,
+    SouthEasternAsia,
+    SouthernAsia,
+    WesternAsia,
+    EasternEurope,
+    NorthernEurope,
+    SouthernEurope,
+    WesternEurope,
+    AustraliaAndNewZealand,
+    Melanesia,
+    Micronesia,
+    Polynesia,
+
+    EasternAfrica,
+    MiddleAfrica,
+    SouthernAfrica,
+    WesternAfrica,
+    Caribbean,
+    CentralAmerica,
+    SouthAmerica,
+    ChannelIslands,
+}
+
+static REGIONS: [Region; 30] = [
+    Region::Africa, Region::Americas, Region::Asia, Region::Europe, Region::Oceania,
+    Region::NorthernAfrica, Region::SubSaharanAfrica, Region::LatinAmericaAndTheCaribbean, Region::NorthernAmerica,
+    Region::CentralAsia, Region::EasternAsia, Region::SouthEasternAsia, Region::SouthernAsia, Region::WesternAsia,
+    Region::EasternEurope, Region::NorthernEurope, Region::SouthernEurope, Region::WesternEurope,
+    Region::AustraliaAndNewZealand, Region::Melanesia, Region::Micronesia, Region::Polynesia,
+    Region::EasternAfrica, Region::MiddleAfrica, Region::SouthernAfrica, Region::WesternAfrica,
+    Region::Caribbean, Region::CentralAmerica, Region::SouthAmerica, Region::ChannelIslands,
+];
+
+impl Region {
+    pub fn from_code(code: u16) -> Result<Self, Error> {
+        REGIONS.iter()
+            .find(|region| region.code() == code)
+            .cloned()
+            .ok_or_else(|| Error::ParseRegionError(format!("Unknow Region Code ({})", code)))
+    }
+
+    /// M49 numeric code, e.g. `11` for Western Africa.
+    pub fn code(&self) -> u16 {
+        match *self {
+            Region::Africa => 2,
+            Region::Americas => 19,
+            Region::Asia => 142,
+            Region::Europe => 150,
+            Region::Oceania => 9,
+            Region::NorthernAfrica => 15,
+            Region::SubSaharanAfrica => 202,
+            Region::LatinAmericaAndTheCaribbean => 419,
+            Region::NorthernAmerica => 21,
+            Region::CentralAsia => 143,
+            Region::EasternAsia => 30,
+            Region::SouthEasternAsia => 35,
+            Region::SouthernAsia => 34,
+            Region::WesternAsia => 145,
+            Region::EasternEurope => 151,
+            Region::NorthernEurope => 154,
+            Region::SouthernEurope => 39,
+            Region::WesternEurope => 155,
+            Region::AustraliaAndNewZealand => 53,
+            Region::Melanesia => 54,
+            Region::Micronesia => 57,
+            Region::Polynesia => 61,
+            Region::EasternAfrica => 14,
+            Region::MiddleAfrica => 17,
+            Region::SouthernAfrica => 18,
+            Region::WesternAfrica => 11,
+            Region::Caribbean => 29,
+            Region::CentralAmerica => 13,
+            Region::SouthAmerica => 5,
+            Region::ChannelIslands => 830,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Region::Africa => "Africa",
+            Region::Americas => "Americas",
+            Region::Asia => "Asia",
+            Region::Europe => "Europe",
+            Region::Oceania => "Oceania",
+            Region::NorthernAfrica => "Northern Africa",
+            Region::SubSaharanAfrica => "Sub-Saharan Africa",
+            Region::LatinAmericaAndTheCaribbean => "Latin America and the Caribbean",
+            Region::NorthernAmerica => "Northern America",
+            Region::CentralAsia => "Central Asia",
+            Region::EasternAsia => "Eastern Asia",
+            Region::SouthEasternAsia => "South-eastern Asia",
+            Region::SouthernAsia => "Southern Asia",
+            Region::WesternAsia => "Western Asia",
+            Region::EasternEurope => "Eastern Europe",
+            Region::NorthernEurope => "Northern Europe",
+            Region::SouthernEurope => "Southern Europe",
+            Region::WesternEurope => "Western Europe",
+            Region::AustraliaAndNewZealand => "Australia and New Zealand",
+            Region::Melanesia => "Melanesia",
+            Region::Micronesia => "Micronesia",
+            Region::Polynesia => "Polynesia",
+            Region::EasternAfrica => "Eastern Africa",
+            Region::MiddleAfrica => "Middle Africa",
+            Region::SouthernAfrica => "Southern Africa",
+            Region::WesternAfrica => "Western Africa",
+            Region::Caribbean => "Caribbean",
+            Region::CentralAmerica => "Central America",
+            Region::SouthAmerica => "South America",
+            Region::ChannelIslands => "Channel Islands",
+        }
+    }
+
+    /// The area this one is part of, `None` for the five regions.
+    pub fn parent(&self) -> Option<Region> {
+        match *self {
+            Region::Africa | Region::Americas | Region::Asia | Region::Europe | Region::Oceania => None,
+            Region::NorthernAfrica | Region::SubSaharanAfrica => Some(Region::Africa),
+            Region::LatinAmericaAndTheCaribbean | Region::NorthernAmerica => Some(Region::Americas),
+            Region::CentralAsia | Region::EasternAsia | Region::SouthEasternAsia
+                | Region::SouthernAsia | Region::WesternAsia => Some(Region::Asia),
+            Region::EasternEurope | Region::NorthernEurope
+                | Region::SouthernEurope | Region::WesternEurope => Some(Region::Europe),
+            Region::AustraliaAndNewZealand | Region::Melanesia
+                | Region::Micronesia | Region::Polynesia => Some(Region::Oceania),
+            Region::EasternAfrica | Region::MiddleAfrica
+                | Region::SouthernAfrica | Region::WesternAfrica => Some(Region::SubSaharanAfrica),
+            Region::Caribbean | Region::CentralAmerica
+                | Region::SouthAmerica => Some(Region::LatinAmericaAndTheCaribbean),
+            Region::ChannelIslands => Some(Region::NorthernEurope),
+        }
+    }
+
+    /// Whether `self` is `area` or lies within it.
+    pub fn is_within(&self, area: Region) -> bool {
+        let mut current = Some(*self);
+        while let Some(region) = current {
+            if region == area {
+                return true;
+            }
+            current = region.parent();
+        }
+        false
+    }
+}
+
+impl FromStr for Region {
+    type Err = Error;
+    /// The name in any case, with or without spaces and dashes, or the M49 code.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(code) = s.parse::<u16>() {
+            return Region::from_code(code);
+        }
+
+        let normalize = |name: &str| -> String {
+            name.chars().filter(|c| c.is_alphanumeric()).flat_map(|c| c.to_lowercase()).collect()
+        };
+        let name = normalize(s);
+        REGIONS.iter()
+            .find(|region| normalize(region.name()) == name)
+            .cloned()
+            .ok_or_else(|| Error::ParseRegionError(format!("Unknow Region ({})", s)))
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+
+/// Continents as in GeoNames and the MaxMind databases.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd)]
+pub enum Continent {
+    Africa,
+    Antarctica,
+    Asia,
+    Europe,
+    NorthAmerica,
+    Oceania,
+    SouthAmerica,
+}
+
+impl Continent {
+    pub fn code(&self) -> &'static str {
+        match *self {
+            Continent::Africa => "AF",
+            Continent::Antarctica => "AN",
+            Continent::Asia => "AS",
+            Continent::Europe => "EU",
+            Continent::NorthAmerica => "NA",
+            Continent::Oceania => "OC",
+            Continent::SouthAmerica => "SA",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Continent::Africa => "Africa",
+            Continent::Antarctica => "Antarctica",
+            Continent::Asia => "Asia",
+            Continent::Europe => "Europe",
+            Continent::NorthAmerica => "North America",
+            Continent::Oceania => "Oceania",
+            Continent::SouthAmerica => "South America",
+        }
+    }
+}
+
+impl FromStr for Continent {
+    type Err = Error;
+    /// The two letter code or the name.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "AF" | "Africa" => Ok(Continent::Africa),
+            "AN" | "Antarctica" => Ok(Continent::Antarctica),
+            "AS" | "Asia" => Ok(Continent::Asia),
+            "EU" | "Europe" => Ok(Continent::Europe),
+            "NA" | "North America" => Ok(Continent::NorthAmerica),
+            "OC" | "Oceania" => Ok(Continent::Oceania),
+            "SA" | "South America" => Ok(Continent::SouthAmerica),
+            e => Err(Error::ParseContinentError(format!("Unknow Continent ({})", e))),
+        }
+    }
+}
+
+impl fmt::Display for Continent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+
+impl Country {
+    /// M49 region, e.g. `Africa`. `EU` is in Europe, `AP`, `ZZ` and `AQ` are in none.
+    pub fn region(&self) -> Option<Region> {
+        let mut area = self.area()?;
+        while let Some(parent) = area.parent() {
+            area = parent;
+        }
+        Some(area)
+    }
+
+    /// M49 sub-region, e.g. `Sub-Saharan Africa`.
+    pub fn sub_region(&self) -> Option<Region> {
+        let area = self.area()?;
+        match area.parent() {
+            Some(parent) if parent.parent().is_some() => Some(parent),
+            Some(_) => Some(area),
+            None => None,
+        }
+    }
+
+    /// M49 intermediate region, e.g. `Western Africa`. Only some sub-regions are divided.
+    pub fn intermediate_region(&self) -> Option<Region> {
+        self.area().filter(|area| area.parent().and_then(|parent| parent.parent()).is_some())
+    }
+
+    /// Whether the country lies within `region`, at any level.
+    pub fn is_in(&self, region: Region) -> bool {
+        self.area().map(|area| area.is_within(region)).unwrap_or(false)
+    }
+
+    /// Continent, following GeoNames: the Americas are split in two, and the
+    /// sub-antarctic territories are in Antarctica rather than their M49 region.
+    pub fn continent(&self) -> Option<Continent> {
+        match *self {
+            Country::AQ | Country::BV | Country::GS | Country::HM | Country::TF => return Some(Continent::Antarctica),
+            _ => { },
+        }
+
+        let area = self.area()?;
+        if area == Region::SouthAmerica {
+            return Some(Continent::SouthAmerica);
+        }
+        match self.region()? {
+            Region::Africa => Some(Continent::Africa),
+            Region::Americas => Some(Continent::NorthAmerica),
+            Region::Asia => Some(Continent::Asia),
+            Region::Europe => Some(Continent::Europe),
+            Region::Oceania => Some(Continent::Oceania),
+            _ => unreachable!(),
+        }
+    }
+}
+
+
+#[test]
+fn test_region() {
+    assert_eq!(Country::NG.region(), Some(Region::Africa));
+    assert_eq!(Country::NG.sub_region(), Some(Region::SubSaharanAfrica));
+    assert_eq!(Country::NG.intermediate_region(), Some(Region::WesternAfrica));
+    assert_eq!(Country::EG.sub_region(), Some(Region::NorthernAfrica));
+    assert_eq!(Country::EG.intermediate_region(), None);
+    assert_eq!(Country::EU.region(), Some(Region::Europe));
+    assert_eq!(Country::EU.sub_region(), None);
+    assert_eq!(Country::AP.region(), None);
+    assert_eq!(Country::AQ.region(), None);
+
+    assert!(Country::GG.is_in(Region::Europe));
+    assert!(Country::GG.is_in(Region::ChannelIslands));
+    assert!(!Country::GB.is_in(Region::ChannelIslands));
+
+    assert_eq!(Country::BR.continent(), Some(Continent::SouthAmerica));
+    assert_eq!(Country::MX.continent(), Some(Continent::NorthAmerica));
+    assert_eq!(Country::TF.continent(), Some(Continent::Antarctica));
+    assert_eq!(Country::AP.continent(), None);
+
+    assert_eq!("Western Africa".parse(), Ok(Region::WesternAfrica));
+    assert_eq!("south-eastern-asia".parse(), Ok(Region::SouthEasternAsia));
+    assert_eq!("011".parse(), Ok(Region::WesternAfrica));
+    assert!("Atlantis".parse::<Region>().is_err());
+    assert_eq!("NA".parse(), Ok(Continent::NorthAmerica));
+
+    for region in REGIONS.iter() {
+        assert_eq!(Region::from_code(region.code()), Ok(*region));
+        assert_eq!(region.name().parse(), Ok(*region));
+    }
+}
@@ -0,0 +1,242 @@
+
+use std::str::FromStr;
+use std::fmt;
+
+use crate::rir::country::Country;
+use crate::rir::error::Error;
+
+
+/// Number Resources
+/// 
+/// `https://www.iana.org/numbers`
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Registry {
+    /// Africa Region
+    Afrinic = 0u8,
+    /// Asia/Pacific Region
+    Apnic,
+    /// Canada, USA, and some Caribbean Islands
+    Arin,
+    /// Internet Assigned Numbers Authority(IANA)
+    Iana,
+    /// Internet Engineering Task Force(IETF), Special Registry
+    Ietf,
+    /// Latin America and some Caribbean Islands
+    Lacnic,
+    /// Europe, the Middle East, and Central Asia
+    Ripencc,
+}
+
+static REGISTRIES: [Registry; 7] = [
+    Registry::Afrinic,
+    Registry::Apnic,
+    Registry::Arin,
+    Registry::Iana,
+    Registry::Ietf,
+    Registry::Lacnic,
+    Registry::Ripencc,
+];
+
+impl Registry {
+    pub fn all() -> impl Iterator<Item = Registry> {
+        REGISTRIES.iter().cloned()
+    }
+
+    pub fn from_index(index: u8) -> Result<Self, Error> {
+        match index {
+            0u8 => Ok(Registry::Afrinic),
+            1u8 => Ok(Registry::Apnic),
+            2u8 => Ok(Registry::Arin),
+            3u8 => Ok(Registry::Iana),
+            4u8 => Ok(Registry::Ietf),
+            5u8 => Ok(Registry::Lacnic),
+            6u8 => Ok(Registry::Ripencc),
+            e => Err(Error::ParseRegistryError(format!("Unknow Registry Index ({})", e))),
+        }
+    }
+
+    pub fn index(&self) -> u8 {
+        match *self {
+            Registry::Afrinic => 0u8,
+            Registry::Apnic => 1u8,
+            Registry::Arin => 2u8,
+            Registry::Iana => 3u8,
+            Registry::Ietf => 4u8,
+            Registry::Lacnic => 5u8,
+            Registry::Ripencc => 6u8,
+        }
+    }
+
+    pub fn description(&self) -> &str {
+        match *self {
+            Registry::Afrinic => "Africa Region",
+            Registry::Apnic => "Asia/Pacific Region",
+            Registry::Arin => "Canada, USA, and some Caribbean Islands",
+            Registry::Iana => "Internet Assigned Numbers Authority(IANA)",
+            Registry::Ietf => "Internet Engineering Task Force(IETF), Special Registry",
+            Registry::Lacnic => "Latin America and some Caribbean Islands",
+            Registry::Ripencc => "Europe, the Middle East, and Central Asia",
+        }
+    }
+
+    /// Full name, e.g. `American Registry for Internet Numbers`.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Registry::Afrinic => "African Network Information Centre",
+            Registry::Apnic => "Asia-Pacific Network Information Centre",
+            Registry::Arin => "American Registry for Internet Numbers",
+            Registry::Iana => "Internet Assigned Numbers Authority",
+            Registry::Ietf => "Internet Engineering Task Force",
+            Registry::Lacnic => "Latin America and Caribbean Network Information Centre",
+            Registry::Ripencc => "RIPE Network Coordination Centre",
+        }
+    }
+
+    /// WHOIS server host, port 43. `None` for `Ietf`, whose special registry is served by IANA.
+    pub fn whois_server(&self) -> Option<&'static str> {
+        match *self {
+            Registry::Afrinic => Some("whois.afrinic.net"),
+            Registry::Apnic => Some("whois.apnic.net"),
+            Registry::Arin => Some("whois.arin.net"),
+            Registry::Iana => Some("whois.iana.org"),
+            Registry::Ietf => None,
+            Registry::Lacnic => Some("whois.lacnic.net"),
+            Registry::Ripencc => Some("whois.ripe.net"),
+        }
+    }
+
+    /// RDAP base URL, as in the IANA bootstrap files, e.g. `https://rdap.arin.net/registry/`
+    /// followed by `ip/<address>` or `autnum/<asn>`. `None` for `Iana` and `Ietf`.
+    pub fn rdap_url(&self) -> Option<&'static str> {
+        match *self {
+            Registry::Afrinic => Some("https://rdap.afrinic.net/rdap/"),
+            Registry::Apnic => Some("https://rdap.apnic.net/"),
+            Registry::Arin => Some("https://rdap.arin.net/registry/"),
+            Registry::Iana | Registry::Ietf => None,
+            Registry::Lacnic => Some("https://rdap.lacnic.net/rdap/"),
+            Registry::Ripencc => Some("https://rdap.db.ripe.net/"),
+        }
+    }
+
+    /// The delegation stats files of the registry, `(filename, url)`, in the order of `IANA_RIR_FILES`.
+    pub const fn stats_files(&self) -> &'static [(&'static str, &'static str)] {
+        match *self {
+            Registry::Afrinic => &[
+                ("delegated-afrinic-latest",          "https://ftp.afrinic.net/pub/stats/afrinic/delegated-afrinic-latest"),
+                ("delegated-afrinic-extended-latest", "https://ftp.afrinic.net/pub/stats/afrinic/delegated-afrinic-extended-latest"),
+            ],
+            Registry::Apnic => &[
+                ("delegated-apnic-latest",            "https://ftp.apnic.net/stats/apnic/delegated-apnic-latest"),
+                ("delegated-apnic-extended-latest",   "https://ftp.apnic.net/stats/apnic/delegated-apnic-extended-latest"),
+            ],
+            // `delegated-arin-latest` is not published, only the extended file.
+            Registry::Arin => &[
+                ("delegated-arin-extended-latest",    "https://ftp.arin.net/pub/stats/arin/delegated-arin-extended-latest"),
+            ],
+            // `delegated-iana-extended-latest` is not published either.
+            Registry::Iana => &[
+                ("delegated-iana-latest",             "https://ftp.apnic.net/stats/iana/delegated-iana-latest"),
+            ],
+            Registry::Ietf => &[],
+            Registry::Lacnic => &[
+                ("delegated-lacnic-latest",           "http://ftp.lacnic.net/pub/stats/lacnic/delegated-lacnic-latest"),
+                ("delegated-lacnic-extended-latest",  "http://ftp.lacnic.net/pub/stats/lacnic/delegated-lacnic-extended-latest"),
+            ],
+            Registry::Ripencc => &[
+                ("delegated-ripencc-latest",          "https://ftp.ripe.net/pub/stats/ripencc/delegated-ripencc-latest"),
+                ("delegated-ripencc-extended-latest", "https://ftp.ripe.net/pub/stats/ripencc/delegated-ripencc-extended-latest"),
+            ],
+        }
+    }
+
+    /// The registry publishing one of `IANA_RIR_FILES`, e.g. `Apnic` for `delegated-apnic-latest`.
+    pub fn from_stats_file(filename: &str) -> Option<Self> {
+        Registry::all().find(|registry| registry.stats_files().iter().any(|&(name, _url)| name == filename))
+    }
+
+    /// The countries of the service region, see `Country::registry`, none for `Iana` and `Ietf`.
+    ///
+    /// The records of a RIR can still name other countries, e.g. legacy space or resources
+    /// transferred between RIRs.
+    pub fn countries(&self) -> impl Iterator<Item = Country> {
+        let registry = *self;
+        Country::all().filter(move |country| country.registry() == Some(registry))
+    }
+}
+
+impl FromStr for Registry {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "afrinic" => Ok(Registry::Afrinic),
+            "apnic" => Ok(Registry::Apnic),
+            "arin" => Ok(Registry::Arin),
+            "iana" => Ok(Registry::Iana),
+            "ietf" => Ok(Registry::Ietf),
+            "lacnic" => Ok(Registry::Lacnic),
+            "ripencc" => Ok(Registry::Ripencc),
+            e => Err(Error::ParseRegistryError(format!("Unknow Registry Name ({})", e))),
+        }
+    }
+}
+
+impl fmt::Display for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Registry::Afrinic => write!(f, "afrinic"),
+            Registry::Apnic => write!(f, "apnic"),
+            Registry::Arin => write!(f, "arin"),
+            Registry::Iana => write!(f, "iana"),
+            Registry::Ietf => write!(f, "ietf"),
+            Registry::Lacnic => write!(f, "lacnic"),
+            Registry::Ripencc => write!(f, "ripencc"),
+        }
+    }
+}
+
+
+#[test]
+fn test_countries() {
+    use crate::rir::status::Status;
+
+    assert_eq!(Registry::all().count(), 7);
+    assert!(Registry::all().zip(Registry::all().skip(1)).all(|(a, b)| a < b && a.index() < b.index()));
+    assert!(Status::all().zip(Status::all().skip(1)).all(|(a, b)| a < b && a.index() < b.index()));
+
+    assert!(Registry::Apnic.countries().any(|country| country == Country::CN));
+    assert!(Registry::Apnic.countries().any(|country| country == Country::AP));
+    assert!(Registry::Arin.countries().any(|country| country == Country::US));
+    assert!(Registry::Afrinic.countries().any(|country| country == Country::ZA));
+    assert!(Registry::Lacnic.countries().any(|country| country == Country::BR));
+    assert!(Registry::Ripencc.countries().any(|country| country == Country::DE));
+    assert_eq!(Registry::Iana.countries().count(), 0);
+    assert_eq!(Registry::Ietf.countries().count(), 0);
+
+    let served: usize = Registry::all().map(|registry| registry.countries().count()).sum();
+    assert_eq!(served, Country::all().filter(|country| *country != Country::ZZ).count());
+}
+
+#[test]
+fn test_metadata() {
+    use crate::rir::rir_files::IANA_RIR_FILES;
+
+    let mut files = Vec::new();
+    for registry in Registry::all() {
+        for &(filename, url) in registry.stats_files() {
+            assert!(filename.starts_with(&format!("delegated-{}-", registry)), "{}", filename);
+            assert!(url.ends_with(&format!("/{}/{}", registry, filename)), "{}", url);
+            assert_eq!(Registry::from_stats_file(filename), Some(registry));
+            files.push((filename, url));
+        }
+        assert!(registry.rdap_url().map(|url| url.starts_with("https://") && url.ends_with('/')).unwrap_or(true));
+        assert_eq!(registry.countries().next().is_some(), registry.rdap_url().is_some());
+    }
+    // Every file once, `test_iana_rir_files` checks the order.
+    let mut expected = IANA_RIR_FILES.to_vec();
+    expected.sort_unstable();
+    files.sort_unstable();
+    assert_eq!(files, expected);
+    assert_eq!(Registry::from_stats_file("delegated-arin-latest"), None);
+    assert_eq!(Registry::Ripencc.whois_server(), Some("whois.ripe.net"));
+}
@@ -0,0 +1,69 @@
+use crate::rir::registry::Registry;
+
+
+/// The delegation stats files of every registry, `(filename, url)`, from `Registry::stats_files`.
+///
+/// The order is stable, ARIN first and IANA last: the provenance tables store an index into it.
+pub static IANA_RIR_FILES: [(&str, &str); stats_files_len()] = stats_files();
+
+/// Registries in the order of `IANA_RIR_FILES`.
+const STATS_FILES_ORDER: [Registry; 6] = [
+    Registry::Arin,
+    Registry::Ripencc,
+    Registry::Apnic,
+    Registry::Lacnic,
+    Registry::Afrinic,
+    Registry::Iana,
+];
+
+const fn stats_files_len() -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < STATS_FILES_ORDER.len() {
+        len += STATS_FILES_ORDER[i].stats_files().len();
+        i += 1;
+    }
+    len
+}
+
+const fn stats_files() -> [(&'static str, &'static str); stats_files_len()] {
+    let mut files = [("", ""); stats_files_len()];
+    let mut len = 0;
+    let mut i = 0;
+    while i < STATS_FILES_ORDER.len() {
+        let stats_files: &[(&str, &str)] = STATS_FILES_ORDER[i].stats_files();
+        let mut j = 0;
+        while j < stats_files.len() {
+            files[len] = stats_files[j];
+            len += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    files
+}
+
+/// The RDAP bootstrap files of IANA (RFC 9224), `(filename, url)`, see `RdapBootstrap`.
+pub static IANA_RDAP_FILES: [(&str, &str); 3] = [
+    ("ipv4.json", "https://data.iana.org/rdap/ipv4.json"),
+    ("ipv6.json", "https://data.iana.org/rdap/ipv6.json"),
+    ("asn.json",  "https://data.iana.org/rdap/asn.json"),
+];
+
+
+#[test]
+fn test_iana_rir_files() {
+    let filenames: Vec<&str> = IANA_RIR_FILES.iter().map(|&(filename, _url)| filename).collect();
+    assert_eq!(filenames, vec![
+        "delegated-arin-extended-latest",
+        "delegated-ripencc-latest",
+        "delegated-ripencc-extended-latest",
+        "delegated-apnic-latest",
+        "delegated-apnic-extended-latest",
+        "delegated-lacnic-latest",
+        "delegated-lacnic-extended-latest",
+        "delegated-afrinic-latest",
+        "delegated-afrinic-extended-latest",
+        "delegated-iana-latest",
+    ]);
+}
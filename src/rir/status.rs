@@ -2,10 +2,10 @@
 use std::str::FromStr;
 use std::fmt;
 
-use crate::error::Error;
+use crate::rir::error::Error;
 
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Status {
     Allocated = 0u8,
     Assigned,
@@ -13,14 +13,25 @@ pub enum Status {
     Reserved,
 }
 
+static STATUSES: [Status; 4] = [
+    Status::Allocated,
+    Status::Assigned,
+    Status::Available,
+    Status::Reserved,
+];
+
 impl Status {
-    pub fn from_index(index: u8) -> Result<Self, ()> {
+    pub fn all() -> impl Iterator<Item = Status> {
+        STATUSES.iter().cloned()
+    }
+
+    pub fn from_index(index: u8) -> Result<Self, Error> {
         match index {
             0 => Ok(Status::Allocated),
             1 => Ok(Status::Assigned),
             2 => Ok(Status::Available),
             3 => Ok(Status::Reserved),
-            _ => Err(())
+            e => Err(Error::ParseStatusError(format!("Unknow Status Index ({})", e))),
         }
     }
 
@@ -42,7 +53,7 @@ impl FromStr for Status {
             "assigned" => Ok(Status::Assigned),
             "available" => Ok(Status::Available),
             "reserved" => Ok(Status::Reserved),
-            e @ _ => Err(Error::ParseStatusError(format!("Unknow Status ({})", e))),
+            e => Err(Error::ParseStatusError(format!("Unknow Status ({})", e))),
         }
     }
 }
@@ -0,0 +1,411 @@
+//! `Serialize` and `Deserialize` behind the `serde` feature.
+//!
+//! Codes use their `Display` form and parse back with `FromStr`: `Country` is `"DE"`,
+//! `Registry` is `"ripencc"`, `Ipv4Cidr` is `"10.0.0.0/8"`, `IpBlock` is a CIDR or
+//! `"first - last"`. Structs are maps of their public fields, enums holding data are tagged
+//! with the variant name, and an `IpSet` is a list of CIDRs. An `Error` is its `Display`
+//! string, so it and the sync types holding one (`Outcome`, `Progress`, `Report`) only
+//! implement `Serialize`.
+//!
+//! Handles to files, tables and downloads (`Snapshots`, `Overlay`, `RdapBootstrap`,
+//! `MmdbWriter`, `Syncer`) and the iterators are not serializable.
+
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::ser::SerializeSeq;
+use serde::de::{self, Visitor, SeqAccess};
+
+use crate::rir::country::{Country, CountryKind};
+use crate::rir::region::{Region, Continent};
+use crate::rir::registry::Registry;
+use crate::rir::status::Status;
+use crate::rir::origin::Origin;
+use crate::rir::error::Error;
+use crate::rir::cidr::{Ipv4Cidr, Ipv6Cidr};
+use crate::rir::ipset::IpSet;
+use crate::rir::record::{Record, IpBlock, Ipv4Range, Provenance};
+use crate::diff::{Delta, Delegation, ChangeKind};
+use crate::enrich::Enriched;
+use crate::rir::rir_files::IANA_RIR_FILES;
+use crate::LookupResult;
+#[cfg(feature = "localized-names")]
+use crate::locale::Locale;
+#[cfg(feature = "sync")]
+use crate::sync::{Source, Outcome, Progress, Report};
+
+use std::fmt;
+use std::str::FromStr;
+use std::marker::PhantomData;
+use std::net::IpAddr;
+
+
+struct FromStrVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for FromStrVisitor<T>
+    where T: FromStr, T::Err: fmt::Display
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        T::from_str(v).map_err(E::custom)
+    }
+}
+
+/// The `Display` form, parsed back with `FromStr`.
+macro_rules! string_serde {
+    ($($type_:ty),* $(,)?) => {$(
+        impl Serialize for $type_ {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $type_ {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_str(FromStrVisitor(PhantomData))
+            }
+        }
+    )*};
+}
+
+string_serde!(Country, CountryKind, Region, Continent, Registry, Status, Origin,
+              Ipv4Cidr, Ipv6Cidr, Ipv4Range, IpBlock, ChangeKind);
+#[cfg(feature = "localized-names")]
+string_serde!(Locale);
+
+/// The derived `Serialize` of the `Def` mirrors below, for the types holding an `Error`.
+#[cfg(feature = "sync")]
+macro_rules! remote_serialize {
+    ($($type_:ty => $def:ident),* $(,)?) => {$(
+        impl Serialize for $type_ {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $def::serialize(self, serializer)
+            }
+        }
+    )*};
+}
+
+/// The derived implementations of the `Def` mirrors below.
+macro_rules! remote_serde {
+    ($($type_:ty => $def:ident),* $(,)?) => {$(
+        impl Serialize for $type_ {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $def::serialize(self, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $type_ {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $def::deserialize(deserializer)
+            }
+        }
+    )*};
+}
+
+
+/// The `Display` form, the variant is not kept.
+impl Serialize for Error {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl Serialize for Provenance {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct ProvenanceRef {
+            file: Option<&'static str>,
+            serial: Option<u64>,
+            date: Option<u32>,
+            line: u32,
+        }
+        ProvenanceRef { file: self.file, serial: self.serial, date: self.date, line: self.line }.serialize(serializer)
+    }
+}
+
+/// `file` must be one of `IANA_RIR_FILES`.
+impl<'de> Deserialize<'de> for Provenance {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct ProvenanceOwned {
+            file: Option<String>,
+            serial: Option<u64>,
+            date: Option<u32>,
+            line: u32,
+        }
+        let provenance = ProvenanceOwned::deserialize(deserializer)?;
+        let file = match provenance.file {
+            Some(file) => Some(IANA_RIR_FILES.iter()
+                .map(|&(filename, _url)| filename)
+                .find(|&filename| filename == file)
+                .ok_or_else(|| de::Error::custom(format!("Unknow RIR File ({})", file)))?),
+            None => None,
+        };
+        Ok(Provenance { file, serial: provenance.serial, date: provenance.date, line: provenance.line })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Record")]
+struct RecordDef {
+    src_registry: Registry,
+    country: Country,
+    ip_block: IpBlock,
+    status: Status,
+    dst_registry: Option<Registry>,
+    date: Option<u32>,
+    opaque_id: Option<String>,
+    origin: Origin,
+    provenance: Option<Provenance>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "LookupResult")]
+struct LookupResultDef {
+    first: IpAddr,
+    last: IpAddr,
+    country: Country,
+    registry: Registry,
+    status: Status,
+    origin: Origin,
+    provenance: Option<Provenance>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Delegation")]
+struct DelegationDef {
+    registry: Registry,
+    country: Country,
+    status: Status,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Delta")]
+struct DeltaDef {
+    first: IpAddr,
+    last: IpAddr,
+    old: Option<Delegation>,
+    new: Option<Delegation>,
+}
+
+remote_serde!(Record => RecordDef,
+              LookupResult => LookupResultDef, Delegation => DelegationDef, Delta => DeltaDef);
+
+
+#[cfg(feature = "sync")]
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Source")]
+struct SourceDef {
+    name: String,
+    url: String,
+    checksum: bool,
+    optional: bool,
+}
+
+#[cfg(feature = "sync")]
+#[derive(Serialize)]
+#[serde(remote = "Outcome")]
+enum OutcomeDef {
+    UpToDate,
+    Updated { bytes: u64 },
+    Skipped(Error),
+    Failed(Error),
+    Cancelled,
+}
+
+#[cfg(feature = "sync")]
+#[derive(Serialize)]
+#[serde(remote = "Progress")]
+enum ProgressDef {
+    Started { name: String, url: String },
+    Downloading { name: String, received: u64, total: Option<u64> },
+    Retrying { name: String, attempt: usize, error: Error },
+    Finished { name: String, outcome: Outcome },
+}
+
+#[cfg(feature = "sync")]
+#[derive(Serialize)]
+#[serde(remote = "Report")]
+struct ReportDef {
+    name: String,
+    url: String,
+    outcome: Outcome,
+}
+
+#[cfg(feature = "sync")]
+remote_serde!(Source => SourceDef);
+#[cfg(feature = "sync")]
+remote_serialize!(Outcome => OutcomeDef, Progress => ProgressDef, Report => ReportDef);
+
+
+impl<S: Serialize> Serialize for Enriched<S> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        #[derive(Serialize)]
+        struct EnrichedRef<'a, S> {
+            line: &'a S,
+            ip: Option<IpAddr>,
+            result: Option<LookupResult>,
+        }
+        EnrichedRef { line: &self.line, ip: self.ip, result: self.result }.serialize(serializer)
+    }
+}
+
+impl<'de, S: Deserialize<'de>> Deserialize<'de> for Enriched<S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct EnrichedOwned<S> {
+            line: S,
+            ip: Option<IpAddr>,
+            result: Option<LookupResult>,
+        }
+        let enriched = EnrichedOwned::deserialize(deserializer)?;
+        Ok(Enriched { line: enriched.line, ip: enriched.ip, result: enriched.result })
+    }
+}
+
+
+/// IPv4 then IPv6 CIDRs.
+impl Serialize for IpSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (v4_cidrs, v6_cidrs) = (self.ipv4_cidrs(), self.ipv6_cidrs());
+        let mut seq = serializer.serialize_seq(Some(v4_cidrs.len() + v6_cidrs.len()))?;
+        for v4_cidr in v4_cidrs.iter() {
+            seq.serialize_element(v4_cidr)?;
+        }
+        for v6_cidr in v6_cidrs.iter() {
+            seq.serialize_element(v6_cidr)?;
+        }
+        seq.end()
+    }
+}
+
+/// Any list of `IpBlock`, overlapping or not.
+impl<'de> Deserialize<'de> for IpSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct IpSetVisitor;
+
+        impl<'de> Visitor<'de> for IpSetVisitor {
+            type Value = IpSet;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a list of CIDRs or ranges")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<IpSet, A::Error> {
+                // Merge once at the end, the list may be long.
+                let (mut v4, mut v6) = (Vec::new(), Vec::new());
+                while let Some(ip_block) = seq.next_element::<IpBlock>()? {
+                    match (ip_block.first(), ip_block.last()) {
+                        (IpAddr::V4(first), IpAddr::V4(last)) => v4.push((u32::from(first) as u128, u32::from(last) as u128)),
+                        (IpAddr::V6(first), IpAddr::V6(last)) => v6.push((u128::from(first), u128::from(last))),
+                        _ => unreachable!(),
+                    }
+                }
+                Ok(IpSet::from_ranges(v4, v6))
+            }
+        }
+
+        deserializer.deserialize_seq(IpSetVisitor)
+    }
+}
+
+
+#[test]
+fn test_string_forms() {
+    use serde::de::IntoDeserializer;
+    use serde::de::value::Error as ValueError;
+
+    fn from_str<'de, T: Deserialize<'de>>(s: &'de str) -> Result<T, ValueError> {
+        T::deserialize(s.into_deserializer())
+    }
+
+    assert_eq!(serde_urlencoded::to_string([("country", Country::DE)]).unwrap(), "country=DE");
+    assert_eq!(serde_urlencoded::to_string([("registry", Registry::Ripencc)]).unwrap(), "registry=ripencc");
+    assert_eq!(serde_urlencoded::to_string([("region", Region::WesternAfrica)]).unwrap(), "region=Western+Africa");
+
+    assert_eq!(from_str::<Country>("DE"), Ok(Country::DE));
+    assert_eq!(from_str::<CountryKind>("region"), Ok(CountryKind::Region));
+    assert_eq!(from_str::<Region>("Western Africa"), Ok(Region::WesternAfrica));
+    assert_eq!(from_str::<Continent>("AF"), Ok(Continent::Africa));
+    assert_eq!(from_str::<Registry>("ripencc"), Ok(Registry::Ripencc));
+    assert_eq!(from_str::<Status>("allocated"), Ok(Status::Allocated));
+    assert_eq!(from_str::<Origin>("overlay"), Ok(Origin::Overlay));
+    assert_eq!(from_str::<ChangeKind>("reassigned"), Ok(ChangeKind::Reassigned));
+    assert_eq!(from_str::<IpBlock>("41.0.0.0/8"), Ok(IpBlock::Ipv4Cidr("41.0.0.0/8".parse().unwrap())));
+    assert_eq!(from_str::<IpBlock>("2001:4200::/23"), Ok(IpBlock::Ipv6Cidr("2001:4200::/23".parse().unwrap())));
+    assert_eq!(from_str::<IpBlock>("41.0.0.0 - 41.0.2.255"),
+               Ok(IpBlock::Ipv4Range(Ipv4Range::new("41.0.0.0".parse().unwrap(), "41.0.2.255".parse().unwrap()))));
+
+    let e = from_str::<Country>("de").unwrap_err();
+    assert_eq!(e.to_string(), "Unknow Country Code (de)");
+    assert!(from_str::<IpBlock>("41.0.2.255 - 41.0.0.0").is_err());
+
+    for country in Country::all() {
+        assert_eq!(from_str::<Country>(&country.to_string()), Ok(country));
+    }
+    for registry in Registry::all() {
+        assert_eq!(from_str::<Registry>(&registry.to_string()), Ok(registry));
+    }
+    for status in Status::all() {
+        assert_eq!(from_str::<Status>(&status.to_string()), Ok(status));
+    }
+}
+
+#[test]
+fn test_lookup_result() {
+    let result = LookupResult {
+        first: "41.0.0.0".parse().unwrap(),
+        last: "41.0.3.255".parse().unwrap(),
+        country: Country::ZA,
+        registry: Registry::Afrinic,
+        status: Status::Allocated,
+        origin: Origin::Rir,
+        provenance: None,
+    };
+    let encoded = serde_urlencoded::to_string(result).unwrap();
+    assert_eq!(encoded, "first=41.0.0.0&last=41.0.3.255&country=ZA&registry=afrinic&status=allocated&origin=rir");
+    assert_eq!(serde_urlencoded::from_str::<LookupResult>(&encoded), Ok(result));
+
+    let result = LookupResult {
+        provenance: Some(Provenance { file: Some("delegated-afrinic-latest"), serial: Some(7), date: Some(20190911), line: 42 }),
+        ..result
+    };
+    let encoded = serde_json::to_string(&result).unwrap();
+    assert!(encoded.ends_with(",\"provenance\":{\"file\":\"delegated-afrinic-latest\",\"serial\":7,\"date\":20190911,\"line\":42}}"),
+            "{}", encoded);
+    assert_eq!(serde_json::from_str::<LookupResult>(&encoded).unwrap(), result);
+    assert!(serde_json::from_str::<LookupResult>(&encoded.replace("afrinic-latest", "afrinic-20190911")).is_err());
+}
+
+#[test]
+fn test_error() {
+    let error = Error::ParseCountryError("Unknow Country Code (de)".to_string());
+    assert_eq!(serde_json::to_string(&error).unwrap(), "\"Unknow Country Code (de)\"");
+}
+
+#[test]
+fn test_record() {
+    let mut record = crate::parse_str("2|afrinic|20190911|1|00000000|20190911|00000\n\
+                                       afrinic|ZA|ipv4|41.0.0.0|768|20071126|allocated\n").into_iter().next().unwrap();
+    record.provenance = None;
+    let encoded = serde_urlencoded::to_string(&record).unwrap();
+    assert_eq!(encoded, "src_registry=afrinic&country=ZA&ip_block=41.0.0.0+-+41.0.2.255\
+                         &status=allocated&date=20071126&origin=rir");
+    assert_eq!(serde_urlencoded::from_str::<Record>(&encoded), Ok(record));
+}
+
+#[test]
+fn test_ipset() {
+    use serde::de::value::{Error as ValueError, SeqDeserializer};
+
+    let mut ipset = IpSet::new();
+    ipset.insert_range("10.0.0.0".parse().unwrap(), "10.0.2.255".parse().unwrap());
+    ipset.insert_range("2001:db8::".parse().unwrap(), "2001:db8::ffff".parse().unwrap());
+
+    let blocks = ["10.0.0.0/23", "10.0.2.0 - 10.0.2.255", "2001:db8::/112"];
+    let deserializer: SeqDeserializer<_, ValueError> = SeqDeserializer::new(blocks.iter().cloned());
+    assert_eq!(IpSet::deserialize(deserializer), Ok(ipset));
+}
@@ -0,0 +1,278 @@
+//! Lookups against dated snapshots of the RIR files, e.g. to place the addresses of old logs.
+//!
+//! Each snapshot is a subdirectory of the data path named after its date, `YYYYMMDD` or
+//! `YYYY-MM-DD`, holding the `IANA_RIR_FILES` as they were published on that day, under
+//! their `-latest` names or their dated archive names:
+//!
+//! ```text
+//! data/2019-09-11/delegated-apnic-latest
+//! data/2019-09-11/delegated-iana-latest
+//! data/2020-01-01/delegated-apnic-20200101
+//! data/2020-01-01/delegated-ripencc-extended-20200101
+//! ...
+//! ```
+
+use log::{debug, info, error};
+
+use crate::rir::registry::Registry;
+use crate::rir::record::Record;
+use crate::rir::overlay::{Painted, paint};
+use crate::rir::parse::parse_files;
+use crate::{LookupResult, IANA_RIR_FILES};
+
+use std::io;
+use std::fs;
+use std::sync::{Arc, Mutex};
+use std::path::{Path, PathBuf};
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+
+/// `YYYYMMDD` or `YYYY-MM-DD` as `YYYYMMDD`, the date format of `Snapshots`.
+pub fn parse_date(s: &str) -> Option<u32> {
+    let digits = match s.len() {
+        8 => s.to_string(),
+        10 if s.as_bytes()[4] == b'-' && s.as_bytes()[7] == b'-' => s.replace('-', ""),
+        _ => return None,
+    };
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let date: u32 = digits.parse().ok()?;
+    let (year, month, day) = (date / 10000, date / 100 % 100, date % 100);
+    let days = match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+    if (1..=12).contains(&month) && (1..=days).contains(&day) {
+        Some(date)
+    } else {
+        None
+    }
+}
+
+/// The files of a snapshot directory, `(filename, filepath)` of each `IANA_RIR_FILES` found:
+/// `delegated-apnic-latest`, or else the latest archive `delegated-apnic-YYYYMMDD`.
+fn snapshot_files(path: &Path) -> io::Result<Vec<(&'static str, PathBuf)>> {
+    let mut names = Vec::new();
+    for entry in fs::read_dir(path)? {
+        if let Some(name) = entry?.file_name().to_str() {
+            names.push(name.to_string());
+        }
+    }
+    names.sort();
+
+    let mut files = Vec::new();
+    for &(filename, _url) in IANA_RIR_FILES.iter() {
+        let prefix = filename.trim_end_matches("latest");
+        let archive = names.iter()
+            .filter(|name| name.as_str() == filename || name.strip_prefix(prefix).and_then(parse_date).is_some())
+            .max_by_key(|name| name.as_str() == filename);
+        match archive {
+            Some(name) => files.push((filename, path.join(name))),
+            None => debug!("No {} in {:?}", filename, path),
+        }
+    }
+    Ok(files)
+}
+
+/// The RIR records of one snapshot, without overlaps.
+#[derive(Debug, Default)]
+struct Tables {
+    v4: Painted<LookupResult>,
+    v6: Painted<LookupResult>,
+}
+
+impl Tables {
+    fn new(records: &[Record]) -> Self {
+        let mut tables = Tables::default();
+        for record in records.iter() {
+            if record.src_registry() == Registry::Iana {
+                continue;
+            }
+
+            let ip_block = record.ip_block();
+            let result = LookupResult {
+                first: ip_block.first(),
+                last: ip_block.last(),
+                country: record.country(),
+                registry: record.src_registry(),
+                status: record.status(),
+                origin: record.origin(),
+                provenance: if cfg!(feature = "provenance") { record.provenance() } else { None },
+            };
+            match (ip_block.first(), ip_block.last()) {
+                (IpAddr::V4(first), IpAddr::V4(last)) => paint(&mut tables.v4, u32::from(first) as u128, u32::from(last) as u128, result),
+                (IpAddr::V6(first), IpAddr::V6(last)) => paint(&mut tables.v6, u128::from(first), u128::from(last), result),
+                _ => unreachable!(),
+            }
+        }
+        tables
+    }
+
+    fn lookup(&self, ip: &IpAddr) -> Option<LookupResult> {
+        let (table, number) = match *ip {
+            IpAddr::V4(v4_addr) => (&self.v4, u32::from(v4_addr) as u128),
+            IpAddr::V6(v6_addr) => (&self.v6, u128::from(v6_addr)),
+        };
+        table.range(..=number).next_back()
+            .filter(|&(_, &(last, _))| number <= last)
+            .map(|(_, &(_, result))| result)
+    }
+}
+
+/// The dated snapshots of a data path, parsed on first use.
+///
+/// ```no_run
+/// let snapshots = iana::Snapshots::open("data").unwrap();
+/// let ip = "41.0.0.1".parse().unwrap();
+/// println!("{:?}", snapshots.lookup_at(&ip, 20190911));
+/// ```
+#[derive(Debug)]
+pub struct Snapshots {
+    /// Snapshot directories, sorted by date.
+    paths: Vec<(u32, PathBuf)>,
+    tables: Mutex<HashMap<u32, Arc<Tables>>>,
+}
+
+impl Snapshots {
+    pub fn open<P: AsRef<Path>>(data_path: P) -> io::Result<Self> {
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(data_path)? {
+            let entry = entry?;
+            if !entry.file_type()?.is_dir() {
+                continue;
+            }
+            match entry.file_name().to_str().and_then(parse_date) {
+                Some(date) => paths.push((date, entry.path())),
+                None => debug!("Ignore {:?}, not a dated snapshot", entry.path()),
+            }
+        }
+        paths.sort();
+
+        Ok(Snapshots { paths, tables: Mutex::new(HashMap::new()) })
+    }
+
+    pub fn len(&self) -> usize {
+        self.paths.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// Dates of the snapshots as `YYYYMMDD`, oldest first.
+    pub fn dates(&self) -> impl Iterator<Item = u32> + '_ {
+        self.paths.iter().map(|&(date, _)| date)
+    }
+
+    /// The date of the snapshot in force on `date` (`YYYYMMDD`): the latest one published on or before it.
+    pub fn snapshot_date(&self, date: u32) -> Option<u32> {
+        self.dates().take_while(|&snapshot_date| snapshot_date <= date).last()
+    }
+
+    fn tables(&self, date: u32) -> Arc<Tables> {
+        if let Some(tables) = self.tables.lock().unwrap().get(&date) {
+            return tables.clone();
+        }
+
+        // Parse without the lock, lookups in the loaded snapshots do not wait for this one.
+        let path = &self.paths.iter().find(|&&(snapshot_date, _)| snapshot_date == date).unwrap().1;
+        info!("Load snapshot {:?} ...", path);
+        let files = snapshot_files(path).unwrap_or_else(|e| {
+            error!("Snapshot {:?}: {}", path, e);
+            Vec::new()
+        });
+        let records: Vec<Record> = parse_files(files).into_iter().collect();
+        let tables = Arc::new(Tables::new(&records));
+
+        // Another thread may have loaded it meanwhile, keep the first.
+        self.tables.lock().unwrap().entry(date).or_insert(tables).clone()
+    }
+
+    /// Look `ip` up in the snapshot in force on `date` (`YYYYMMDD`).
+    ///
+    /// `None` when the address was not delegated then, or no snapshot is that old.
+    pub fn lookup_at(&self, ip: &IpAddr, date: u32) -> Option<LookupResult> {
+        let snapshot_date = self.snapshot_date(date)?;
+        self.tables(snapshot_date).lookup(ip)
+    }
+}
+
+
+#[test]
+fn test_lookup_at() {
+    use crate::Country;
+
+    let data_path = tempfile::tempdir().unwrap();
+    let snapshots = [
+        ("20190101", "2|afrinic|20190101|1|00000000|20190101|00000\n\
+                      afrinic|ZA|ipv4|41.0.0.0|1024|20071126|allocated\n"),
+        ("2020-01-01", "2|afrinic|20200101|2|00000000|20200101|00000\n\
+                        afrinic|EG|ipv4|41.0.0.0|1024|20071126|allocated\n\
+                        afrinic|ZA|ipv6|2001:4200::|32|20051021|allocated\n"),
+    ];
+    for &(name, content) in snapshots.iter() {
+        fs::create_dir(data_path.path().join(name)).unwrap();
+        fs::write(data_path.path().join(name).join("delegated-afrinic-latest"), content).unwrap();
+    }
+    fs::create_dir(data_path.path().join("archive")).unwrap();
+    fs::create_dir(data_path.path().join("2021-01-01")).unwrap();
+    fs::write(data_path.path().join("2021-01-01").join("delegated-afrinic-20201231"),
+              "2|afrinic|20201231|1|00000000|20201231|00000\n\
+               afrinic|NG|ipv4|41.0.0.0|1024|20071126|allocated\n").unwrap();
+    fs::write(data_path.path().join("2021-01-01").join("delegated-afrinic-extended-20201231"),
+              "2|afrinic|20201231|1|00000000|20201231|00000\n\
+               afrinic|NG|ipv4|41.0.0.0|1024|20071126|allocated|F36B9F4B\n").unwrap();
+
+    let snapshots = Snapshots::open(data_path.path()).unwrap();
+    assert_eq!(snapshots.dates().collect::<Vec<u32>>(), vec![20190101, 20200101, 20210101]);
+    assert_eq!(snapshots.snapshot_date(20191231), Some(20190101));
+    assert_eq!(snapshots.snapshot_date(20200101), Some(20200101));
+
+    let ip: IpAddr = "41.0.1.1".parse().unwrap();
+    assert_eq!(snapshots.lookup_at(&ip, 20181231), None);
+    assert_eq!(snapshots.lookup_at(&ip, 20190601).map(|result| result.country), Some(Country::ZA));
+    assert_eq!(snapshots.lookup_at(&ip, 20200601).map(|result| result.country), Some(Country::EG));
+    assert_eq!(snapshots.lookup_at(&ip, 20260101).map(|result| result.country), Some(Country::NG));
+    assert!(snapshots.lookup_at(&"41.0.4.0".parse().unwrap(), 20260101).is_none());
+
+    let ip: IpAddr = "2001:4200::1".parse().unwrap();
+    assert!(snapshots.lookup_at(&ip, 20190601).is_none());
+    assert_eq!(snapshots.lookup_at(&ip, 20200101).map(|result| result.registry), Some(Registry::Afrinic));
+}
+
+#[test]
+fn test_snapshot_files() {
+    let path = tempfile::tempdir().unwrap();
+    for name in ["delegated-apnic-20190910", "delegated-apnic-20190911", "delegated-apnic-extended-20190911",
+                 "delegated-ripencc-latest", "delegated-ripencc-20190911", "delegated-ripencc-20190911.md5",
+                 "delegated-afrinic-notes"].iter() {
+        fs::write(path.path().join(name), "").unwrap();
+    }
+
+    let files: Vec<(&str, String)> = snapshot_files(path.path()).unwrap().into_iter()
+        .map(|(filename, filepath)| (filename, filepath.file_name().unwrap().to_str().unwrap().to_string()))
+        .collect();
+    assert_eq!(files, vec![
+        ("delegated-ripencc-latest", "delegated-ripencc-latest".to_string()),
+        ("delegated-apnic-latest", "delegated-apnic-20190911".to_string()),
+        ("delegated-apnic-extended-latest", "delegated-apnic-extended-20190911".to_string()),
+    ]);
+}
+
+#[test]
+fn test_parse_date() {
+    assert_eq!(parse_date("20190911"), Some(20190911));
+    assert_eq!(parse_date("2019-09-11"), Some(20190911));
+    assert_eq!(parse_date("2019-13-11"), None);
+    assert_eq!(parse_date("2019-13-45"), None);
+    assert_eq!(parse_date("2019-09-31"), None);
+    assert_eq!(parse_date("2019-02-29"), None);
+    assert_eq!(parse_date("2020-02-29"), Some(20200229));
+    assert_eq!(parse_date("2019/09/11"), None);
+    assert_eq!(parse_date("latest"), None);
+}
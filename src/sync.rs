@@ -0,0 +1,452 @@
+//! Synchronize RIR delegation files into a local data directory.
+//!
+//! ```no_run
+//! # async fn run() {
+//! let syncer = iana::Syncer::new("data")
+//!     .concurrency(4)
+//!     .on_progress(|progress| println!("{:?}", progress));
+//!
+//! for report in syncer.run().await.unwrap() {
+//!     println!("{} {:?}", report.name, report.outcome);
+//! }
+//! # }
+//! ```
+
+mod ftp;
+
+use futures_util::stream::{self, StreamExt};
+use log::{debug, trace};
+use tokio::fs;
+use percent_encoding::percent_decode_str;
+
+use crate::rir::error::Error;
+use crate::{IANA_RIR_FILES, IANA_RDAP_FILES};
+
+pub use tokio_util::sync::CancellationToken;
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use std::path::{Path, PathBuf};
+
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Protocol {
+    Http,
+    Https,
+    Ftp,
+    File,
+}
+
+impl Protocol {
+    fn from_url(url: &str) -> Option<Self> {
+        let scheme = url.split("://").next()?;
+        match scheme {
+            "http" => Some(Protocol::Http),
+            "https" => Some(Protocol::Https),
+            "ftp" => Some(Protocol::Ftp),
+            "file" => Some(Protocol::File),
+            _ => None,
+        }
+    }
+}
+
+/// A delegation file to keep in sync.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Source {
+    /// Local file name, also appended to the mirror base URL.
+    pub name: String,
+    pub url: String,
+    /// Whether `<url>.md5` is published next to the file.
+    pub checksum: bool,
+    /// Whether a missing file is skipped rather than failing the sync, e.g. on a mirror
+    /// holding the RIR files only.
+    pub optional: bool,
+}
+
+impl Source {
+    pub fn new<N: Into<String>, U: Into<String>>(name: N, url: U) -> Self {
+        Source { name: name.into(), url: url.into(), checksum: true, optional: false }
+    }
+
+    pub fn without_checksum(mut self) -> Self {
+        self.checksum = false;
+        self
+    }
+
+    pub fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+
+    /// The files listed in `IANA_RIR_FILES`, and the optional `IANA_RDAP_FILES`.
+    pub fn defaults() -> Vec<Source> {
+        IANA_RIR_FILES.iter()
+            .map(|&(name, url)| {
+                let source = Source::new(name, url);
+                // IANA does not publish a checksum file.
+                if name == "delegated-iana-latest" { source.without_checksum() } else { source }
+            })
+            .chain(IANA_RDAP_FILES.iter().map(|&(name, url)| Source::new(name, url).without_checksum().optional()))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The published checksum matches the local copy, nothing was downloaded.
+    UpToDate,
+    Updated { bytes: u64 },
+    /// An optional source is not published at its URL, the local copy is left alone.
+    Skipped(Error),
+    Failed(Error),
+    Cancelled,
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        matches!(*self, Outcome::Failed(_))
+    }
+}
+
+/// Events passed to the progress callback.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Progress {
+    Started { name: String, url: String },
+    Downloading { name: String, received: u64, total: Option<u64> },
+    Retrying { name: String, attempt: usize, error: Error },
+    Finished { name: String, outcome: Outcome },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub name: String,
+    pub url: String,
+    pub outcome: Outcome,
+}
+
+
+type ProgressCallback = Arc<dyn Fn(&Progress) + Send + Sync>;
+
+/// Downloads delegation files (and their `.md5` checksums) with bounded concurrency.
+///
+/// A file is only replaced once its content matches the published checksum,
+/// and the local `.md5` file is written last, so an interrupted run never
+/// leaves a file that looks up-to-date but is not.
+///
+/// `run` only needs a Tokio runtime to be present, so the same `Syncer` can
+/// be driven from the `sync` binary or from inside another application.
+pub struct Syncer {
+    data_path: PathBuf,
+    sources: Vec<Source>,
+    mirror: Option<String>,
+    client: reqwest::Client,
+    concurrency: usize,
+    timeout: Duration,
+    retries: usize,
+    retry_delay: Duration,
+    progress: Option<ProgressCallback>,
+    cancel: CancellationToken,
+}
+
+impl fmt::Debug for Syncer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Syncer")
+            .field("data_path", &self.data_path)
+            .field("sources", &self.sources)
+            .field("mirror", &self.mirror)
+            .field("concurrency", &self.concurrency)
+            .field("timeout", &self.timeout)
+            .field("retries", &self.retries)
+            .field("retry_delay", &self.retry_delay)
+            .finish()
+    }
+}
+
+impl Syncer {
+    pub fn new<P: AsRef<Path>>(data_path: P) -> Self {
+        let client = reqwest::Client::builder()
+            .user_agent(concat!("iana/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .expect("Failed to initialize the HTTP client");
+
+        Syncer {
+            data_path: data_path.as_ref().to_path_buf(),
+            sources: Source::defaults(),
+            mirror: None,
+            client,
+            concurrency: 4,
+            timeout: Duration::from_secs(120),
+            retries: 2,
+            retry_delay: Duration::from_secs(2),
+            progress: None,
+            cancel: CancellationToken::new(),
+        }
+    }
+
+    /// Replace the default `IANA_RIR_FILES` sources.
+    pub fn sources(mut self, sources: Vec<Source>) -> Self {
+        self.sources = sources;
+        self
+    }
+
+    /// Fetch every file from `<base>/<name>` instead of its own URL,
+    /// e.g. `file:///mnt/rir-mirror` for air-gapped builds.
+    pub fn mirror<S: Into<String>>(mut self, base: S) -> Self {
+        self.mirror = Some(base.into());
+        self
+    }
+
+    /// Share an existing HTTP client (connection pool, proxy and TLS settings).
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Maximum number of files transferred at the same time.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = std::cmp::max(1, concurrency);
+        self
+    }
+
+    /// Time limit for a single transfer, body included.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Number of additional attempts after a transfer or checksum failure.
+    pub fn retries(mut self, retries: usize) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Base delay between attempts, multiplied by the attempt number.
+    pub fn retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    pub fn on_progress<F>(mut self, callback: F) -> Self
+        where F: Fn(&Progress) + Send + Sync + 'static
+    {
+        self.progress = Some(Arc::new(callback));
+        self
+    }
+
+    pub fn with_cancel_token(mut self, token: CancellationToken) -> Self {
+        self.cancel = token;
+        self
+    }
+
+    /// Cancelling this token stops all pending transfers, their outcome is `Outcome::Cancelled`.
+    pub fn cancel_token(&self) -> CancellationToken {
+        self.cancel.clone()
+    }
+
+    pub fn url_for(&self, source: &Source) -> String {
+        match self.mirror {
+            Some(ref base) => format!("{}/{}", base.trim_end_matches('/'), source.name),
+            None => source.url.clone(),
+        }
+    }
+
+    /// Sync every source, reports are returned in the order of the sources.
+    pub async fn run(&self) -> Result<Vec<Report>, Error> {
+        fs::create_dir_all(&self.data_path).await
+            .map_err(|e| Error::IoError(format!("{:?}: {}", self.data_path, e)))?;
+
+        let reports = stream::iter(self.sources.iter())
+            .map(|source| async move {
+                let url = self.url_for(source);
+                let outcome = self.sync_file(source, &url).await;
+                Report { name: source.name.clone(), url, outcome }
+            })
+            .buffered(self.concurrency)
+            .collect::<Vec<Report>>()
+            .await;
+
+        Ok(reports)
+    }
+
+    fn emit(&self, progress: Progress) {
+        trace!("{:?}", progress);
+        if let Some(ref callback) = self.progress {
+            callback(&progress);
+        }
+    }
+
+    async fn sync_file(&self, source: &Source, url: &str) -> Outcome {
+        self.emit(Progress::Started { name: source.name.clone(), url: url.to_string() });
+
+        let mut attempt = 0;
+        let outcome = loop {
+            let result = tokio::select! {
+                _ = self.cancel.cancelled() => break Outcome::Cancelled,
+                result = self.try_sync_file(source, url) => result,
+            };
+
+            match result {
+                Ok(outcome) => break outcome,
+                Err(Error::NotFoundError(e)) if source.optional => break Outcome::Skipped(Error::NotFoundError(e)),
+                Err(e) => {
+                    let retryable = matches!(e, Error::FetchError(_) | Error::ChecksumError(_));
+                    if !retryable || attempt >= self.retries {
+                        break Outcome::Failed(e);
+                    }
+
+                    attempt += 1;
+                    debug!("Retry {} ({}/{}): {:?}", source.name, attempt, self.retries, e);
+                    self.emit(Progress::Retrying { name: source.name.clone(), attempt, error: e });
+
+                    tokio::select! {
+                        _ = self.cancel.cancelled() => break Outcome::Cancelled,
+                        _ = tokio::time::sleep(self.retry_delay * attempt as u32) => { },
+                    }
+                }
+            }
+        };
+
+        self.emit(Progress::Finished { name: source.name.clone(), outcome: outcome.clone() });
+        outcome
+    }
+
+    async fn try_sync_file(&self, source: &Source, url: &str) -> Result<Outcome, Error> {
+        let filepath = self.data_path.join(&source.name);
+        let md5_filepath = self.data_path.join(format!("{}.md5", source.name));
+
+        let checksum = if source.checksum {
+            let body = self.fetch(&format!("{}.md5", url), None).await?;
+            let body = String::from_utf8(body)
+                .map_err(|_| Error::ChecksumError(format!("{}.md5: not a text file", url)))?;
+            let digest = parse_md5(&body)
+                .ok_or_else(|| Error::ChecksumError(format!("{}.md5: no MD5 digest found", url)))?;
+
+            let local_md5 = fs::read_to_string(&md5_filepath).await.ok();
+            if local_md5.as_ref() == Some(&body) && fs::metadata(&filepath).await.is_ok() {
+                trace!("File {:?} Is Up-to-date!", filepath);
+                return Ok(Outcome::UpToDate);
+            }
+
+            Some((body, digest))
+        } else {
+            None
+        };
+
+        let body = self.fetch(url, Some(&source.name)).await?;
+
+        if let Some((_, ref digest)) = checksum {
+            let actual = format!("{:x}", md5::compute(&body));
+            if &actual != digest {
+                return Err(Error::ChecksumError(format!("{}: expected {}, got {}", url, digest, actual)));
+            }
+        }
+
+        write_file(&filepath, &body).await?;
+        if let Some((md5_body, _)) = checksum {
+            write_file(&md5_filepath, md5_body.as_bytes()).await?;
+        }
+
+        Ok(Outcome::Updated { bytes: body.len() as u64 })
+    }
+
+    /// Download `url`, reporting `Progress::Downloading` under `name` when given.
+    async fn fetch(&self, url: &str, name: Option<&str>) -> Result<Vec<u8>, Error> {
+        let timed_out = || Error::FetchError(format!("{}: timed out after {:?}", url, self.timeout));
+
+        match Protocol::from_url(url) {
+            Some(Protocol::Http) | Some(Protocol::Https) => {
+                tokio::time::timeout(self.timeout, self.fetch_http(url, name)).await
+                    .map_err(|_| timed_out())?
+            },
+            Some(Protocol::Ftp) => {
+                let owned_url = url.to_string();
+                let timeout = self.timeout;
+                // `ftp::get` gives up at the same deadline, the blocking thread does not outlive
+                // the timeout and retries do not pile up threads.
+                let task = tokio::task::spawn_blocking(move || ftp::get(&owned_url, timeout));
+
+                match tokio::time::timeout(self.timeout, task).await {
+                    Ok(Ok(Ok(body))) => Ok(body),
+                    Ok(Ok(Err(e))) => Err(io_error(url, e)),
+                    Ok(Err(e)) => Err(Error::FetchError(format!("{}: {}", url, e))),
+                    Err(_) => Err(timed_out()),
+                }
+            },
+            Some(Protocol::File) => {
+                let path = url.trim_start_matches("file://");
+                let path = if path.starts_with("localhost/") { &path["localhost".len()..] } else { path };
+                let path = percent_decode_str(path).decode_utf8()
+                    .map_err(|_| Error::FetchError(format!("{}: invalid percent-encoding", url)))?;
+
+                fs::read(path.as_ref()).await.map_err(|e| io_error(url, e))
+            },
+            None => Err(Error::FetchError(format!("{}: URL Scheme Not Supported.", url))),
+        }
+    }
+
+    async fn fetch_http(&self, url: &str, name: Option<&str>) -> Result<Vec<u8>, Error> {
+        let fetch_error = |e: reqwest::Error| Error::FetchError(format!("GET {}: {}", url, e));
+
+        let mut response = self.client.get(url).send().await.map_err(fetch_error)?;
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND || status == reqwest::StatusCode::GONE {
+            return Err(Error::NotFoundError(format!("GET {}: {}", url, status)));
+        }
+        if !status.is_success() {
+            return Err(Error::FetchError(format!("GET {}: {}", url, status)));
+        }
+
+        let total = response.content_length();
+        let mut body = Vec::with_capacity(std::cmp::min(total.unwrap_or(0), 64 * 1024 * 1024) as usize);
+        while let Some(chunk) = response.chunk().await.map_err(fetch_error)? {
+            body.extend_from_slice(&chunk);
+            if let Some(name) = name {
+                self.emit(Progress::Downloading { name: name.to_string(), received: body.len() as u64, total });
+            }
+        }
+
+        match total {
+            Some(total) if total != body.len() as u64 => {
+                Err(Error::FetchError(format!("GET {}: truncated body, received {} of {} bytes", url, body.len(), total)))
+            },
+            _ => Ok(body),
+        }
+    }
+}
+
+
+fn io_error(url: &str, e: std::io::Error) -> Error {
+    match e.kind() {
+        std::io::ErrorKind::NotFound => Error::NotFoundError(format!("{}: {}", url, e)),
+        _ => Error::FetchError(format!("{}: {}", url, e)),
+    }
+}
+
+/// Extract the hex digest from `MD5 (name) = <digest>` or `<digest>  name`.
+fn parse_md5(body: &str) -> Option<String> {
+    body.split_whitespace()
+        .find(|word| word.len() == 32 && word.chars().all(|c| c.is_ascii_hexdigit()))
+        .map(|word| word.to_ascii_lowercase())
+}
+
+/// Write through a temporary file, so readers never see a partial file.
+async fn write_file(path: &Path, content: &[u8]) -> Result<(), Error> {
+    let mut tmp_path = path.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+
+    fs::write(&tmp_path, content).await
+        .map_err(|e| Error::IoError(format!("{:?}: {}", tmp_path, e)))?;
+    fs::rename(&tmp_path, path).await
+        .map_err(|e| Error::IoError(format!("{:?}: {}", path, e)))
+}
+
+
+#[test]
+fn test_parse_md5() {
+    assert_eq!(parse_md5("MD5 (delegated-afrinic-latest) = d5719d0f5c7b8167913b04d942089bcf"),
+               Some("d5719d0f5c7b8167913b04d942089bcf".to_string()));
+    assert_eq!(parse_md5("D5719D0F5C7B8167913B04D942089BCF  delegated-afrinic-latest\n"),
+               Some("d5719d0f5c7b8167913b04d942089bcf".to_string()));
+    assert_eq!(parse_md5("<html>Not Found</html>"), None);
+}
@@ -0,0 +1,103 @@
+//! Round-trip the MaxMind DB exporter through the `maxminddb` reader.
+
+use iana::{parse_str, MmdbWriter};
+
+use maxminddb::{geoip2, MaxMindDBError, Reader};
+use serde::Deserialize;
+
+use std::net::IpAddr;
+
+
+const RECORDS: &str = "\
+2|afrinic|20190911|6|00000000|20190911|00000
+afrinic|*|ipv4|*|3|summary
+afrinic|ZA|ipv4|41.0.0.0|2097152|20071126|allocated
+afrinic|EG|ipv4|41.32.0.0|1048576|20091105|allocated
+afrinic|ZA|ipv4|41.48.0.0|768|20091105|assigned
+afrinic|ZZ|ipv4|41.49.0.0|256||available
+apnic|AP|ipv4|182.50.184.0|2048|20100302|allocated
+afrinic|ZA|ipv6|2001:4200::|32|20051021|allocated
+";
+
+#[derive(Debug, Deserialize)]
+struct Rir<'a> {
+    registry: &'a str,
+    status: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+struct RegionCode<'a> {
+    iso_code: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+struct Region<'a> {
+    #[serde(borrow)]
+    region: RegionCode<'a>,
+}
+
+fn reader() -> Reader<Vec<u8>> {
+    let records = parse_str(RECORDS);
+    let mut buf = Vec::new();
+    MmdbWriter::new().build_epoch(1568196000).write(&mut buf, &records).unwrap();
+
+    Reader::from_source(buf).unwrap()
+}
+
+fn iso_code(reader: &Reader<Vec<u8>>, ip: &str) -> Option<String> {
+    let ip: IpAddr = ip.parse().unwrap();
+    match reader.lookup::<geoip2::Country>(ip) {
+        Ok(country) => country.country.and_then(|c| c.iso_code).map(|s| s.to_string()),
+        Err(MaxMindDBError::AddressNotFoundError(_)) => None,
+        Err(e) => panic!("{:?}", e),
+    }
+}
+
+#[test]
+fn test_metadata() {
+    let reader = reader();
+    assert_eq!(reader.metadata.database_type, "GeoLite2-Country");
+    assert_eq!(reader.metadata.ip_version, 6);
+    assert_eq!(reader.metadata.build_epoch, 1568196000);
+    assert_eq!(reader.metadata.languages, vec!["en".to_string()]);
+    assert_eq!(reader.metadata.record_size, 24);
+}
+
+#[test]
+fn test_lookup_country() {
+    let reader = reader();
+    assert_eq!(iso_code(&reader, "41.0.0.0").as_deref(), Some("ZA"));
+    assert_eq!(iso_code(&reader, "41.31.255.255").as_deref(), Some("ZA"));
+    assert_eq!(iso_code(&reader, "41.40.1.2").as_deref(), Some("EG"));
+    // 768 addresses, not a CIDR.
+    assert_eq!(iso_code(&reader, "41.48.2.255").as_deref(), Some("ZA"));
+    assert_eq!(iso_code(&reader, "41.48.3.0"), None);
+    assert_eq!(iso_code(&reader, "8.8.8.8"), None);
+    assert_eq!(iso_code(&reader, "2001:4200:1::1").as_deref(), Some("ZA"));
+    assert_eq!(iso_code(&reader, "2001:4300::1"), None);
+    assert_eq!(iso_code(&reader, "::ffff:41.40.1.2").as_deref(), Some("EG"));
+
+    let country = reader.lookup::<geoip2::Country>("41.40.1.2".parse().unwrap()).unwrap();
+    let names = country.country.unwrap().names.unwrap();
+    assert_eq!(names.get("en"), Some(&"Egypt"));
+    assert_eq!(country.registered_country.unwrap().iso_code, Some("EG"));
+}
+
+#[test]
+fn test_lookup_custom_fields() {
+    let reader = reader();
+
+    let rir: Rir = reader.lookup("41.48.1.1".parse().unwrap()).unwrap();
+    assert_eq!(rir.registry, "afrinic");
+    assert_eq!(rir.status, "assigned");
+
+    // No country for `ZZ`, registry and status are still there.
+    let rir: Rir = reader.lookup("41.49.0.1".parse().unwrap()).unwrap();
+    assert_eq!(rir.status, "available");
+    assert_eq!(iso_code(&reader, "41.49.0.1"), None);
+
+    // `AP` is a region, not a country.
+    let region: Region = reader.lookup("182.50.184.1".parse().unwrap()).unwrap();
+    assert_eq!(region.region.iso_code, "AP");
+    assert_eq!(iso_code(&reader, "182.50.184.1"), None);
+}
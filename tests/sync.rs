@@ -0,0 +1,387 @@
+//! `Syncer` against an in-process HTTP server serving the files in `tests/fixtures`.
+#![cfg(feature = "sync")]
+
+extern crate iana;
+extern crate tempfile;
+extern crate tokio;
+
+use iana::{Syncer, Source, Progress, Outcome, Error};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+
+const NAME: &str = "delegated-afrinic-latest";
+
+
+#[derive(Debug, Clone)]
+enum Reply {
+    Ok(Vec<u8>),
+    Status(u16),
+    /// Advertise the full length but close the connection after half of the body.
+    Truncated(Vec<u8>),
+    Slow(Duration, Vec<u8>),
+    /// Answer `500` to the first `n` requests, then the inner reply.
+    FailFirst(usize, Box<Reply>),
+}
+
+#[derive(Default)]
+struct State {
+    routes: Mutex<HashMap<String, Reply>>,
+    hits: Mutex<HashMap<String, usize>>,
+    in_flight: AtomicUsize,
+    max_in_flight: AtomicUsize,
+}
+
+struct Server {
+    base: String,
+    state: Arc<State>,
+}
+
+impl Server {
+    async fn start() -> Server {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let state = Arc::new(State::default());
+
+        let server_state = state.clone();
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = match listener.accept().await {
+                    Ok(conn) => conn,
+                    Err(_) => break,
+                };
+                tokio::spawn(serve(stream, server_state.clone()));
+            }
+        });
+
+        Server { base, state }
+    }
+
+    fn route(&self, path: &str, reply: Reply) {
+        self.state.routes.lock().unwrap().insert(path.to_string(), reply);
+    }
+
+    /// Serve the fixture file and its checksum under `/<name>`.
+    fn fixture(&self, name: &str) {
+        self.route(&format!("/{}", name), Reply::Ok(fixture(name)));
+        self.route(&format!("/{}.md5", name), Reply::Ok(fixture(&format!("{}.md5", name))));
+    }
+
+    fn hits(&self, path: &str) -> usize {
+        self.state.hits.lock().unwrap().get(path).cloned().unwrap_or(0)
+    }
+}
+
+async fn serve(mut stream: tokio::net::TcpStream, state: Arc<State>) {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+        match stream.read(&mut buf).await {
+            Ok(0) | Err(_) => return,
+            Ok(n) => request.extend_from_slice(&buf[..n]),
+        }
+    }
+    let request = String::from_utf8_lossy(&request);
+    let path = request.split_whitespace().nth(1).unwrap_or("/").to_string();
+
+    let hit = {
+        let mut hits = state.hits.lock().unwrap();
+        let hit = hits.entry(path.clone()).or_insert(0);
+        *hit += 1;
+        *hit
+    };
+    let reply = state.routes.lock().unwrap().get(&path).cloned().unwrap_or(Reply::Status(404));
+
+    let in_flight = state.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+    state.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+    respond(&mut stream, reply, hit).await;
+    state.in_flight.fetch_sub(1, Ordering::SeqCst);
+}
+
+async fn respond(stream: &mut tokio::net::TcpStream, mut reply: Reply, hit: usize) {
+    if let Reply::FailFirst(n, inner) = reply {
+        reply = if hit <= n { Reply::Status(500) } else { *inner };
+    }
+
+    let header = |status: &str, len: usize| {
+        format!("HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", status, len).into_bytes()
+    };
+
+    match reply {
+        Reply::Ok(body) => {
+            let _ = stream.write_all(&header("200 OK", body.len())).await;
+            let _ = stream.write_all(&body).await;
+        },
+        Reply::Status(code) => {
+            let _ = stream.write_all(&header(&format!("{} Status", code), 0)).await;
+        },
+        Reply::Truncated(body) => {
+            let _ = stream.write_all(&header("200 OK", body.len())).await;
+            let _ = stream.write_all(&body[..body.len() / 2]).await;
+        },
+        Reply::Slow(delay, body) => {
+            let _ = stream.write_all(&header("200 OK", body.len())).await;
+            tokio::time::sleep(delay).await;
+            let _ = stream.write_all(&body).await;
+        },
+        Reply::FailFirst(..) => unreachable!(),
+    }
+    let _ = stream.shutdown().await;
+}
+
+
+fn fixture(name: &str) -> Vec<u8> {
+    fs::read(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)).unwrap()
+}
+
+fn syncer(server: &Server, data_path: &tempfile::TempDir) -> Syncer {
+    Syncer::new(data_path.path())
+        .sources(vec![ Source::new(NAME, format!("{}/{}", server.base, NAME)) ])
+        .retries(2)
+        .retry_delay(Duration::from_millis(10))
+        .timeout(Duration::from_secs(5))
+}
+
+async fn outcome(syncer: &Syncer) -> Outcome {
+    let mut reports = syncer.run().await.unwrap();
+    assert_eq!(reports.len(), 1);
+    reports.remove(0).outcome
+}
+
+
+#[tokio::test]
+async fn test_sync_success() {
+    let server = Server::start().await;
+    server.fixture(NAME);
+    let data_path = tempfile::tempdir().unwrap();
+    let syncer = syncer(&server, &data_path);
+
+    let body = fixture(NAME);
+    assert_eq!(outcome(&syncer).await, Outcome::Updated { bytes: body.len() as u64 });
+    assert_eq!(fs::read(data_path.path().join(NAME)).unwrap(), body);
+    assert_eq!(fs::read(data_path.path().join(format!("{}.md5", NAME))).unwrap(), fixture(&format!("{}.md5", NAME)));
+
+    // Same checksum: only the `.md5` is fetched again.
+    assert_eq!(outcome(&syncer).await, Outcome::UpToDate);
+    assert_eq!(server.hits(&format!("/{}.md5", NAME)), 2);
+    assert_eq!(server.hits(&format!("/{}", NAME)), 1);
+}
+
+#[tokio::test]
+async fn test_sync_without_checksum() {
+    let server = Server::start().await;
+    server.fixture(NAME);
+    let data_path = tempfile::tempdir().unwrap();
+    let syncer = syncer(&server, &data_path)
+        .sources(vec![ Source::new(NAME, format!("{}/{}", server.base, NAME)).without_checksum() ]);
+
+    assert!(matches!(outcome(&syncer).await, Outcome::Updated { .. }));
+    assert_eq!(server.hits(&format!("/{}.md5", NAME)), 0);
+    assert!(!data_path.path().join(format!("{}.md5", NAME)).exists());
+}
+
+#[tokio::test]
+async fn test_sync_not_found() {
+    let server = Server::start().await;
+    let data_path = tempfile::tempdir().unwrap();
+    let syncer = syncer(&server, &data_path);
+
+    match outcome(&syncer).await {
+        Outcome::Failed(Error::NotFoundError(_)) => { },
+        outcome => panic!("unexpected outcome {:?}", outcome),
+    }
+    // A missing file is not retried.
+    assert_eq!(server.hits(&format!("/{}.md5", NAME)), 1);
+    assert!(!data_path.path().join(NAME).exists());
+}
+
+#[tokio::test]
+async fn test_sync_optional_not_found() {
+    let server = Server::start().await;
+    let data_path = tempfile::tempdir().unwrap();
+    let syncer = syncer(&server, &data_path)
+        .sources(vec![ Source::new(NAME, format!("{}/{}", server.base, NAME)).without_checksum().optional() ]);
+
+    let outcome = outcome(&syncer).await;
+    assert!(matches!(outcome, Outcome::Skipped(Error::NotFoundError(_))), "{:?}", outcome);
+    assert!(!outcome.is_failure());
+    assert_eq!(server.hits(&format!("/{}", NAME)), 1);
+}
+
+#[tokio::test]
+async fn test_sync_checksum_mismatch() {
+    let server = Server::start().await;
+    server.fixture(NAME);
+    let mut body = fixture(NAME);
+    body.extend_from_slice(b"afrinic|ZA|ipv4|41.64.0.0|1024|20091211|allocated\n");
+    server.route(&format!("/{}", NAME), Reply::Ok(body));
+    let data_path = tempfile::tempdir().unwrap();
+    let syncer = syncer(&server, &data_path);
+
+    match outcome(&syncer).await {
+        Outcome::Failed(Error::ChecksumError(_)) => { },
+        outcome => panic!("unexpected outcome {:?}", outcome),
+    }
+    assert_eq!(server.hits(&format!("/{}", NAME)), 3);
+    assert!(!data_path.path().join(NAME).exists());
+    assert!(!data_path.path().join(format!("{}.md5", NAME)).exists());
+}
+
+#[tokio::test]
+async fn test_sync_truncated_body() {
+    let server = Server::start().await;
+    server.fixture(NAME);
+    server.route(&format!("/{}", NAME), Reply::Truncated(fixture(NAME)));
+    let data_path = tempfile::tempdir().unwrap();
+    let syncer = syncer(&server, &data_path).retries(0);
+
+    match outcome(&syncer).await {
+        Outcome::Failed(Error::FetchError(_)) => { },
+        outcome => panic!("unexpected outcome {:?}", outcome),
+    }
+    assert!(!data_path.path().join(NAME).exists());
+}
+
+#[tokio::test]
+async fn test_sync_slow_response() {
+    let server = Server::start().await;
+    server.fixture(NAME);
+    server.route(&format!("/{}", NAME), Reply::Slow(Duration::from_millis(500), fixture(NAME)));
+    let data_path = tempfile::tempdir().unwrap();
+
+    let syncer = syncer(&server, &data_path).retries(0).timeout(Duration::from_millis(100));
+    match outcome(&syncer).await {
+        Outcome::Failed(Error::FetchError(ref e)) if e.contains("timed out") => { },
+        outcome => panic!("unexpected outcome {:?}", outcome),
+    }
+
+    let syncer = syncer.timeout(Duration::from_secs(5));
+    assert!(matches!(outcome(&syncer).await, Outcome::Updated { .. }));
+}
+
+#[tokio::test]
+async fn test_sync_retries() {
+    let server = Server::start().await;
+    server.fixture(NAME);
+    server.route(&format!("/{}", NAME), Reply::FailFirst(2, Box::new(Reply::Ok(fixture(NAME)))));
+    let data_path = tempfile::tempdir().unwrap();
+
+    let retries = Arc::new(AtomicUsize::new(0));
+    let counter = retries.clone();
+    let syncer = syncer(&server, &data_path).on_progress(move |progress| {
+        if let Progress::Retrying { .. } = *progress {
+            counter.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    assert!(matches!(outcome(&syncer).await, Outcome::Updated { .. }));
+    assert_eq!(retries.load(Ordering::SeqCst), 2);
+    assert_eq!(server.hits(&format!("/{}", NAME)), 3);
+}
+
+#[tokio::test]
+async fn test_sync_retries_exhausted() {
+    let server = Server::start().await;
+    server.fixture(NAME);
+    server.route(&format!("/{}", NAME), Reply::FailFirst(3, Box::new(Reply::Ok(fixture(NAME)))));
+    let data_path = tempfile::tempdir().unwrap();
+    let syncer = syncer(&server, &data_path);
+
+    match outcome(&syncer).await {
+        Outcome::Failed(Error::FetchError(_)) => { },
+        outcome => panic!("unexpected outcome {:?}", outcome),
+    }
+    assert_eq!(server.hits(&format!("/{}", NAME)), 3);
+}
+
+#[tokio::test]
+async fn test_sync_cancel() {
+    let server = Server::start().await;
+    server.fixture(NAME);
+    server.route(&format!("/{}", NAME), Reply::Slow(Duration::from_secs(30), fixture(NAME)));
+    let data_path = tempfile::tempdir().unwrap();
+    let syncer = syncer(&server, &data_path).timeout(Duration::from_secs(60));
+
+    let cancel = syncer.cancel_token();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        cancel.cancel();
+    });
+
+    let outcome = tokio::time::timeout(Duration::from_secs(5), outcome(&syncer)).await.unwrap();
+    assert_eq!(outcome, Outcome::Cancelled);
+    assert!(!data_path.path().join(NAME).exists());
+}
+
+#[tokio::test]
+async fn test_sync_concurrency() {
+    let server = Server::start().await;
+    let names: Vec<String> = (0..6).map(|idx| format!("delegated-test{}-latest", idx)).collect();
+    for name in names.iter() {
+        server.route(&format!("/{}", name), Reply::Slow(Duration::from_millis(100), fixture(NAME)));
+    }
+    let data_path = tempfile::tempdir().unwrap();
+    let sources = names.iter()
+        .map(|name| Source::new(name.clone(), format!("{}/{}", server.base, name)).without_checksum())
+        .collect();
+    let syncer = syncer(&server, &data_path).sources(sources).concurrency(2);
+
+    let reports = syncer.run().await.unwrap();
+    assert_eq!(reports.iter().map(|report| report.name.clone()).collect::<Vec<String>>(), names);
+    assert!(reports.iter().all(|report| matches!(report.outcome, Outcome::Updated { .. })));
+    assert_eq!(server.state.max_in_flight.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_sync_file_mirror() {
+    let data_path = tempfile::tempdir().unwrap();
+    let mirror = format!("file://{}/tests/fixtures", env!("CARGO_MANIFEST_DIR"));
+    let syncer = Syncer::new(data_path.path())
+        .sources(vec![ Source::new(NAME, "https://ftp.afrinic.net/pub/stats/afrinic/delegated-afrinic-latest") ])
+        .mirror(mirror);
+
+    assert!(matches!(outcome(&syncer).await, Outcome::Updated { .. }));
+    assert_eq!(fs::read(data_path.path().join(NAME)).unwrap(), fixture(NAME));
+}
+
+#[tokio::test]
+async fn test_sync_file_mirror_without_rdap() {
+    let data_path = tempfile::tempdir().unwrap();
+    let mirror = format!("file://{}/tests/fixtures", env!("CARGO_MANIFEST_DIR"));
+    let sources = Source::defaults().into_iter()
+        .filter(|source| source.name == NAME || source.optional)
+        .collect();
+    let syncer = Syncer::new(data_path.path()).sources(sources).mirror(mirror);
+
+    let reports = syncer.run().await.unwrap();
+    assert_eq!(reports.len(), 4);
+    assert!(reports.iter().all(|report| !report.outcome.is_failure()));
+    assert!(reports.iter().filter(|report| report.name.ends_with(".json"))
+                   .all(|report| matches!(report.outcome, Outcome::Skipped(_))));
+}
+
+#[tokio::test]
+async fn test_sync_file_mirror_escaped_path() {
+    let data_path = tempfile::tempdir().unwrap();
+    let mirror_path = tempfile::tempdir().unwrap();
+    let mirror_dir = mirror_path.path().join("rir mirror");
+    fs::create_dir(&mirror_dir).unwrap();
+    fs::write(mirror_dir.join(NAME), fixture(NAME)).unwrap();
+    fs::write(mirror_dir.join(format!("{}.md5", NAME)), fixture(&format!("{}.md5", NAME))).unwrap();
+
+    let mirror = format!("file://{}/rir%20mirror", mirror_path.path().display());
+    let syncer = Syncer::new(data_path.path())
+        .sources(vec![ Source::new(NAME, "https://ftp.afrinic.net/pub/stats/afrinic/delegated-afrinic-latest") ])
+        .mirror(mirror);
+
+    assert!(matches!(outcome(&syncer).await, Outcome::Updated { .. }));
+    assert_eq!(fs::read(data_path.path().join(NAME)).unwrap(), fixture(NAME));
+}